@@ -40,7 +40,7 @@ pub enum GameStatus {
     PendingCreate,
     PendingStart(Lobby),
     Active(ActiveGame),
-    Concluded(ActiveGame, u64),
+    Concluded(ActiveGame, Option<u64>),
     PendingReplay,
     Replay(ReplayerState),
     HardError(Vec<String>),
@@ -382,8 +382,10 @@ pub fn render(outer: &mut OuterApplication, ui: &mut egui::Ui, current_time: Dur
             }
         }
         GameStatus::Concluded(game, _winner) => {
-            if let Some(PlayerMessage::Rematch) = game.render(ui, current_time, None) {
-                send(PlayerMessage::Rematch);
+            if let Some(msg @ (PlayerMessage::Rematch | PlayerMessage::DeclineRematch)) =
+                game.render(ui, current_time, None)
+            {
+                send(msg);
             }
         }
         GameStatus::PendingReplay => {
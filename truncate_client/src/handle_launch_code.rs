@@ -14,7 +14,7 @@ use crate::{
         active_game::HeaderType, lobby::Lobby, single_player::SinglePlayerState,
         tutorial::TutorialState,
     },
-    utils::{self, daily::get_puzzle_day, macros::current_time},
+    utils::{self, daily::get_puzzle_day, macros::current_time, theming::BoardTheme},
 };
 
 use super::OuterApplication;
@@ -156,6 +156,21 @@ pub fn handle_launch_code(
         _ => {}
     };
 
+    if let Some(name) = launch_code.strip_prefix("THEME:") {
+        let Some(board_theme) = BoardTheme::from_name(name) else {
+            return Some(GameStatus::HardError(vec![
+                "Sorry, we don't have".to_string(),
+                format!("a {name} theme!"),
+            ]));
+        };
+        outer.theme = outer.theme.clone().with_board_theme(board_theme);
+        ui.ctx().style_mut(|style| {
+            style.visuals.window_fill = outer.theme.water;
+            style.visuals.panel_fill = outer.theme.water;
+        });
+        return None;
+    }
+
     if launch_code.starts_with("PUZZLE:") {
         let url_segments = launch_code.chars().filter(|c| *c == ':').count();
         let has_board_generation = url_segments >= 3;
@@ -243,6 +258,7 @@ pub fn handle_launch_code(
         send_to_server(PlayerMessage::NewGame {
             player_name: outer.name.clone(),
             effective_day: outer.launched_at_day,
+            private: false,
         });
         return Some(GameStatus::PendingCreate);
     }
@@ -14,7 +14,9 @@ use crate::{
     },
     utils::{
         daily::{get_playable_daily_puzzle, get_raw_daily_puzzle},
+        depot::RematchStatus,
         game_evals::get_main_dict,
+        reveal::hidden_squares,
     },
 };
 
@@ -89,6 +91,9 @@ pub fn handle_server_msg(outer: &mut OuterApplication, ui: &mut egui::Ui) {
                 game_ends_at,
                 paused,
                 remaining_turns,
+                game_end,
+                visible_enemy_tiles,
+                view,
             }) => {
                 // If we're already in a game, treat this as a game update
                 // (the websocket probably dropped and reconnected)
@@ -105,13 +110,16 @@ pub fn handle_server_msg(outer: &mut OuterApplication, ui: &mut egui::Ui) {
                             game_ends_at,
                             paused,
                             remaining_turns,
+                            game_end,
+                            visible_enemy_tiles,
+                            view,
                         };
                         game.apply_new_state(update);
                         continue;
                     }
                 }
 
-                outer.game_status = GameStatus::Active(ActiveGame::new(
+                let mut game = ActiveGame::new(
                     ui.ctx(),
                     room_code.to_uppercase(),
                     None,
@@ -126,7 +134,9 @@ pub fn handle_server_msg(outer: &mut OuterApplication, ui: &mut egui::Ui) {
                     GameLocation::Online,
                     game_ends_at,
                     remaining_turns,
-                ));
+                );
+                game.depot.gameplay.visible_enemy_tiles = visible_enemy_tiles;
+                outer.game_status = GameStatus::Active(game);
             }
             GameMessage::GameUpdate(state_message) => match &mut outer.game_status {
                 GameStatus::Active(game) => {
@@ -162,8 +172,16 @@ pub fn handle_server_msg(outer: &mut OuterApplication, ui: &mut egui::Ui) {
 
                 match &mut outer.game_status {
                     GameStatus::Active(game) => {
+                        let fogged_board = game.board.clone();
                         game.apply_new_state(state_message);
-                        game.depot.gameplay.winner = Some(winner as usize);
+                        game.depot.gameplay.winner = winner.map(|w| w as usize);
+                        game.depot.gameplay.drawn = winner.is_none();
+                        game.depot.gameplay.revealing_squares = hidden_squares(
+                            &fogged_board,
+                            &game.board,
+                            game.depot.gameplay.player_number as usize,
+                            &game.depot.gameplay.changes,
+                        );
                         outer.game_status = GameStatus::Concluded(game.clone(), winner);
                     }
                     _ => {}
@@ -174,6 +192,7 @@ pub fn handle_server_msg(outer: &mut OuterApplication, ui: &mut egui::Ui) {
                     // assert_eq!(game.room_code, id);
                     // assert_eq!(game.player_number, num);
                     game.depot.gameplay.error_msg = Some(err);
+                    game.depot.interactions.move_debounce.acknowledge();
                 }
                 _ => {}
             },
@@ -319,6 +338,19 @@ pub fn handle_server_msg(outer: &mut OuterApplication, ui: &mut egui::Ui) {
                 );
                 outer.game_status = GameStatus::Replay(replayer);
             }
+            GameMessage::RoomList(_rooms) => { /* no lobby browser yet */ }
+            GameMessage::RematchRequested(_player) => {
+                if let GameStatus::Concluded(game, _) = &mut outer.game_status {
+                    if game.depot.gameplay.rematch == RematchStatus::Undecided {
+                        game.depot.gameplay.rematch = RematchStatus::Offered;
+                    }
+                }
+            }
+            GameMessage::RematchDeclined(_player) => {
+                if let GameStatus::Concluded(game, _) = &mut outer.game_status {
+                    game.depot.gameplay.rematch = RematchStatus::Declined;
+                }
+            }
         }
     }
 }
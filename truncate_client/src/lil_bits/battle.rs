@@ -297,6 +297,19 @@ impl<'a> BattleUI<'a> {
                 );
             }
 
+            if active && !self.battle.attackers.is_empty() && !self.battle.defenders.is_empty() {
+                ui.add_space(5.0);
+                let galley = ui.painter().layout_no_wrap(
+                    self.battle.explanation(),
+                    FontId::new(
+                        aesthetics.theme.letter_size * 0.2,
+                        egui::FontFamily::Name("Truncate-Heavy".into()),
+                    ),
+                    aesthetics.theme.text,
+                );
+                battle_rect = battle_rect.union(self.paint_galleys(vec![galley], ui, false).rect);
+            }
+
             if !active {
                 return battle_rect;
             }
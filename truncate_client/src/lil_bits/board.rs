@@ -447,6 +447,46 @@ impl<'a> BoardUI<'a> {
                     depot.interactions.hovered_occupied_square_on_board =
                         occupied_square_is_hovered;
                     depot.interactions.hovered_tile_on_board = tile_is_hovered;
+
+                    // Preview the words the selected tile would form on the hovered square.
+                    // This is display-only, the placement is still sent when the square is clicked.
+                    let previewed_words = depot
+                        .interactions
+                        .selected_tile_in_hand
+                        .zip(
+                            depot
+                                .interactions
+                                .hovered_unoccupied_square_on_board
+                                .as_ref()
+                                .and_then(|h| h.coord),
+                        )
+                        .filter(|_| !ui.memory(|m| m.is_anything_being_dragged()))
+                        .and_then(|((_, tile), coord)| {
+                            self.board
+                                .preview_words(depot.gameplay.player_number as usize, coord, tile)
+                                .ok()
+                        })
+                        .filter(|words| !words.is_empty());
+
+                    depot.interactions.previewed_word_squares =
+                        previewed_words.as_ref().map(|words| {
+                            words
+                                .iter()
+                                .flat_map(|(squares, _)| squares.iter().cloned())
+                                .collect()
+                        });
+
+                    if let Some(words) = previewed_words {
+                        egui::show_tooltip_at_pointer(
+                            ui.ctx(),
+                            Id::new("board_word_preview"),
+                            |ui| {
+                                for (_, word) in words {
+                                    ui.label(word);
+                                }
+                            },
+                        );
+                    }
                 })
             })
             .inner;
@@ -110,6 +110,7 @@ impl<'a> EditorUI<'a> {
                     player_colors: self.player_colors.clone(),
                     destruction_tick: 0.0,
                     destruction_duration: 0.0,
+                    reveal_duration: 0.0,
                 };
                 self.mapped_board.remap_texture(
                     ui.ctx(),
@@ -306,7 +307,20 @@ impl<'a> EditorUI<'a> {
                 }
             });
 
-            if let Some((coord, new_state)) = modify_pos {
+            if let Some((coord, Square::Artifact { player, .. })) = modify_pos {
+                // Artifacts are moved rather than painted, so that each player keeps a single one.
+                // TODO: Player mirroring won't work for >2 players
+                // Both artifacts are moved on a copy, so that if either can't move, neither does.
+                let recip = self.board.reciprocal_coordinate(coord);
+                let mut board = self.board.clone();
+                if recip != coord
+                    && board.set_artifact(player, coord).is_ok()
+                    && board.set_artifact((player + 1) % 2, recip).is_ok()
+                {
+                    *self.board = board;
+                    edited = true;
+                }
+            } else if let Some((coord, new_state)) = modify_pos {
                 // Not bounds-checking values as they came from the above loop over this very state.
                 self.board.squares[coord.y][coord.x] = new_state;
 
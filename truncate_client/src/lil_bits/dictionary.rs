@@ -196,8 +196,12 @@ impl DictionaryUI {
                     resolved_word: self.current_word.clone(),
                     meanings,
                     valid: Some(self.is_valid),
+                    coordinates: vec![],
                 }],
                 outcome: Outcome::DefenderWins,
+                attacker_length: None,
+                defender_length: None,
+                length_delta: 0,
             };
 
             let desired_battle_width = ui.available_width().min(550.0);
@@ -1,5 +1,5 @@
 use time::Duration;
-use truncate_core::{messages::GamePlayerMessage, reporting::TimeChange};
+use truncate_core::{messages::GamePlayerMessage, reporting::TimeChange, rules::OvertimeRule};
 
 use eframe::egui::{self, Layout, Response, Sense};
 use epaint::{emath::Align, hex_color, vec2, Color32, Stroke};
@@ -14,6 +14,7 @@ pub struct TimerUI<'a> {
     friend: bool,
     active: bool,
     right_align: bool,
+    overtime_rule: Option<&'a OvertimeRule>,
 }
 
 impl<'a> TimerUI<'a> {
@@ -36,6 +37,7 @@ impl<'a> TimerUI<'a> {
             friend: true,
             active: true,
             right_align: false,
+            overtime_rule: None,
         }
     }
 
@@ -53,6 +55,11 @@ impl<'a> TimerUI<'a> {
         self.right_align = true;
         self
     }
+
+    pub fn overtime_rule(mut self, overtime_rule: Option<&'a OvertimeRule>) -> Self {
+        self.overtime_rule = overtime_rule;
+        self
+    }
 }
 
 impl<'a> TimerUI<'a> {
@@ -61,6 +68,15 @@ impl<'a> TimerUI<'a> {
             self.depot.aesthetics.theme.gold_medal
         } else if !self.active {
             hex_color!("#444444")
+        } else if self.overtime_seconds().is_some() {
+            // Pulse between the player's color and a warning color on each quarter-second tick
+            if self.depot.aesthetics.qs_tick.is_multiple_of(2) {
+                self.depot.aesthetics.theme.word_invalid
+            } else {
+                self.depot.aesthetics.player_colors[self.player.index]
+                    .darken()
+                    .darken()
+            }
         } else {
             self.depot.aesthetics.player_colors[self.player.index]
                 .darken()
@@ -88,39 +104,66 @@ impl<'a> TimerUI<'a> {
         time_string
     }
 
-    fn calculate_time(&mut self) -> String {
+    /// The time this player has left as of this frame, including any in-progress turn
+    fn current_time_remaining(&self) -> Option<Duration> {
+        let time_remaining = self.player.time_remaining?;
+
         match self.player.turn_starts_no_later_than {
             Some(next_turn) => {
                 let now = self.depot.timing.current_time.as_secs();
-                let elapsed = now.checked_sub(next_turn);
-                if let Some(elapsed) = elapsed {
-                    if let Some(time) = self.player.time_remaining {
-                        self.time = time - Duration::seconds(elapsed as i64);
-                        format!("{}", TimerUI::human_time(self.time.whole_seconds(), false))
-                    } else {
-                        format!("")
-                    }
-                } else {
-                    if let Some(time) = self.player.time_remaining {
-                        self.time = time;
-                        format!("{}", TimerUI::human_time(self.time.whole_seconds(), false))
-                    } else {
-                        format!("")
-                    }
+                match now.checked_sub(next_turn) {
+                    Some(elapsed) => Some(time_remaining - Duration::seconds(elapsed as i64)),
+                    None => Some(time_remaining),
                 }
             }
-            None => {
-                if let Some(mut time) = self.player.time_remaining {
-                    if let Some(paused_time_delta) = self.player.paused_turn_delta {
-                        time = time.saturating_add(Duration::seconds(paused_time_delta));
-                    }
-
-                    self.time = time;
-                    format!("{}", TimerUI::human_time(self.time.whole_seconds(), false))
-                } else {
-                    format!("")
+            None => match self.player.paused_turn_delta {
+                Some(paused_time_delta) => {
+                    Some(time_remaining.saturating_add(Duration::seconds(paused_time_delta)))
                 }
+                None => Some(time_remaining),
+            },
+        }
+    }
+
+    /// How many seconds this player has spent in overtime, if they are in overtime
+    fn overtime_seconds(&self) -> Option<i64> {
+        if self.depot.gameplay.winner.is_some() || self.depot.gameplay.drawn {
+            return None;
+        }
+
+        let time = self.current_time_remaining()?;
+        if time.is_negative() {
+            Some(-time.whole_seconds())
+        } else {
+            None
+        }
+    }
+
+    fn calculate_time(&mut self) -> String {
+        if let Some(time) = self.current_time_remaining() {
+            self.time = time;
+            TimerUI::human_time(self.time.whole_seconds(), false)
+        } else {
+            "".to_string()
+        }
+    }
+
+    fn calculate_overtime_status(&self) -> Option<String> {
+        let overtime = self.overtime_seconds()?;
+        let next_in = |period: usize| {
+            let period = period.max(1) as i64;
+            TimerUI::human_time(period - (overtime % period), true)
+        };
+
+        match self.overtime_rule? {
+            OvertimeRule::FreeWildcard { period } => {
+                Some(format!("Wildcard granted in {}", next_in(*period)))
+            }
+            OvertimeRule::Bomb { period } => Some(format!("Bomb sent in {}", next_in(*period))),
+            OvertimeRule::RemoveTiles { period, .. } => {
+                Some(format!("Tile removed in {}", next_in(*period)))
             }
+            OvertimeRule::Elimination => Some("Out of time!".into()),
         }
     }
 
@@ -132,6 +175,9 @@ impl<'a> TimerUI<'a> {
             Some(_) => {
                 return "Defeated".into();
             }
+            None if self.depot.gameplay.drawn => {
+                return "Draw".into();
+            }
             _ => {}
         };
 
@@ -139,6 +185,10 @@ impl<'a> TimerUI<'a> {
             return format!("Game is paused!");
         }
 
+        if let Some(overtime_status) = self.calculate_overtime_status() {
+            return overtime_status;
+        }
+
         match self.player.turn_starts_no_later_than {
             Some(next_turn) => {
                 let now = self.depot.timing.current_time.as_secs();
@@ -170,6 +220,11 @@ impl<'a> TimerUI<'a> {
         let inner_timer_rect = ui.available_rect_before_wrap();
         ui.allocate_rect(inner_timer_rect, Sense::hover());
 
+        // Overtime status counts down every second, so keep it fresh
+        if self.overtime_seconds().is_some() {
+            ui.ctx().request_repaint();
+        }
+
         // Paint bar background
         let mut bar = inner_timer_rect.clone();
         bar.set_bottom(bar.top() + bar_h);
@@ -272,6 +327,11 @@ impl<'a> TimerUI<'a> {
         } else {
             self.player.name.clone()
         };
+        // Under fog of war, show how many of the opponents' tiles are in sight
+        let text = match self.depot.gameplay.visible_enemy_tiles {
+            Some(visible) if !self.friend => format!("{text} ({visible} in sight)"),
+            _ => text,
+        };
 
         // Render the player name
         let text = TextHelper::heavy(&text, font_z, None, ui);
@@ -10,6 +10,7 @@ use eframe::{
 use crate::{
     lil_bits::{DictionaryUI, HandUI},
     utils::{
+        depot::RematchStatus,
         tex::{render_tex_quad, tiles},
         text::TextHelper,
     },
@@ -115,19 +116,55 @@ impl ActiveGame {
 
                     ui.add_space(10.0);
 
-                    if self.depot.gameplay.winner.is_some() {
+                    if self.depot.gameplay.winner.is_some() || self.depot.gameplay.drawn {
                         if matches!(self.location, GameLocation::Online) {
-                            let text = TextHelper::heavy("REMATCH", 12.0, None, ui);
-                            if text
-                                .centered_button(
-                                    self.depot.aesthetics.theme.button_primary,
-                                    self.depot.aesthetics.theme.text,
-                                    &self.depot.aesthetics.map_texture,
-                                    ui,
-                                )
-                                .clicked()
-                            {
-                                msg = Some(PlayerMessage::Rematch);
+                            match self.depot.gameplay.rematch {
+                                RematchStatus::Undecided | RematchStatus::Offered => {
+                                    let offered =
+                                        self.depot.gameplay.rematch == RematchStatus::Offered;
+                                    let label = if offered { "ACCEPT REMATCH" } else { "REMATCH" };
+                                    let text = TextHelper::heavy(label, 12.0, None, ui);
+                                    if text
+                                        .centered_button(
+                                            self.depot.aesthetics.theme.button_primary,
+                                            self.depot.aesthetics.theme.text,
+                                            &self.depot.aesthetics.map_texture,
+                                            ui,
+                                        )
+                                        .clicked()
+                                    {
+                                        msg = Some(PlayerMessage::Rematch);
+                                        self.depot.gameplay.rematch = RematchStatus::Requested;
+                                    }
+
+                                    if offered {
+                                        ui.add_space(10.0);
+                                        let text = TextHelper::heavy("DECLINE", 12.0, None, ui);
+                                        if text
+                                            .centered_button(
+                                                self.depot.aesthetics.theme.button_secondary,
+                                                self.depot.aesthetics.theme.text,
+                                                &self.depot.aesthetics.map_texture,
+                                                ui,
+                                            )
+                                            .clicked()
+                                        {
+                                            msg = Some(PlayerMessage::DeclineRematch);
+                                            self.depot.gameplay.rematch = RematchStatus::Declined;
+                                        }
+                                    }
+                                }
+                                RematchStatus::Requested => {
+                                    TextHelper::heavy("WAITING FOR OPPONENT", 12.0, None, ui)
+                                        .paint(self.depot.aesthetics.theme.text, ui, true);
+                                }
+                                RematchStatus::Declined => {
+                                    TextHelper::heavy("REMATCH DECLINED", 12.0, None, ui).paint(
+                                        self.depot.aesthetics.theme.text,
+                                        ui,
+                                        true,
+                                    );
+                                }
                             }
 
                             ui.add_space(20.0);
@@ -1,6 +1,6 @@
 use epaint::{emath::Align2, vec2, Rect, Vec2};
 
-use truncate_core::messages::PlayerMessage;
+use truncate_core::{messages::PlayerMessage, rules::Timing};
 
 use eframe::{
     egui::{self, CursorIcon, Layout, Order, Sense},
@@ -118,6 +118,10 @@ impl ActiveGame {
                             ui.add_space(item_spacing);
 
                             let timer_width = (total_width - item_spacing * 3.0) / 2.0;
+                            let overtime_rule = game_ref.and_then(|g| match &g.rules.timing {
+                                Timing::PerPlayer { overtime_rule, .. } => Some(overtime_rule),
+                                _ => None,
+                            });
 
                             if let Some(player) = self
                                 .players
@@ -134,6 +138,7 @@ impl ActiveGame {
                                                 .next_player_number
                                                 .is_some_and(|n| n == player.index as u64),
                                     )
+                                    .overtime_rule(overtime_rule)
                                     .render(Some(timer_width), false, ui);
                             }
 
@@ -155,6 +160,7 @@ impl ActiveGame {
                                                 .is_some_and(|n| n == opponent.index as u64),
                                     )
                                     .right_align()
+                                    .overtime_rule(overtime_rule)
                                     .render(Some(timer_width), false, ui);
                             }
 
@@ -21,7 +21,7 @@ use crate::{
         control_devices,
         depot::{
             AestheticDepot, AudioDepot, BoardDepot, GameplayDepot, InteractionDepot, RegionDepot,
-            TimingDepot, TruncateDepot, UIStateDepot,
+            RematchStatus, TimingDepot, TruncateDepot, UIStateDepot,
         },
         mapper::{MappedBoard, MappedTiles},
         timing::get_qs_tick,
@@ -110,10 +110,14 @@ impl ActiveGame {
                 next_player_number,
                 error_msg: None,
                 winner: None,
+                drawn: false,
+                rematch: RematchStatus::default(),
                 changes: Vec::new(),
                 last_battle_origin: None,
                 npc,
                 remaining_turns,
+                revealing_squares: vec![],
+                visible_enemy_tiles: None,
             },
             aesthetics: AestheticDepot {
                 theme: theme.clone(),
@@ -122,6 +126,7 @@ impl ActiveGame {
                 player_colors,
                 destruction_tick: 0.05,
                 destruction_duration: 0.6,
+                reveal_duration: 1.0,
             },
             audio: AudioDepot::default(),
         };
@@ -175,6 +180,12 @@ impl ActiveGame {
             self.depot.aesthetics.qs_tick = cur_tick;
         }
 
+        // Any click skips the end of game reveal of fogged tiles
+        if !self.depot.gameplay.revealing_squares.is_empty() && ui.input(|i| i.pointer.any_click())
+        {
+            self.depot.gameplay.revealing_squares.clear();
+        }
+
         let kb_msg = control_devices::keyboard::handle_input(
             ui.ctx(),
             &self.board,
@@ -258,7 +269,12 @@ impl ActiveGame {
             .or(dict_player_message)
             .or(sidebar_player_message);
 
-        kb_msg.or(player_message)
+        kb_msg.or(player_message).filter(|msg| {
+            self.depot
+                .interactions
+                .move_debounce
+                .allow(msg, current_time)
+        })
     }
 
     pub fn apply_new_timing(&mut self, state_message: GameStateMessage) {
@@ -273,10 +289,14 @@ impl ActiveGame {
             game_ends_at,
             paused,
             remaining_turns: _,
+            game_end: _,
+            visible_enemy_tiles,
+            view: _,
         } = state_message;
 
         self.players = players;
         self.depot.timing.game_ends_at = game_ends_at;
+        self.depot.gameplay.visible_enemy_tiles = visible_enemy_tiles;
 
         self.depot.timing.paused = paused;
     }
@@ -293,6 +313,9 @@ impl ActiveGame {
             game_ends_at,
             paused,
             remaining_turns,
+            game_end: _,
+            visible_enemy_tiles,
+            view: _,
         } = state_message;
 
         // assert_eq!(self.room_code, room_code);
@@ -322,6 +345,7 @@ impl ActiveGame {
         self.depot.timing.game_ends_at = game_ends_at;
         self.depot.timing.paused = paused;
         self.depot.gameplay.remaining_turns = remaining_turns;
+        self.depot.gameplay.visible_enemy_tiles = visible_enemy_tiles;
 
         self.depot.gameplay.changes = changes.clone();
 
@@ -380,6 +404,7 @@ impl ActiveGame {
         // TODO: Verify that our modified hand matches the actual hand in GameStateMessage
 
         self.depot.interactions.playing_tile = None;
+        self.depot.interactions.move_debounce.acknowledge();
         self.depot.gameplay.error_msg = None;
     }
 }
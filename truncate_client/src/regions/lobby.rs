@@ -64,6 +64,7 @@ impl Lobby {
             player_colors,
             destruction_tick: 0.0,
             destruction_duration: 0.0,
+            reveal_duration: 0.0,
         };
 
         Self {
@@ -93,6 +93,7 @@ pub fn render_native_menu_if_required(
                 send_to_server(PlayerMessage::NewGame {
                     player_name: outer.name.clone(),
                     effective_day: outer.launched_at_day,
+                    private: false,
                 });
                 return Some(GameStatus::PendingCreate);
             }
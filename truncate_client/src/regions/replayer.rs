@@ -10,7 +10,7 @@ use truncate_core::{
 use crate::{
     app_outer::Backchannel,
     utils::{
-        depot::{AestheticDepot, GameplayDepot, TimingDepot},
+        depot::{AestheticDepot, GameplayDepot, RematchStatus, TimingDepot},
         game_evals::get_main_dict,
         mapper::MappedBoard,
         text::TextHelper,
@@ -78,6 +78,7 @@ impl ReplayerState {
             player_colors,
             destruction_tick: 0.05,
             destruction_duration: 0.6,
+            reveal_duration: 1.0,
         };
         let mapped_board = MappedBoard::new(ctx, &aesthetics, &game.board, 2, as_player, true);
 
@@ -87,10 +88,14 @@ impl ReplayerState {
             next_player_number: game.next_player.map(|p| p as u64),
             error_msg: None,
             winner: None,
+            drawn: false,
+            rematch: RematchStatus::default(),
             changes: vec![],
             last_battle_origin: None,
             npc: None,
             remaining_turns: None,
+            revealing_squares: vec![],
+            visible_enemy_tiles: None,
         };
 
         game.start();
@@ -2,7 +2,7 @@ use eframe::egui::{self, Layout, Sense};
 use epaint::{emath::Align, hex_color, vec2, TextureHandle};
 use instant::Duration;
 use truncate_core::{
-    board::Board,
+    board::{Board, ViewTransform},
     game::{Game, GAME_COLOR_BLUE, GAME_COLOR_RED},
     generation::BoardSeed,
     messages::{DailyStats, GamePlayerMessage, GameStateMessage, PlayerMessage},
@@ -370,6 +370,9 @@ impl SinglePlayerState {
                     game_ends_at: None,
                     paused: false,
                     remaining_turns: None,
+                    game_end: self.game.game_end.clone(),
+                    visible_enemy_tiles: self.game.visible_enemy_tiles(human_player),
+                    view: ViewTransform::Upright,
                 };
                 self.active_game.apply_new_state(state_message);
 
@@ -377,6 +380,11 @@ impl SinglePlayerState {
             }
             Err(msg) => {
                 self.active_game.depot.gameplay.error_msg = Some(msg);
+                self.active_game
+                    .depot
+                    .interactions
+                    .move_debounce
+                    .acknowledge();
                 return Err(());
             }
         }
@@ -654,10 +662,16 @@ impl SinglePlayerState {
                 tile,
                 position,
             }),
+            Some((player, PlayerMessage::PlaceMany(placements))) => {
+                Some(Move::PlaceMany { player, placements })
+            }
             Some((player, PlayerMessage::Swap(from, to))) => Some(Move::Swap {
                 player,
                 positions: [from, to],
             }),
+            Some((player, PlayerMessage::Exchange(indices))) => {
+                Some(Move::Exchange { player, indices })
+            }
             _ => None,
         };
 
@@ -6,7 +6,7 @@ use instant::Duration;
 use serde::Deserialize;
 use truncate_core::{
     bag::TileBag,
-    board::{Board, Coordinate},
+    board::{Board, Coordinate, ViewTransform},
     game::{Game, GAME_COLOR_BLUE, GAME_COLOR_RED},
     judge::Judge,
     messages::{GamePlayerMessage, GameStateMessage, PlayerMessage},
@@ -114,6 +114,15 @@ impl TutorialStage {
                     self.active_game.depot.interactions.highlight_squares =
                         Some(positions.to_vec());
                 }
+                Move::PlaceMany { placements, .. } => {
+                    let (positions, tiles) = placements.into_iter().unzip();
+                    self.active_game.depot.interactions.highlight_tiles = Some(tiles);
+                    self.active_game.depot.interactions.highlight_squares = Some(positions);
+                }
+                Move::Exchange { .. } => {
+                    self.active_game.depot.interactions.highlight_tiles = None;
+                    self.active_game.depot.interactions.highlight_squares = None;
+                }
             }
         } else {
             self.active_game.depot.interactions.highlight_tiles = None;
@@ -179,6 +188,9 @@ impl TutorialStage {
                     game_ends_at: None,
                     paused: false,
                     remaining_turns: None,
+                    game_end: self.game.game_end.clone(),
+                    visible_enemy_tiles: None,
+                    view: ViewTransform::Upright,
                 };
                 self.active_game.apply_new_state(state_message);
                 self.active_game.depot.gameplay.winner = possible_winner;
@@ -305,6 +317,12 @@ impl TutorialState {
                 next_player: Some(0),
                 paused: false,
                 winner: None,
+                game_end: None,
+                word_judgements: Default::default(),
+                move_durations: vec![vec![], vec![]],
+                consecutive_swaps: 0,
+                cooling_squares: Default::default(),
+                clock: truncate_core::game::now,
             };
 
             let mut active_game = ActiveGame::new(
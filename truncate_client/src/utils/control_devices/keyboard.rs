@@ -41,7 +41,7 @@ pub fn handle_input(
             depot.interactions.selected_square_on_board = Some((coord.clone(), sq));
             return coord;
         }
-        let artifact = board.artifacts.iter().find(|d| {
+        let artifact = board.artifacts().iter().find(|d| {
             board.get(**d).is_ok_and(
                 |s| matches!(s, Square::Artifact{player: p, ..} if p == depot.gameplay.player_number as usize),
             )
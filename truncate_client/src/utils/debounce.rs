@@ -0,0 +1,78 @@
+use instant::Duration;
+use truncate_core::messages::PlayerMessage;
+
+/// How long a move is held as pending before an identical one may be sent again,
+/// in case the server's response never arrives.
+pub const MOVE_DEBOUNCE_WINDOW: Duration = Duration::from_millis(1500);
+
+/// Drops repeated move submissions while the first is still waiting on the server,
+/// so that a laggy connection doesn't turn one click into two placements.
+#[derive(Clone, Default)]
+pub struct MoveDebounce {
+    pending: Option<(PlayerMessage, Duration)>,
+}
+
+impl MoveDebounce {
+    /// Whether `msg` should be sent, recording it as pending if it is a move.
+    pub fn allow(&mut self, msg: &PlayerMessage, now: Duration) -> bool {
+        if !matches!(
+            msg,
+            PlayerMessage::Place(..)
+                | PlayerMessage::PlaceMany(_)
+                | PlayerMessage::Swap(..)
+                | PlayerMessage::Exchange(_)
+        ) {
+            return true;
+        }
+
+        if let Some((pending, sent_at)) = &self.pending {
+            if pending == msg && now.saturating_sub(*sent_at) < MOVE_DEBOUNCE_WINDOW {
+                return false;
+            }
+        }
+
+        self.pending = Some((msg.clone(), now));
+        true
+    }
+
+    /// Called once the server has responded to the pending move, either applying or rejecting it.
+    pub fn acknowledge(&mut self) {
+        self.pending = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use truncate_core::board::Coordinate;
+
+    use super::*;
+
+    #[test]
+    fn drops_duplicate_moves_within_window() {
+        let mut debounce = MoveDebounce::default();
+        let place = PlayerMessage::Place(Coordinate::new(1, 1), 'A');
+        let start = Duration::from_secs(100);
+
+        assert!(debounce.allow(&place, start));
+        assert!(!debounce.allow(&place, start + Duration::from_millis(200)));
+
+        // Different moves and non-move messages are never held back
+        assert!(debounce.allow(&PlayerMessage::Ping, start));
+        let other = PlayerMessage::Place(Coordinate::new(2, 1), 'A');
+        assert!(debounce.allow(&other, start + Duration::from_millis(300)));
+
+        assert!(debounce.allow(&place, start + Duration::from_millis(400)));
+        assert!(debounce.allow(&place, start + MOVE_DEBOUNCE_WINDOW * 2));
+    }
+
+    #[test]
+    fn acknowledged_moves_can_be_repeated() {
+        let mut debounce = MoveDebounce::default();
+        let swap = PlayerMessage::Swap(Coordinate::new(1, 1), Coordinate::new(1, 2));
+        let start = Duration::from_secs(100);
+
+        assert!(debounce.allow(&swap, start));
+        debounce.acknowledge();
+        assert!(debounce.allow(&swap, start + Duration::from_millis(100)));
+    }
+}
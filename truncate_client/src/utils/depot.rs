@@ -10,7 +10,7 @@ use truncate_core::{
 
 use crate::regions::active_game::HeaderType;
 
-use super::Theme;
+use super::{debounce::MoveDebounce, Theme};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct HoveredRegion {
@@ -37,6 +37,8 @@ pub struct InteractionDepot {
     pub selected_tile_in_hand: Option<(usize, char)>,
     pub highlight_tiles: Option<Vec<char>>,
     pub highlight_squares: Option<Vec<Coordinate>>,
+    pub previewed_word_squares: Option<Vec<Coordinate>>,
+    pub move_debounce: MoveDebounce,
 }
 
 #[derive(Clone, Default)]
@@ -90,6 +92,19 @@ pub struct TimingDepot {
     pub paused: bool,
 }
 
+/// Where the players of a finished online game stand on playing again
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum RematchStatus {
+    #[default]
+    Undecided,
+    /// We asked for a rematch and are waiting on our opponent
+    Requested,
+    /// Our opponent asked for a rematch and is waiting on us
+    Offered,
+    /// Someone turned the rematch down
+    Declined,
+}
+
 #[derive(Clone)]
 pub struct GameplayDepot {
     pub room_code: RoomCode,
@@ -97,10 +112,17 @@ pub struct GameplayDepot {
     pub next_player_number: Option<u64>,
     pub error_msg: Option<String>,
     pub winner: Option<usize>,
+    /// Whether the game ended level, with nobody winning
+    pub drawn: bool,
+    pub rematch: RematchStatus,
     pub changes: Vec<Change>,
     pub last_battle_origin: Option<Coordinate>,
     pub npc: Option<NPCPersonality>,
     pub remaining_turns: Option<u64>,
+    /// Tiles that were under fog until the game ended, and are fading into view
+    pub revealing_squares: Vec<Coordinate>,
+    /// How many enemy tiles the player can currently see, when fog of war is hiding the rest
+    pub visible_enemy_tiles: Option<usize>,
 }
 
 #[derive(Clone)]
@@ -111,6 +133,7 @@ pub struct AestheticDepot {
     pub player_colors: Vec<Color32>,
     pub destruction_tick: f32,
     pub destruction_duration: f32,
+    pub reveal_duration: f32,
 }
 
 #[derive(Clone, Default)]
@@ -1,4 +1,5 @@
 use epaint::{Color32, ColorImage, Rgba};
+use truncate_core::board::ViewTransform;
 
 // Sometimes we prefer to mush images in CPU-space so that they can live on the same texture,
 // without the GPU having to tint multiple UV rects when painting.
@@ -9,6 +10,8 @@ pub trait ImageMusher {
     fn recolor(&mut self, color: &Color32);
     fn trim(&mut self);
     fn flip_y(&mut self);
+    fn rotate_cw(&mut self);
+    fn turn(&mut self, transform: ViewTransform);
 }
 
 impl ImageMusher for ColorImage {
@@ -113,6 +116,29 @@ impl ImageMusher for ColorImage {
     fn flip_y(&mut self) {
         self.pixels.reverse();
     }
+
+    fn rotate_cw(&mut self) {
+        let [width, height] = self.size;
+        let mut rotated = ColorImage::new([height, width], Color32::TRANSPARENT);
+        for y in 0..height {
+            for x in 0..width {
+                rotated[(height - 1 - y, x)] = self[(x, y)];
+            }
+        }
+        *self = rotated;
+    }
+
+    fn turn(&mut self, transform: ViewTransform) {
+        match transform {
+            ViewTransform::Upright => {}
+            ViewTransform::Rotate90 => self.rotate_cw(),
+            ViewTransform::Rotate180 => self.flip_y(),
+            ViewTransform::Rotate270 => {
+                self.rotate_cw();
+                self.flip_y();
+            }
+        }
+    }
 }
 
 pub fn alpha_blend(base: Color32, overlay: Color32, overlay_opacity: Option<f32>) -> Color32 {
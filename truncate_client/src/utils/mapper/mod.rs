@@ -22,6 +22,7 @@ use super::{
     },
     glyph_utils::Glypher,
     tex::{self, BGTexType, PieceLayer, Tex, TexLayers, TileDecoration},
+    timing::AnimationPhase,
     Lighten,
 };
 
@@ -350,9 +351,23 @@ impl MappedBoard {
                 .is_some_and(|c| i.highlight_squares.as_ref().is_some_and(|s| s.contains(&c)))
         });
 
+        let square_is_previewed = interactions.is_some_and(|i| {
+            coord.real_coord().is_some_and(|c| {
+                i.previewed_word_squares
+                    .as_ref()
+                    .is_some_and(|s| s.contains(&c))
+            })
+        });
+
+        let square_is_revealing = gameplay.is_some_and(|g| {
+            coord
+                .real_coord()
+                .is_some_and(|c| g.revealing_squares.contains(&c))
+        });
+
         let mut tile_was_added = false;
         let mut tile_was_swapped = false;
-        let mut tile_was_victor = false;
+        let mut victor_time = None;
 
         let base_destructo_time = (timing.current_time - timing.last_turn_change).as_secs_f32();
         let mut destructo_time = base_destructo_time;
@@ -388,7 +403,7 @@ impl MappedBoard {
                     .unwrap_or_default()
             };
 
-            let mut animated_variant = |player: usize| {
+            let mut animated_variant = |player: usize, destructo_time: f32| {
                 if destructo_time < aesthetics.destruction_duration {
                     wants_repaint = true;
                     (MappedTileVariant::Healthy, Some(base_color(player)))
@@ -398,6 +413,15 @@ impl MappedBoard {
             };
 
             for change in changes {
+                // Each change waits for the changes in earlier phases to play out
+                let delay = AnimationPhase::of(&change.action).delay(
+                    &gameplay.changes,
+                    &aesthetics.theme,
+                    aesthetics.destruction_duration,
+                );
+                let phase_time = base_destructo_time - delay;
+                let destructo_time = (destructo_time - delay).max(0.0);
+
                 match change.action {
                     BoardChangeAction::Added => {
                         tile_was_added = true;
@@ -406,7 +430,7 @@ impl MappedBoard {
                         tile_was_swapped = true;
                     }
                     BoardChangeAction::Victorious => {
-                        tile_was_victor = true;
+                        victor_time = Some(phase_time);
                     }
                     BoardChangeAction::Defeated => {
                         // TODO: We could use `validity` below to show whether a tile
@@ -419,9 +443,8 @@ impl MappedBoard {
                         } = change.detail.square
                         {
                             let validity_color =
-                                if base_destructo_time < aesthetics.destruction_duration {
-                                    let traj = ((aesthetics.destruction_duration
-                                        - base_destructo_time)
+                                if (0.0..aesthetics.destruction_duration).contains(&phase_time) {
+                                    let traj = ((aesthetics.destruction_duration - phase_time)
                                         .clamp(0.0, 1.0)
                                         / aesthetics.destruction_duration)
                                         .sqrt();
@@ -435,7 +458,7 @@ impl MappedBoard {
                                 } else {
                                     None
                                 };
-                            let (variant, color) = animated_variant(player);
+                            let (variant, color) = animated_variant(player, destructo_time);
 
                             let tile_layers = Tex::board_game_tile(
                                 variant,
@@ -449,7 +472,7 @@ impl MappedBoard {
                             layers = layers.merge_below_self(tile_layers);
                         }
                     }
-                    BoardChangeAction::Truncated => {
+                    BoardChangeAction::Truncated | BoardChangeAction::Neutralized => {
                         // TODO: We could use `validity` below to show whether a tile
                         // lost on length or lost on being invalid.
                         if let Occupied {
@@ -459,7 +482,7 @@ impl MappedBoard {
                             ..
                         } = change.detail.square
                         {
-                            let (variant, color) = animated_variant(player);
+                            let (variant, color) = animated_variant(player, destructo_time);
 
                             let tile_layers = Tex::board_game_tile(
                                 variant,
@@ -483,7 +506,7 @@ impl MappedBoard {
                             ..
                         } = change.detail.square
                         {
-                            let (variant, color) = animated_variant(player);
+                            let (variant, color) = animated_variant(player, destructo_time);
 
                             let tile_layers = Tex::board_game_tile(
                                 variant,
@@ -597,13 +620,23 @@ impl MappedBoard {
                     player_colors.get(*player).cloned().map(|c| c.lighten())
                 };
 
-                if tile_was_victor && base_destructo_time < aesthetics.destruction_duration {
+                if let Some(victor_time) =
+                    victor_time.filter(|t| *t < aesthetics.destruction_duration)
+                {
                     wants_repaint = true;
-                    let traj = ((aesthetics.destruction_duration - base_destructo_time)
-                        .clamp(0.0, 1.0)
-                        / aesthetics.destruction_duration)
-                        .sqrt();
-                    color = color.map(|c| alpha_blend(c, aesthetics.theme.word_valid, Some(traj)));
+                    // Victors look as placed until the battle phase starts
+                    if victor_time >= 0.0 {
+                        let traj = ((aesthetics.destruction_duration - victor_time)
+                            .clamp(0.0, 1.0)
+                            / aesthetics.destruction_duration)
+                            .sqrt();
+                        color =
+                            color.map(|c| alpha_blend(c, aesthetics.theme.word_valid, Some(traj)));
+                    }
+                }
+
+                if square_is_previewed {
+                    color = Some(aesthetics.theme.ring_selected);
                 }
 
                 if square_is_highlighted && (tick % 4 < 2) {
@@ -622,6 +655,19 @@ impl MappedBoard {
                     }
                 }
 
+                // Tiles that were under fog fade in once any battle from the final turn has played out
+                if square_is_revealing {
+                    let reveal_time = base_destructo_time - aesthetics.destruction_duration;
+                    if reveal_time < aesthetics.reveal_duration {
+                        wants_repaint = true;
+                        let traj = (reveal_time / aesthetics.reveal_duration).clamp(0.0, 1.0);
+                        color = color.map(|c| alpha_blend(aesthetics.theme.water, c, Some(traj)));
+                        if traj < 0.5 {
+                            layers.mist = Some([tex::tiles::BASE_WATER; 4]);
+                        }
+                    }
+                }
+
                 let tile_layers = Tex::board_game_tile(
                     variant,
                     render_as_swap.unwrap_or(*tile),
@@ -841,12 +887,10 @@ impl MappedBoard {
                                     target.hard_overlay(&image, sub_loc);
                                 }
                             }
-                            tex::PieceLayer::Character(char, color, is_flipped, y_offset) => {
+                            tex::PieceLayer::Character(char, color, turn, y_offset) => {
                                 let mut glyph = glypher.paint(*char, 16);
 
-                                if *is_flipped {
-                                    glyph.flip_y();
-                                }
+                                glyph.turn(*turn);
 
                                 let offset = [
                                     (target.width() - glyph.width()) / 2,
@@ -1207,12 +1251,10 @@ impl MappedTiles {
                             target.hard_overlay(&image, sub_loc);
                         }
                     }
-                    tex::PieceLayer::Character(char, color, is_flipped, y_offset) => {
+                    tex::PieceLayer::Character(char, color, turn, y_offset) => {
                         let mut glyph = glypher.paint(*char, 16);
 
-                        if *is_flipped {
-                            glyph.flip_y();
-                        }
+                        glyph.turn(*turn);
 
                         let offset = [
                             (target.width() - glyph.width()) / 2,
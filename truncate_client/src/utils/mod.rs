@@ -1,11 +1,13 @@
 pub mod control_devices;
 pub mod daily;
+pub mod debounce;
 pub mod depot;
 pub mod game_evals;
 pub mod glyph_utils;
 pub mod includes;
 pub mod macros;
 pub mod mapper;
+pub mod reveal;
 pub mod tex;
 pub mod text;
 pub mod theming;
@@ -0,0 +1,114 @@
+use truncate_core::{
+    board::{Board, Coordinate, Square},
+    reporting::{BoardChangeAction, Change},
+};
+
+/// Finds the tiles on the fully visible end-of-game board that `player` couldn't see
+/// on their last fogged board, so that they can be faded in rather than appearing at once.
+///
+/// Fogged boards may have been trimmed, so the player's own artifact is used to line the two boards up.
+/// Tiles placed in plain view by the final move are left to the usual placement animation.
+pub fn hidden_squares(
+    previous: &Board,
+    revealed: &Board,
+    player: usize,
+    changes: &[Change],
+) -> Vec<Coordinate> {
+    let own_artifact = |board: &Board| {
+        board.artifacts().iter().copied().find(|artifact| {
+            matches!(board.get(*artifact), Ok(Square::Artifact { player: p, .. }) if p == player)
+        })
+    };
+    let (offset_x, offset_y) = match (own_artifact(previous), own_artifact(revealed)) {
+        (Some(before), Some(after)) => (
+            after.x as isize - before.x as isize,
+            after.y as isize - before.y as isize,
+        ),
+        _ => (0, 0),
+    };
+
+    let just_added = |coord: Coordinate| {
+        changes.iter().any(|change| {
+            matches!(
+                change,
+                Change::Board(b)
+                    if b.action == BoardChangeAction::Added && b.detail.coordinate == coord
+            )
+        })
+    };
+
+    let rows = revealed.height();
+    let cols = revealed.width();
+    (0..rows)
+        .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+        .map(|(x, y)| Coordinate { x, y })
+        .filter(|coord| {
+            matches!(revealed.get(*coord), Ok(Square::Occupied { player: p, .. }) if p != player)
+        })
+        .filter(|coord| {
+            let previous_x = coord.x as isize - offset_x;
+            let previous_y = coord.y as isize - offset_y;
+            if previous_x < 0 || previous_y < 0 {
+                return true;
+            }
+
+            match previous.get(Coordinate::new(previous_x as usize, previous_y as usize)) {
+                Err(_) | Ok(Square::Fog {}) => true,
+                Ok(square) if square.is_foggy() => true,
+                Ok(Square::Occupied { .. }) => false,
+                Ok(_) => !just_added(*coord),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use truncate_core::reporting::{BoardChange, BoardChangeDetail};
+
+    use super::*;
+
+    #[test]
+    fn only_hidden_tiles_are_revealed() {
+        // The fogged board was trimmed by a column on the left,
+        // the enemy tiles at the bottom were out of sight,
+        // and C1 was hidden as land.
+        let mut previous = Board::from_string(
+            "|0 ~~ ~~\n\
+             A0 B1 __\n\
+             __ __ __\n\
+             ~~ ~~ ~~",
+        );
+        for row in previous.squares.iter_mut().skip(2) {
+            row.fill(Square::fog());
+        }
+        let revealed = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             __ A0 B1 C1\n\
+             __ D1 E1 __\n\
+             ~~ ~~ |1 ~~",
+        );
+
+        assert_eq!(
+            hidden_squares(&previous, &revealed, 0, &[]),
+            vec![
+                Coordinate::new(3, 1),
+                Coordinate::new(1, 2),
+                Coordinate::new(2, 2)
+            ]
+        );
+
+        // Unless C1 was placed in plain view on the final turn
+        let placed = Change::Board(BoardChange {
+            detail: BoardChangeDetail {
+                square: revealed.get(Coordinate::new(3, 1)).unwrap(),
+                coordinate: Coordinate::new(3, 1),
+            },
+            action: BoardChangeAction::Added,
+        });
+        assert_eq!(
+            hidden_squares(&previous, &revealed, 0, &[placed]),
+            vec![Coordinate::new(1, 2), Coordinate::new(2, 2)]
+        );
+    }
+}
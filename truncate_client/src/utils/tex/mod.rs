@@ -3,7 +3,9 @@ use epaint::{
     hex_color, pos2, vec2, Color32, ColorImage, Mesh, Pos2, Rect, Shape, TextureHandle, TextureId,
     Vec2,
 };
-use truncate_core::board::{BoardDistances, Coordinate, Direction, SignedCoordinate, Square};
+use truncate_core::board::{
+    BoardDistances, Coordinate, Direction, SignedCoordinate, Square, ViewTransform,
+};
 
 use crate::{app_outer::TEXTURE_MEASUREMENT, regions::lobby::BoardEditingMode};
 
@@ -18,13 +20,12 @@ pub struct Tex {
 }
 
 pub type TexQuad = [Tex; 4];
-pub type IsFlipped = bool;
 pub type YOffset = isize;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum PieceLayer {
     Texture(TexQuad, Option<Color32>),
-    Character(char, Color32, IsFlipped, YOffset),
+    Character(char, Color32, ViewTransform, YOffset),
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
@@ -68,11 +69,11 @@ impl TexLayers {
         mut self,
         char: char,
         color: Color32,
-        flipped: IsFlipped,
+        turn: ViewTransform,
         y_offset: YOffset,
     ) -> Self {
         self.pieces
-            .push(PieceLayer::Character(char, color, flipped, y_offset));
+            .push(PieceLayer::Character(char, color, turn, y_offset));
         self
     }
 
@@ -223,7 +224,7 @@ impl Tex {
             .with_piece_character(
                 character,
                 hex_color!("#333333"),
-                orientation != Direction::North,
+                orientation.glyph_transform(),
                 -1,
             );
 
@@ -337,7 +338,7 @@ impl Tex {
                     .with_piece_character(
                         character,
                         hex_color!("#888888"),
-                        orientation != Direction::North,
+                        orientation.glyph_transform(),
                         0,
                     );
             }
@@ -27,6 +27,10 @@ pub struct Theme {
     pub tile_margin: f32,
     pub rounding: f32,
     pub animation_time: f32,
+    /// Seconds between the attacking tiles landing and the defeated tiles starting to fade
+    pub battle_pause: f32,
+    /// Seconds between the battle finishing and truncated tiles starting to fade
+    pub truncation_pause: f32,
     pub mobile_breakpoint: f32,
 }
 
@@ -55,6 +59,8 @@ impl Theme {
             tile_margin: 4.0,
             rounding: 10.0,
             animation_time: 0.05,
+            battle_pause: 0.3,
+            truncation_pause: 0.2,
             mobile_breakpoint: 800.0,
         }
     }
@@ -83,6 +89,8 @@ impl Theme {
             tile_margin: 4.0,
             rounding: 10.0,
             animation_time: 0.05,
+            battle_pause: 0.3,
+            truncation_pause: 0.2,
             mobile_breakpoint: 800.0,
         }
     }
@@ -111,6 +119,8 @@ impl Theme {
             tile_margin: 4.0,
             rounding: 10.0,
             animation_time: 0.05,
+            battle_pause: 0.3,
+            truncation_pause: 0.2,
             mobile_breakpoint: 800.0,
         }
     }
@@ -139,11 +149,82 @@ impl Theme {
             tile_margin: 4.0,
             rounding: 10.0,
             animation_time: 0.05,
+            battle_pause: 0.3,
+            truncation_pause: 0.2,
             mobile_breakpoint: 800.0,
         }
     }
 }
 
+/// Named palettes for the board's surroundings, swapping out the water, grass and text colours of a `Theme`.
+/// Each palette has a day and a night variant, following the theme it is applied to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BoardTheme {
+    #[default]
+    Classic,
+    Desert,
+    Ocean,
+}
+
+impl BoardTheme {
+    pub const ALL: [BoardTheme; 3] = [BoardTheme::Classic, BoardTheme::Desert, BoardTheme::Ocean];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BoardTheme::Classic => "classic",
+            BoardTheme::Desert => "desert",
+            BoardTheme::Ocean => "ocean",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|theme| theme.name().eq_ignore_ascii_case(name))
+    }
+}
+
+impl Theme {
+    /// Recolours the theme with a board palette, keeping to the theme's time of day.
+    pub fn with_board_theme(self, board_theme: BoardTheme) -> Self {
+        let (water, grass, text, faded) = match (board_theme, self.daytime) {
+            (BoardTheme::Classic, _) => return self,
+            (BoardTheme::Desert, true) => (
+                hex_color!("#E9B872"),
+                hex_color!("#D7C08A"),
+                hex_color!("#333333"),
+                hex_color!("#6B5A3E"),
+            ),
+            (BoardTheme::Desert, false) => (
+                hex_color!("#2B1D0E"),
+                hex_color!("#4A3418"),
+                hex_color!("#FFFFFF"),
+                hex_color!("#D9C7A8"),
+            ),
+            (BoardTheme::Ocean, true) => (
+                hex_color!("#5CC8E8"),
+                hex_color!("#8FD9C9"),
+                hex_color!("#1F2D3D"),
+                hex_color!("#38566E"),
+            ),
+            (BoardTheme::Ocean, false) => (
+                hex_color!("#041C32"),
+                hex_color!("#0B3B4F"),
+                hex_color!("#FFFFFF"),
+                hex_color!("#B8D8E8"),
+            ),
+        };
+
+        Self {
+            water,
+            grass,
+            text,
+            faded,
+            ..self
+        }
+    }
+}
+
 impl Theme {
     pub fn calc_rescale(
         &self,
@@ -247,3 +328,56 @@ impl Lighten for Color32 {
         color.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The WCAG contrast ratio between two colours, from 1 for identical colours up to 21 for black on white
+    fn contrast_ratio(a: Color32, b: Color32) -> f32 {
+        let luminance = |c: Color32| {
+            let channel = |v: u8| {
+                let v = v as f32 / 255.0;
+                if v <= 0.03928 {
+                    v / 12.92
+                } else {
+                    ((v + 0.055) / 1.055).powf(2.4)
+                }
+            };
+            0.2126 * channel(c.r()) + 0.7152 * channel(c.g()) + 0.0722 * channel(c.b())
+        };
+        let (a, b) = (luminance(a), luminance(b));
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    #[test]
+    fn board_themes_keep_text_legible() {
+        for base in [Theme::day(), Theme::old_day(), Theme::night()] {
+            for board_theme in BoardTheme::ALL {
+                let theme = base.clone().with_board_theme(board_theme);
+                assert_eq!(theme.daytime, base.daytime);
+                for background in [theme.water, theme.grass] {
+                    let ratio = contrast_ratio(theme.text, background);
+                    assert!(
+                        ratio >= 4.5,
+                        "{} text has a contrast of {ratio} against {background:?}",
+                        board_theme.name()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn board_theme_names() {
+        for board_theme in BoardTheme::ALL {
+            assert_eq!(BoardTheme::from_name(board_theme.name()), Some(board_theme));
+        }
+        assert_eq!(BoardTheme::from_name("OCEAN"), Some(BoardTheme::Ocean));
+        assert_eq!(BoardTheme::from_name("jungle"), None);
+        assert_eq!(
+            Theme::day().with_board_theme(BoardTheme::Classic).water,
+            Theme::day().water
+        );
+    }
+}
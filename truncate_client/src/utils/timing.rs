@@ -1,5 +1,98 @@
 use instant::Duration;
+use truncate_core::reporting::{BoardChangeAction, Change};
+
+use super::Theme;
 
 pub fn get_qs_tick(current_time: Duration) -> u64 {
     current_time.as_secs() * 4 + current_time.subsec_millis() as u64 / 250
 }
+
+/// The order in which the board changes from a single turn are animated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AnimationPhase {
+    Placement,
+    Battle,
+    Truncation,
+}
+
+impl AnimationPhase {
+    pub fn of(action: &BoardChangeAction) -> Self {
+        match action {
+            BoardChangeAction::Added | BoardChangeAction::Swapped => Self::Placement,
+            BoardChangeAction::Victorious
+            | BoardChangeAction::Defeated
+            | BoardChangeAction::Exploded => Self::Battle,
+            BoardChangeAction::Truncated | BoardChangeAction::Neutralized => Self::Truncation,
+        }
+    }
+
+    /// Seconds after the turn change that changes in this phase should start animating.
+    /// Truncation waits for the battle to play out, but only if there was one.
+    pub fn delay(&self, changes: &[Change], theme: &Theme, destruction_duration: f32) -> f32 {
+        let had_battle = changes.iter().any(
+            |change| matches!(change, Change::Board(b) if Self::of(&b.action) == Self::Battle),
+        );
+
+        match self {
+            Self::Placement => 0.0,
+            Self::Battle => theme.battle_pause,
+            Self::Truncation if had_battle => {
+                theme.battle_pause + destruction_duration + theme.truncation_pause
+            }
+            Self::Truncation => theme.truncation_pause,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use truncate_core::{
+        board::{Coordinate, Square},
+        reporting::{BoardChange, BoardChangeDetail},
+    };
+
+    use super::*;
+
+    fn change(action: BoardChangeAction) -> Change {
+        Change::Board(BoardChange {
+            detail: BoardChangeDetail {
+                square: Square::land(),
+                coordinate: Coordinate::new(0, 0),
+            },
+            action,
+        })
+    }
+
+    #[test]
+    fn phases_play_in_order() {
+        let theme = Theme::day();
+        let battle = vec![
+            change(BoardChangeAction::Added),
+            change(BoardChangeAction::Victorious),
+            change(BoardChangeAction::Defeated),
+            change(BoardChangeAction::Truncated),
+        ];
+
+        let delays: Vec<_> = battle
+            .iter()
+            .map(|c| match c {
+                Change::Board(b) => AnimationPhase::of(&b.action).delay(&battle, &theme, 0.6),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(delays[0], 0.0);
+        assert_eq!(delays[1], theme.battle_pause);
+        assert_eq!(delays[1], delays[2]);
+        assert_eq!(delays[3], theme.battle_pause + 0.6 + theme.truncation_pause);
+
+        // Without a battle, truncation follows the placement directly
+        let quiet = vec![
+            change(BoardChangeAction::Added),
+            change(BoardChangeAction::Truncated),
+        ];
+        assert_eq!(
+            AnimationPhase::Truncation.delay(&quiet, &theme, 0.6),
+            theme.truncation_pause
+        );
+    }
+}
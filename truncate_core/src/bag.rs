@@ -1,7 +1,7 @@
 use oorandom::Rand32;
 use std::fmt;
 
-use crate::rules;
+use crate::{error::GamePlayError, rules};
 
 /*
 INFO: Letter distributions in Truncate's dict
@@ -100,6 +100,12 @@ pub struct TileBag {
     bag: Vec<char>,
     rng: Rand32,
     letter_distribution: Option<[usize; 26]>,
+    vowel_guarantee: Option<rules::VowelGuarantee>,
+    draws_without_vowel: usize,
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'A' | 'E' | 'I' | 'O' | 'U')
 }
 
 impl TileBag {
@@ -129,11 +135,39 @@ impl TileBag {
                     .as_secs()
             })),
             letter_distribution: Some(letter_distribution),
+            vowel_guarantee: None,
+            draws_without_vowel: 0,
         };
         tile_bag.fill();
         tile_bag
     }
 
+    /// Builds a bag from the rules' distribution, where the standard distribution is the given tile generation.
+    /// Custom distributions can only hold the letters A to Z, and must hold at least one tile.
+    pub fn from_distribution(
+        distribution: &rules::TileDistribution,
+        generation: u32,
+        seed: Option<u64>,
+    ) -> Result<Self, GamePlayError> {
+        match distribution {
+            rules::TileDistribution::Standard => Ok(TileBag::generation(generation, seed)),
+            rules::TileDistribution::Custom(counts) => {
+                let mut letter_distribution = [0; 26];
+                for (letter, count) in counts {
+                    let upper = letter.to_ascii_uppercase();
+                    if !upper.is_ascii_uppercase() {
+                        return Err(GamePlayError::UnknownTileLetter { letter: *letter });
+                    }
+                    letter_distribution[(upper as u8 - b'A') as usize] = *count;
+                }
+                if letter_distribution.iter().all(|count| *count == 0) {
+                    return Err(GamePlayError::EmptyTileDistribution);
+                }
+                Ok(TileBag::custom(letter_distribution, seed))
+            }
+        }
+    }
+
     pub fn explicit(tiles: Vec<char>, seed: Option<u64>) -> Self {
         TileBag {
             bag: tiles,
@@ -144,15 +178,44 @@ impl TileBag {
                     .as_secs()
             })),
             letter_distribution: None,
+            vowel_guarantee: None,
+            draws_without_vowel: 0,
         }
     }
 
+    pub fn with_vowel_guarantee(mut self, vowel_guarantee: Option<rules::VowelGuarantee>) -> Self {
+        self.vowel_guarantee = vowel_guarantee;
+        self
+    }
+
     pub fn draw_tile(&mut self) -> char {
         if self.bag.is_empty() {
             self.fill();
         }
-        let index = self.rng.rand_range(0..self.bag.len() as u32);
-        self.bag.swap_remove(index as usize)
+        let mut index = self.rng.rand_range(0..self.bag.len() as u32) as usize;
+
+        if let Some(guarantee) = &self.vowel_guarantee {
+            let running_low = self.bag.len() < guarantee.below;
+            let overdue = self.draws_without_vowel + 1 >= guarantee.within;
+            if running_low && overdue && !is_vowel(self.bag[index]) {
+                if let Some(vowel) = self.bag.iter().position(|c| is_vowel(*c)) {
+                    index = vowel;
+                }
+            }
+        }
+
+        let tile = self.bag.swap_remove(index);
+        if is_vowel(tile) {
+            self.draws_without_vowel = 0;
+        } else {
+            self.draws_without_vowel += 1;
+        }
+        tile
+    }
+
+    /// How many tiles can be drawn before the bag refills or runs out
+    pub fn remaining(&self) -> usize {
+        self.bag.len()
     }
 
     // TODO: this doesn't stop us from returning tiles that weren't originally in the bag
@@ -160,6 +223,31 @@ impl TileBag {
         self.bag.push(c);
     }
 
+    /// Pulls a specific letter back out of the bag, returning whether there was one to take.
+    /// The most recently returned copy is taken, so this undoes `return_tile` exactly.
+    /// Bags built from a letter distribution never run out, so any letter
+    /// in that distribution can always be taken, refilling the bag if needed.
+    pub fn take(&mut self, c: char) -> bool {
+        if !self.bag.contains(&c) && self.distributes(c) {
+            self.fill();
+        }
+
+        match self.bag.iter().rposition(|tile| *tile == c) {
+            Some(index) => {
+                self.bag.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn distributes(&self, c: char) -> bool {
+        let Some(letter_distribution) = self.letter_distribution else {
+            return false;
+        };
+        c.is_ascii_uppercase() && letter_distribution[(c as u8 - b'A') as usize] > 0
+    }
+
     fn fill(&mut self) {
         if let Some(letter_distribution) = self.letter_distribution {
             self.bag.extend(
@@ -196,6 +284,109 @@ pub mod tests {
         assert_eq!(drawn.filter(|&x| x == 'A').count(), 5);
     }
 
+    #[test]
+    fn take_undoes_return() {
+        let mut bag = a_b_bag();
+        let original = bag.clone();
+
+        bag.return_tile('C');
+        bag.return_tile('A');
+        assert!(bag.take('A'));
+        assert!(bag.take('C'));
+        assert_eq!(bag, original);
+
+        assert!(!bag.take('Z'));
+        assert_eq!(bag, original);
+    }
+
+    #[test]
+    fn take_specific_letters() {
+        let mut bag = TileBag::explicit(vec!['A', 'B', 'A'], Some(1));
+
+        assert!(bag.take('A'));
+        assert_eq!(bag.to_string(), "Letters in the bag:\n['A', 'B']");
+        assert!(bag.take('A'));
+        assert!(!bag.take('A'));
+        assert!(!bag.take('Z'));
+        assert_eq!(bag.to_string(), "Letters in the bag:\n['B']");
+    }
+
+    #[test]
+    fn take_from_refilling_bag() {
+        let mut bag = a_b_bag();
+
+        // Bags with a letter distribution refill rather than running out
+        assert!(bag.take('A'));
+        assert!(bag.take('A'));
+        assert!(bag.take('A'));
+        assert_eq!(bag.to_string(), "Letters in the bag:\n['B', 'B', 'B']");
+
+        // But can't produce letters they never contained
+        assert!(!bag.take('Z'));
+        assert!(!bag.take('¤'));
+    }
+
+    #[test]
+    fn guarantees_vowels_when_low() {
+        let low_bag = |seed| TileBag::explicit("BCDFGHAJ".chars().collect(), Some(seed));
+        let guarantee = rules::VowelGuarantee {
+            below: 10,
+            within: 3,
+        };
+
+        // Left to chance, the only vowel regularly misses the first three draws
+        assert!((0..20).any(|seed| {
+            let mut bag = low_bag(seed);
+            (0..3).all(|_| bag.draw_tile() != 'A')
+        }));
+
+        for seed in 0..20 {
+            let mut bag = low_bag(seed).with_vowel_guarantee(Some(guarantee.clone()));
+            assert!((0..3).any(|_| bag.draw_tile() == 'A'));
+        }
+
+        // Bags that aren't yet low enough are left alone
+        let guarantee = rules::VowelGuarantee {
+            below: 5,
+            within: 1,
+        };
+        assert!((0..20).any(|seed| {
+            let mut bag = low_bag(seed).with_vowel_guarantee(Some(guarantee.clone()));
+            bag.draw_tile() != 'A'
+        }));
+    }
+
+    #[test]
+    fn custom_distributions() {
+        let distribution =
+            rules::TileDistribution::Custom([('A', 3), ('Z', 1)].into_iter().collect());
+        let mut bag = TileBag::from_distribution(&distribution, 1, Some(1)).unwrap();
+
+        let drawn: Vec<_> = (0..100).map(|_| bag.draw_tile()).collect();
+        assert!(drawn.iter().all(|tile| *tile == 'A' || *tile == 'Z'));
+        assert_eq!(drawn.iter().filter(|tile| **tile == 'Z').count(), 25);
+
+        // The standard distribution is the tile generation
+        assert_eq!(
+            TileBag::from_distribution(&rules::TileDistribution::Standard, 1, Some(1)),
+            Ok(TileBag::generation(1, Some(1)))
+        );
+
+        // Distributions that would leave nothing sensible to draw are refused
+        let unknown = rules::TileDistribution::Custom([('A', 3), ('?', 1)].into_iter().collect());
+        assert_eq!(
+            TileBag::from_distribution(&unknown, 1, Some(1)),
+            Err(GamePlayError::UnknownTileLetter { letter: '?' })
+        );
+        for counts in [vec![], vec![('A', 0), ('B', 0)]] {
+            let empty = rules::TileDistribution::Custom(counts.into_iter().collect());
+            assert_eq!(
+                TileBag::from_distribution(&empty, 1, Some(1)),
+                Err(GamePlayError::EmptyTileDistribution)
+            );
+        }
+    }
+
     // Util functions
     pub fn a_b_bag() -> TileBag {
         let mut dist = [0; 26];
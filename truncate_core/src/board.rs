@@ -1,19 +1,39 @@
 use anyhow::Result;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 use serde::{Deserialize, Serialize};
 use std::array::IntoIter;
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
 use std::iter::{FilterMap, Flatten};
 use std::slice::Iter;
 
 use super::reporting::{BoardChange, BoardChangeAction, BoardChangeDetail};
 use crate::bag::TileBag;
-use crate::error::GamePlayError;
-use crate::judge::WordDict;
-use crate::reporting::Change;
-use crate::rules::{ArtifactDefense, GameRules, WinCondition};
+use crate::error::{BoardError, BoardParseError, GamePlayError};
+use crate::judge::{Judge, Outcome, WordDict};
+use crate::reporting::{BattleReport, Change};
+use crate::rules::{ArtifactDefense, Connectivity, GameRules, WinCondition};
 use crate::{player, rules};
 
+/// The player byte used by `Square::to_code` for squares that no player owns
+pub const NO_PLAYER: u8 = u8::MAX;
+
+/// Flags on the player byte of `Square::to_code` marking owned squares that aren't tiles,
+/// so that a tile is never mistaken for a town or artifact because of its letter
+const TOWN_CODE: u8 = 0x40;
+const ARTIFACT_CODE: u8 = 0x80;
+/// The bits of a `Square::to_code` player byte that hold the player
+const PLAYER_CODE: u8 = 0x3F;
+
+/// The most placements `Board::best_attack` will simulate before settling on the best found so far
+pub const MAX_ATTACK_SEARCH: usize = 2048;
+
+/// The most pairs `Board::legal_swaps` will list, so that huge boards stay cheap to search
+pub const MAX_SWAP_PAIRS: usize = 2048;
+
+/// The fewest rows and columns of land `Board::try_new` will build, which leaves room for both artifacts
+pub const MIN_LAND_SIZE: usize = 3;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Direction {
     NorthWest,
@@ -27,14 +47,79 @@ pub enum Direction {
 }
 
 impl Direction {
-    // Returns whether vertical words should be read from top to bottom if played by a player on this side of the board
+    /// The turn a client should give the board so that a player whose words run in this direction sees it from their seat.
+    /// Players facing north sit at the south edge, so see the board as it is.
+    /// Diagonal seats aren't used by players, so they are left upright.
+    pub fn view_transform(self) -> ViewTransform {
+        match self {
+            Direction::South => ViewTransform::Rotate180,
+            Direction::East => ViewTransform::Rotate270,
+            Direction::West => ViewTransform::Rotate90,
+            _ => ViewTransform::Upright,
+        }
+    }
+
+    /// The turn a letter should be drawn with so that its top faces this direction on screen,
+    /// e.g. `South` for an opponent's tile seen from across the table.
+    /// Diagonals can't be reached with a quarter turn, so south-facing ones are flipped
+    /// like `South` and north-facing ones are left upright.
+    pub fn glyph_transform(self) -> ViewTransform {
+        match self {
+            Direction::NorthWest | Direction::North | Direction::NorthEast => {
+                ViewTransform::Upright
+            }
+            Direction::East => ViewTransform::Rotate90,
+            Direction::SouthEast | Direction::South | Direction::SouthWest => {
+                ViewTransform::Rotate180
+            }
+            Direction::West => ViewTransform::Rotate270,
+        }
+    }
+
+    // Returns whether vertical words should be read from top to bottom if played by a player on this side of the board.
+    // Players in a corner read both ways towards their own corner.
     fn read_top_to_bottom(self) -> bool {
-        matches!(self, Direction::South) || matches!(self, Direction::West)
+        matches!(
+            self,
+            Direction::South | Direction::West | Direction::SouthWest | Direction::SouthEast
+        )
     }
 
-    // Returns whether horizontal words should be read from left to right if played by a player on this side of the board
+    // Returns whether horizontal words should be read from left to right if played by a player on this side of the board.
+    // Players in a corner read both ways towards their own corner.
     fn read_left_to_right(self) -> bool {
-        matches!(self, Direction::South) || matches!(self, Direction::East)
+        matches!(
+            self,
+            Direction::South | Direction::East | Direction::NorthEast | Direction::SouthEast
+        )
+    }
+
+    // Returns whether diagonal words should be read in the direction of `forwards` (SouthEast or SouthWest)
+    // if played by a player on this side of the board. Players on the east and west sides read diagonals
+    // the way they read horizontal words, while everyone else reads them the way they read vertical words.
+    fn read_diagonal_forwards(self, forwards: Direction) -> bool {
+        match self {
+            Direction::East | Direction::West => {
+                self.read_left_to_right() == (forwards == Direction::SouthEast)
+            }
+            _ => self.read_top_to_bottom(),
+        }
+    }
+
+    /// The direction a quarter turn clockwise from this one
+    pub fn turned_clockwise(self) -> Self {
+        use Direction::*;
+
+        match self {
+            NorthWest => NorthEast,
+            North => East,
+            NorthEast => SouthEast,
+            East => South,
+            SouthEast => SouthWest,
+            South => West,
+            SouthWest => NorthWest,
+            West => North,
+        }
     }
 
     pub fn opposite(self) -> Self {
@@ -53,6 +138,123 @@ impl Direction {
     }
 }
 
+/// What happens to an eliminated player's tiles
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EliminationMode {
+    /// Tiles are cleared from the board and returned to the bag
+    ReturnTiles,
+    /// Tiles are turned into water, leaving obstacles for the remaining players
+    Neutralize,
+    /// Tiles remain on the board untouched
+    Leave,
+}
+
+/// How a square changed over the course of a turn
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReviewAction {
+    Placed,
+    /// Removed in a battle involving a placed tile
+    Captured,
+    /// Removed after being cut off from its artifact
+    Truncated,
+    Swapped {
+        previous_tile: char,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewedSquare {
+    pub coordinate: Coordinate,
+    pub player: usize,
+    pub tile: char,
+    pub action: ReviewAction,
+}
+
+/// The changed squares between two boards, in reading order
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewDiff {
+    pub squares: Vec<ReviewedSquare>,
+}
+
+/// Clockwise turns of the board, as seen from the player sitting at the south edge
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ViewTransform {
+    #[default]
+    Upright,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+}
+
+impl ViewTransform {
+    /// Where a coordinate on a board of the given size ends up once the board is turned by this transform,
+    /// matching the squares of `Board::rotated`.
+    pub fn apply(self, coordinate: Coordinate, width: usize, height: usize) -> Coordinate {
+        let Coordinate { x, y } = coordinate;
+        match self {
+            ViewTransform::Upright => Coordinate::new(x, y),
+            ViewTransform::Rotate90 => Coordinate::new(height - 1 - y, x),
+            ViewTransform::Rotate180 => Coordinate::new(width - 1 - x, height - 1 - y),
+            ViewTransform::Rotate270 => Coordinate::new(y, width - 1 - x),
+        }
+    }
+
+    /// Where a coordinate on the turned board came from, on a board of the given size before it was turned.
+    /// Undoes `ViewTransform::apply`.
+    pub fn undo(self, coordinate: Coordinate, width: usize, height: usize) -> Coordinate {
+        let Coordinate { x, y } = coordinate;
+        match self {
+            ViewTransform::Upright => Coordinate::new(x, y),
+            ViewTransform::Rotate90 => Coordinate::new(y, height - 1 - x),
+            ViewTransform::Rotate180 => Coordinate::new(width - 1 - x, height - 1 - y),
+            ViewTransform::Rotate270 => Coordinate::new(width - 1 - y, x),
+        }
+    }
+
+    /// The clockwise quarter turns to pass to `Board::rotated`
+    pub fn quarter_turns(self) -> u8 {
+        match self {
+            ViewTransform::Upright => 0,
+            ViewTransform::Rotate90 => 1,
+            ViewTransform::Rotate180 => 2,
+            ViewTransform::Rotate270 => 3,
+        }
+    }
+}
+
+/// Values derived from the board that a status bar shows together, gathered by `Board::summary`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardSummary {
+    /// How many tiles each player has on the board
+    pub tile_counts: Vec<usize>,
+    /// The fewest steps over land and tiles from any of each player's tiles to an enemy artifact
+    pub distance_to_enemy_root: Vec<Option<usize>>,
+    /// Empty land that is as close to two players' artifacts, and so is nobody's territory
+    pub contested_squares: usize,
+}
+
+/// Everything a client needs to draw the board for one player
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientBoard {
+    /// The board with everything the player can't see removed, and its extra water trimmed
+    pub board: Board,
+    pub view: ViewTransform,
+    /// The player's artifact, in the coordinates of the trimmed board
+    pub root: Option<Coordinate>,
+}
+
+/// How `Board::grow` or `Board::trim` changed the edges of the board, so that the change can be mirrored or undone.
+/// Each edge counts the rows or columns that `grow` added or that `trim` removed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardResize {
+    pub top: usize,
+    pub right: usize,
+    pub bottom: usize,
+    pub left: usize,
+    /// The old and new position of each artifact still on the board
+    pub artifacts: Vec<(Coordinate, Coordinate)>,
+}
+
 struct RedundantEdges {
     top: usize,
     right: usize,
@@ -63,19 +265,41 @@ struct RedundantEdges {
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct Board {
     pub squares: Vec<Vec<Square>>,
-    pub artifacts: Vec<Coordinate>,
+    artifacts: Vec<Coordinate>,
     pub towns: Vec<Coordinate>,
     pub obelisks: Vec<Coordinate>,
+    // The next five fields mirror the matching `GameRules` fields, so that the board can be used on its own.
+    // `Board::adopt_rules` is the one place that copies them across, and `Game::start` calls it.
+    /// Whether the edges of the board wrap around to the opposite side when finding neighbours and words
+    #[serde(default)]
+    pub wrap: bool,
+    /// Whether `get_words` also reads along the diagonals
+    #[serde(default)]
+    pub diagonal_words: bool,
+    /// Which neighbours keep tiles connected to each other and to their artifact
+    #[serde(default)]
+    pub connectivity: Connectivity,
+    /// Whether a tile with none of its player's tiles beside it counts as a one letter word
+    #[serde(default = "lone_tiles_are_words_default")]
+    pub lone_tiles_are_words: bool,
+    /// How many squares away each tile can see through fog of war
+    #[serde(default = "vision_radius_default")]
+    pub vision_radius: usize,
     orientations: Vec<Direction>, // The side of the board that the player is sitting at, and the direction that their vertical words go in
                                   // TODO: Move orientations off the Board and have them tagged against specific players
 }
 
-// TODO: provide a way to validate the board
-//  - the empty squares are fully connected
-//  - there are at least 2 roots
-//  - the roots are at empty squares
+fn lone_tiles_are_words_default() -> bool {
+    true
+}
+
+fn vision_radius_default() -> usize {
+    2
+}
 
 impl Board {
+    /// Builds a default board, panicking if it is too small to hold both artifacts.
+    /// Use `Board::try_new` for dimensions that haven't already been checked.
     pub fn new(land_width: usize, land_height: usize) -> Self {
         // Final board should have a ring of water around the land
         let board_width = land_width + 2;
@@ -96,6 +320,11 @@ impl Board {
             towns: vec![],
             obelisks: vec![],
             orientations: vec![Direction::North, Direction::South],
+            wrap: false,
+            diagonal_words: false,
+            connectivity: Connectivity::Orthogonal,
+            lone_tiles_are_words: true,
+            vision_radius: vision_radius_default(),
         };
 
         let north_towns = [
@@ -131,6 +360,18 @@ impl Board {
         board
     }
 
+    /// Builds a default board, rejecting dimensions with too little land for both artifacts.
+    pub fn try_new(land_width: usize, land_height: usize) -> Result<Self, GamePlayError> {
+        if land_width < MIN_LAND_SIZE || land_height < MIN_LAND_SIZE {
+            return Err(GamePlayError::BoardTooSmall {
+                width: land_width,
+                height: land_height,
+            });
+        }
+
+        Ok(Board::new(land_width, land_height))
+    }
+
     pub fn new_legacy(land_width: usize, land_height: usize) -> Self {
         // Final board should have a ring of water around the land
         let board_width = land_width + 2;
@@ -151,6 +392,11 @@ impl Board {
             towns: vec![],
             obelisks: vec![],
             orientations: vec![Direction::North, Direction::South],
+            wrap: false,
+            diagonal_words: false,
+            connectivity: Connectivity::Orthogonal,
+            lone_tiles_are_words: true,
+            vision_radius: vision_radius_default(),
         };
 
         let artifact_x = board_width / 2;
@@ -201,10 +447,69 @@ impl Board {
         board
     }
 
+    /// Creates an open board for any number of players, with no towns.
+    /// Each player gets an artifact at the given position, which includes the ring of water around the land,
+    /// and sits on the given side of the board, which decides which way they read their words.
+    pub fn new_with_players(
+        land_width: usize,
+        land_height: usize,
+        artifacts: Vec<Coordinate>,
+        orientations: Vec<Direction>,
+    ) -> Self {
+        assert_eq!(
+            artifacts.len(),
+            orientations.len(),
+            "Each player needs both an artifact and an orientation"
+        );
+
+        // Final board should have a ring of water around the land
+        let board_width = land_width + 2;
+
+        // Create a slice of land with water on the edges
+        let mut land_row = vec![Square::land(); land_width];
+        land_row.insert(0, Square::water());
+        land_row.push(Square::water());
+
+        let mut squares = vec![vec![Square::water(); board_width]]; // Start with our north row of water
+        squares.extend(vec![land_row.clone(); land_height]); // Build out the centre land of the board
+        squares.extend(vec![vec![Square::water(); board_width]]); // Finish with a south row of water
+
+        let mut board = Board {
+            squares,
+            artifacts: vec![],
+            towns: vec![],
+            obelisks: vec![],
+            orientations,
+            wrap: false,
+            diagonal_words: false,
+            connectivity: Connectivity::Orthogonal,
+            lone_tiles_are_words: true,
+            vision_radius: vision_radius_default(),
+        };
+
+        for (player, artifact) in artifacts.into_iter().enumerate() {
+            board
+                .set_square(artifact, Square::artifact(player))
+                .expect("Artifact square should exist");
+        }
+
+        board.cache_special_squares();
+
+        board
+    }
+
     pub fn get_orientations(&self) -> &Vec<Direction> {
         &self.orientations
     }
 
+    /// Sits every player on the same side of the board, so that they all read words in the same direction.
+    /// Covers at least as many players as there are artifacts.
+    pub fn with_all_orientations(mut self, direction: Direction) -> Self {
+        let players = self.orientations.len().max(self.artifacts.len());
+        self.orientations = vec![direction; players];
+        self
+    }
+
     pub fn land_width(&self) -> usize {
         unimplemented!("Need to calculate the playable dimensions")
     }
@@ -225,12 +530,134 @@ impl Board {
         self.towns.iter()
     }
 
-    pub fn artifacts(&self) -> Iter<Coordinate> {
-        self.artifacts.iter()
+    pub fn artifacts(&self) -> &[Coordinate] {
+        &self.artifacts
+    }
+
+    /// Moves `player`'s artifact to `position`, returning its previous square to water.
+    /// Artifacts can only be moved onto empty land, or left where they already are.
+    pub fn set_artifact(
+        &mut self,
+        player: usize,
+        position: Coordinate,
+    ) -> Result<(), GamePlayError> {
+        match self.get(position)? {
+            Square::Land { .. } => {}
+            Square::Artifact { player: owner, .. } if owner == player => {}
+            _ => return Err(GamePlayError::InvalidPosition { position }),
+        }
+
+        let previous = self.artifacts.iter().copied().find(|artifact| {
+            matches!(
+                self.get(*artifact),
+                Ok(Square::Artifact { player: owner, .. }) if owner == player
+            )
+        });
+        if let Some(previous) = previous {
+            self.squares[previous.y][previous.x] = Square::water();
+        }
+
+        self.squares[position.y][position.x] = Square::artifact(player);
+        self.cache_special_squares();
+        Ok(())
+    }
+
+    /// Checks that the board is rectangular, that all of its land is connected
+    /// (through other land, towns or artifacts, but never water or obelisks),
+    /// and that there are at least two artifacts that each touch land.
+    pub fn validate(&self) -> Result<(), BoardError> {
+        let width = self
+            .squares
+            .first()
+            .map(|row| row.len())
+            .unwrap_or_default();
+        if width == 0 || self.squares.iter().any(|row| row.len() != width) {
+            return Err(BoardError::Malformed);
+        }
+
+        let rows = self.height();
+        let cols = self.width();
+        let artifacts: Vec<_> = (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+            .filter(|c| matches!(self.get(*c), Ok(Square::Artifact { .. })))
+            .collect();
+
+        if artifacts.len() < 2 {
+            return Err(BoardError::MissingArtifacts {
+                found: artifacts.len(),
+            });
+        }
+
+        let playable: Vec<_> = (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+            .filter(|c| {
+                matches!(
+                    self.get(*c),
+                    Ok(Square::Land { .. } | Square::Occupied { .. })
+                )
+            })
+            .collect();
+        if let Some(start) = playable.first() {
+            let mut reached = HashSet::from([*start]);
+            let mut to_visit = vec![*start];
+            while let Some(pt) = to_visit.pop() {
+                for (neighbour, square) in self.neighbouring_squares(pt) {
+                    if matches!(
+                        square,
+                        Square::Land { .. }
+                            | Square::Occupied { .. }
+                            | Square::Town { .. }
+                            | Square::Artifact { .. }
+                    ) && reached.insert(neighbour)
+                    {
+                        to_visit.push(neighbour);
+                    }
+                }
+            }
+
+            if let Some(position) = playable.iter().find(|c| !reached.contains(c)) {
+                return Err(BoardError::DisconnectedLand {
+                    position: *position,
+                });
+            }
+        }
+
+        for position in artifacts {
+            let touches_land = self
+                .neighbouring_squares(position)
+                .iter()
+                .any(|(_, square)| matches!(square, Square::Land { .. } | Square::Occupied { .. }));
+            if !touches_land {
+                return Err(BoardError::StrandedArtifact { position });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Copies the rules that change how the board reads words and neighbours, and how far it sees through fog, onto the board
+    pub fn adopt_rules(&mut self, rules: &GameRules) {
+        self.wrap = rules.wrap;
+        self.diagonal_words = rules.diagonal_words;
+        self.connectivity = rules.connectivity;
+        self.lone_tiles_are_words = rules.lone_tiles_are_words;
+        self.vision_radius = rules.vision_radius;
+    }
+
+    /// Swaps in `other` as the new board, as long as it is valid.
+    /// If it isn't, this board is left untouched.
+    pub fn replace_with(&mut self, other: Board) -> Result<(), BoardError> {
+        other.validate()?;
+
+        *self = other;
+        self.cache_special_squares();
+        Ok(())
     }
 
     /// Adds water to all edges of the board
-    pub fn grow(&mut self) {
+    pub fn grow(&mut self) -> BoardResize {
         for row in &mut self.squares {
             row.insert(0, Square::water());
             row.push(Square::water());
@@ -240,6 +667,178 @@ impl Board {
         self.squares.push(vec![Square::water(); self.width()]);
 
         self.cache_special_squares();
+
+        BoardResize {
+            top: 1,
+            right: 1,
+            bottom: 1,
+            left: 1,
+            artifacts: self
+                .artifacts
+                .iter()
+                .map(|c| (Coordinate::new(c.x - 1, c.y - 1), *c))
+                .collect(),
+        }
+    }
+
+    /// Resizes the board to `new_width` by `new_height` squares, keeping its content anchored to the top left.
+    /// New space on the right and bottom is filled with water.
+    /// Artifacts that would fall off the board are moved to the nearest remaining square that borders land,
+    /// preferring water so that nothing is covered, or to the nearest square of any kind on a board without land.
+    ///
+    /// Returns every tile, town and obelisk that was lost, whether cut off the board or covered by a moved artifact,
+    /// at the position it held before the resize.
+    pub fn resize(
+        &mut self,
+        new_width: usize,
+        new_height: usize,
+    ) -> Result<Vec<BoardChangeDetail>, GamePlayError> {
+        if new_width == 0 || new_height == 0 {
+            return Err(GamePlayError::BoardTooSmall {
+                width: new_width,
+                height: new_height,
+            });
+        }
+
+        let in_bounds = |c: &Coordinate| c.x < new_width && c.y < new_height;
+        let mut dropped = vec![];
+        let mut displaced = vec![];
+        for (y, row) in self.squares.iter().enumerate() {
+            for (x, square) in row.iter().enumerate() {
+                let coordinate = Coordinate { x, y };
+                if in_bounds(&coordinate) {
+                    continue;
+                }
+                match square {
+                    Square::Occupied { .. } | Square::Town { .. } | Square::Obelisk { .. } => {
+                        dropped.push(BoardChangeDetail {
+                            square: *square,
+                            coordinate,
+                        })
+                    }
+                    Square::Artifact { .. } => displaced.push((coordinate, *square)),
+                    _ => {}
+                }
+            }
+        }
+
+        self.squares.truncate(new_height);
+        for row in &mut self.squares {
+            row.resize(new_width, Square::water());
+        }
+        self.squares
+            .resize(new_height, vec![Square::water(); new_width]);
+
+        let is_land = |square: &Square| {
+            !matches!(
+                square,
+                Square::Water { .. } | Square::Fog { .. } | Square::Artifact { .. }
+            )
+        };
+        for (origin, artifact) in displaced {
+            let destination = (0..new_height)
+                .flat_map(|y| (0..new_width).zip(std::iter::repeat(y)))
+                .map(|(x, y)| Coordinate { x, y })
+                .filter(|c| !matches!(self.squares[c.y][c.x], Square::Artifact { .. }))
+                .min_by_key(|c| {
+                    let borders_land = self
+                        .neighbouring_squares(*c)
+                        .iter()
+                        .any(|(_, square)| is_land(square));
+                    (
+                        !borders_land,
+                        is_land(&self.squares[c.y][c.x]),
+                        c.x.abs_diff(origin.x) + c.y.abs_diff(origin.y),
+                        c.y,
+                        c.x,
+                    )
+                });
+            let Some(destination) = destination else {
+                continue;
+            };
+
+            let square = &mut self.squares[destination.y][destination.x];
+            if matches!(
+                square,
+                Square::Occupied { .. } | Square::Town { .. } | Square::Obelisk { .. }
+            ) {
+                dropped.push(BoardChangeDetail {
+                    square: *square,
+                    coordinate: destination,
+                });
+            }
+            *square = artifact;
+        }
+
+        self.cache_special_squares();
+
+        Ok(dropped)
+    }
+
+    /// Overlays the squares of another board, with its top left corner at `offset`, to compose boards from fragments.
+    /// Water and fog in `other` are left out so that fragments can be any shape,
+    /// and its artifacts are left out so that the board keeps its own players.
+    /// Land in `other` turns water into land, but never clears what is already on the board.
+    ///
+    /// Fails without changing the board if any square would land off the board,
+    /// or would cover a different tile, town, obelisk or artifact.
+    pub fn merge(&mut self, other: &Board, offset: Coordinate) -> Result<(), GamePlayError> {
+        let is_filled = |square: &Square| {
+            !matches!(
+                square,
+                Square::Water { .. } | Square::Land { .. } | Square::Fog { .. }
+            )
+        };
+
+        let mut overlay = vec![];
+        for (y, row) in other.squares.iter().enumerate() {
+            for (x, square) in row.iter().enumerate() {
+                if matches!(
+                    square,
+                    Square::Water { .. } | Square::Fog { .. } | Square::Artifact { .. }
+                ) {
+                    continue;
+                }
+
+                let position = Coordinate::new(offset.x + x, offset.y + y);
+                let existing = self.get(position)?;
+                match (is_filled(&existing), is_filled(square)) {
+                    (true, true) if existing != *square => {
+                        return Err(GamePlayError::MergeConflict { position });
+                    }
+                    (true, _) => {}
+                    (false, _) => overlay.push((position, *square)),
+                }
+            }
+        }
+
+        for (position, square) in overlay {
+            self.squares[position.y][position.x] = square;
+        }
+        self.cache_special_squares();
+
+        Ok(())
+    }
+
+    /// Returns a copy of the board turned clockwise by `quarter_turns` quarter turns.
+    /// Players keep their indexes and their seats turn with the board, so they read the same words as before.
+    pub fn rotated(&self, quarter_turns: u8) -> Board {
+        let mut board = self.clone();
+        for _ in 0..quarter_turns % 4 {
+            let height = board.height();
+            let width = board.width();
+            board.squares = (0..width)
+                .map(|x| (0..height).rev().map(|y| board.squares[y][x]).collect())
+                .collect();
+            board.orientations = board
+                .orientations
+                .iter()
+                .map(|o| o.turned_clockwise())
+                .collect();
+        }
+        board.cache_special_squares();
+
+        board
     }
 
     /// Returns the number of rows/columns
@@ -285,8 +884,10 @@ impl Board {
         }
     }
 
-    /// Trims edges containing only empty squares
-    pub fn trim(&mut self) {
+    /// Removes redundant water from the edges of the board, returning how many rows and columns
+    /// were taken from each edge. Coordinates held outside the board can subtract `top` and `left`
+    /// to stay pointing at the same squares. Artifacts cut off with the water are left out of the report.
+    pub fn trim(&mut self) -> BoardResize {
         let trim = self.redundant_edges();
 
         for _ in 0..trim.top {
@@ -304,6 +905,62 @@ impl Board {
             }
         }
         self.cache_special_squares();
+
+        BoardResize {
+            top: trim.top,
+            right: trim.right,
+            bottom: trim.bottom,
+            left: trim.left,
+            artifacts: self
+                .artifacts
+                .iter()
+                .map(|c| (Coordinate::new(c.x + trim.left, c.y + trim.top), *c))
+                .collect(),
+        }
+    }
+
+    /// Renumbers players so that they're ordered by where their artifacts sit, in reading order,
+    /// carrying their orientations and owned squares along with them.
+    /// Boards that only differ by which index each player was given compare equal once normalized.
+    pub fn normalize(&mut self) {
+        self.cache_special_squares();
+
+        // Players with more than one artifact are placed by their first
+        let artifact_owners =
+            self.artifacts
+                .iter()
+                .filter_map(|artifact| match self.get(*artifact) {
+                    Ok(Square::Artifact { player, .. }) => Some(player),
+                    _ => None,
+                });
+        let mut new_order: Vec<usize> = Vec::with_capacity(self.orientations.len());
+        for player in artifact_owners.chain(0..self.orientations.len()) {
+            if !new_order.contains(&player) {
+                new_order.push(player);
+            }
+        }
+
+        let renumber = |player: &mut usize| {
+            if let Some(new_index) = new_order.iter().position(|p| p == player) {
+                *player = new_index;
+            }
+        };
+        for square in self.squares.iter_mut().flatten() {
+            match square {
+                Square::Town { player, .. }
+                | Square::Artifact { player, .. }
+                | Square::Occupied { player, .. } => renumber(player),
+                Square::Water { .. }
+                | Square::Land { .. }
+                | Square::Obelisk { .. }
+                | Square::Fog { .. } => {}
+            }
+        }
+
+        self.orientations = new_order
+            .iter()
+            .filter_map(|player| self.orientations.get(*player).copied())
+            .collect();
     }
 
     pub fn cache_special_squares(&mut self) {
@@ -316,6 +973,7 @@ impl Board {
 
         self.artifacts.clear();
         self.towns.clear();
+        self.obelisks.clear();
 
         for coord in coords {
             match self.get(coord) {
@@ -485,42 +1143,101 @@ impl Board {
         ])
     }
 
-    // TODO: safety on index access like get and set - ideally combine error checking for all 3
-    pub fn clear(
-        &mut self,
-        position: Coordinate,
-        ref_dict: Option<&WordDict>,
-    ) -> Option<BoardChangeDetail> {
-        if let Some(square) = self
-            .squares
-            .get_mut(position.y as usize)
-            .and_then(|y| y.get_mut(position.x as usize))
-        {
-            if matches!(square, Square::Occupied { .. }) {
-                let change = Some(BoardChangeDetail {
-                    square: *square,
-                    coordinate: position,
-                });
-                *square = Square::land();
-
-                self.neighbouring_squares(position)
-                    .into_iter()
-                    .filter(|(_, s)| matches!(s, Square::Occupied { .. }))
-                    .for_each(|(c, _)| self.mark_validity(c, ref_dict));
-
-                return change;
-            }
+    /// Lists the pairs of the player's tiles that `Board::swap` would accept under `swap_rules`,
+    /// each pair once and in reading order, stopping after `MAX_SWAP_PAIRS`.
+    pub fn legal_swaps(&self, player: usize, swap_rules: &rules::Swapping) -> Vec<[Coordinate; 2]> {
+        if matches!(swap_rules, rules::Swapping::None) {
+            return vec![];
         }
-        None
-    }
 
-    pub fn reset(&mut self) {
         let rows = self.height();
         let cols = self.width();
-        // TODO: Implement iterators for board and pull this out
-        let coords = (0..rows)
+        let tiles: Vec<_> = (0..rows)
             .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
-            .map(|(x, y)| Coordinate { x, y });
+            .map(|(x, y)| Coordinate { x, y })
+            .filter_map(|c| match self.get(c) {
+                Ok(Square::Occupied {
+                    player: p, tile, ..
+                }) if p == player => Some((c, tile)),
+                _ => None,
+            })
+            .collect();
+
+        // Under contiguous swapping, tiles can only swap within their own group
+        let mut groups: HashMap<Coordinate, usize> = HashMap::new();
+        if matches!(swap_rules, rules::Swapping::Contiguous(_)) {
+            for (group, (c, _)) in tiles.iter().enumerate() {
+                if groups.contains_key(c) {
+                    continue;
+                }
+                for member in self.depth_first_search(*c) {
+                    groups.insert(member, group);
+                }
+            }
+        }
+
+        tiles
+            .iter()
+            .enumerate()
+            .flat_map(|(i, first)| tiles[i + 1..].iter().map(move |second| (first, second)))
+            .filter(|((_, a), (_, b))| a != b)
+            .filter(|((a, _), (b, _))| groups.get(a) == groups.get(b))
+            .map(|((a, _), (b, _))| [*a, *b])
+            .take(MAX_SWAP_PAIRS)
+            .collect()
+    }
+
+    /// Whether the square at `position` can never hold a tile, as opposed to being empty or off the board.
+    pub fn is_dead_square(&self, position: Coordinate) -> bool {
+        matches!(
+            self.get(position),
+            Ok(Square::Water { .. } | Square::Obelisk { .. })
+        )
+    }
+
+    /// Removes the tile at `position`, returning what was there.
+    /// Squares without a tile give `Ok(None)`, while dead squares and positions
+    /// outside the board are an error.
+    pub fn clear(
+        &mut self,
+        position: Coordinate,
+        ref_dict: Option<&WordDict>,
+    ) -> Result<Option<BoardChangeDetail>, GamePlayError> {
+        if self.is_dead_square(position) {
+            return Err(GamePlayError::InvalidPosition { position });
+        }
+
+        let square = self
+            .squares
+            .get_mut(position.y)
+            .and_then(|y| y.get_mut(position.x))
+            .ok_or(GamePlayError::OutSideBoardDimensions { position })?;
+
+        if !matches!(square, Square::Occupied { .. }) {
+            return Ok(None);
+        }
+
+        let change = BoardChangeDetail {
+            square: *square,
+            coordinate: position,
+        };
+        *square = Square::land();
+
+        self.neighbouring_squares(position)
+            .into_iter()
+            .filter(|(_, s)| matches!(s, Square::Occupied { .. }))
+            .for_each(|(c, _)| self.mark_validity(c, ref_dict));
+
+        Ok(Some(change))
+    }
+
+    pub fn reset(&mut self) {
+        let rows = self.height();
+        let cols = self.width();
+        // TODO: Implement iterators for board and pull this out
+        let coords = (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y });
 
         for coord in coords {
             let Ok(sq) = self.get_mut(coord) else {
@@ -559,9 +1276,137 @@ impl Board {
         }
     }
 
+    /// Removes all tiles belonging to the given player according to the elimination mode
+    pub fn eliminate_player(
+        &mut self,
+        player_to_eliminate: usize,
+        mode: EliminationMode,
+        bag: &mut TileBag,
+    ) -> Vec<Change> {
+        let rows = self.height();
+        let cols = self.width();
+        let coords: Vec<_> = (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+            .filter(|c| {
+                matches!(self.get(*c), Ok(Square::Occupied { player, .. }) if player == player_to_eliminate)
+            })
+            .collect();
+
+        match mode {
+            EliminationMode::Leave => vec![],
+            EliminationMode::ReturnTiles => coords
+                .into_iter()
+                .flat_map(|c| {
+                    if let Ok(Square::Occupied { tile, .. }) = self.get(c) {
+                        bag.return_tile(tile);
+                    }
+                    self.clear(c, None).ok().flatten().map(|detail| {
+                        Change::Board(BoardChange {
+                            detail,
+                            action: BoardChangeAction::Truncated,
+                        })
+                    })
+                })
+                .collect(),
+            EliminationMode::Neutralize => coords
+                .into_iter()
+                .flat_map(|c| {
+                    let square = self.get(c).ok()?;
+                    self.set_square(c, Square::water()).ok()?;
+                    Some(Change::Board(BoardChange {
+                        detail: BoardChangeDetail {
+                            square,
+                            coordinate: c,
+                        },
+                        action: BoardChangeAction::Neutralized,
+                    }))
+                })
+                .collect(),
+        }
+    }
+
+    /// Eliminates a player as `eliminate_player` does, then reclaims their home region so it isn't left as dead space.
+    /// Their artifact, their towns, and any empty land in their territory all become water,
+    /// each reported as a `Neutralized` change.
+    /// When `shrink` is set the board is then trimmed, and the returned `BoardResize`
+    /// says where the remaining players' artifacts moved to.
+    pub fn reclaim_player(
+        &mut self,
+        player_to_reclaim: usize,
+        mode: EliminationMode,
+        bag: &mut TileBag,
+        shrink: bool,
+    ) -> (Vec<Change>, Option<BoardResize>) {
+        let mut changes = self.eliminate_player(player_to_reclaim, mode, bag);
+
+        let rows = self.height();
+        let cols = self.width();
+        let home: Vec<_> = (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+            .filter(|c| !matches!(self.get(*c), Ok(Square::Occupied { .. })))
+            .filter(|c| self.territorial_owner(*c) == Some(player_to_reclaim))
+            .collect();
+        for c in home {
+            let square = self.get(c).expect("Square was just found on the board");
+            if matches!(square, Square::Water { .. }) {
+                continue;
+            }
+            self.set_square(c, Square::water())
+                .expect("Square was just found on the board");
+            changes.push(Change::Board(BoardChange {
+                detail: BoardChangeDetail {
+                    square,
+                    coordinate: c,
+                },
+                action: BoardChangeAction::Neutralized,
+            }));
+        }
+        self.cache_special_squares();
+
+        let resize = shrink.then(|| self.trim());
+        (changes, resize)
+    }
+
+    /// Moves one square in the given direction, wrapping around the edges of the board when `wrap` is set
+    pub fn step(&self, position: Coordinate, direction: Direction) -> Option<Coordinate> {
+        if !self.wrap || self.width() == 0 || self.height() == 0 {
+            return position.add(direction);
+        }
+
+        let stepped =
+            SignedCoordinate::new(position.x as isize, position.y as isize).add(direction)?;
+        Some(Coordinate {
+            x: stepped.x.rem_euclid(self.width() as isize) as usize,
+            y: stepped.y.rem_euclid(self.height() as isize) as usize,
+        })
+    }
+
+    /// Return coordinates of the horizontal and vertical neighbors, from north clockwise,
+    /// taking into account whether the board wraps.
+    /// On narrow wrapped boards two directions can reach the same square, which is only returned once.
+    pub fn neighbours_4_iter(&self, position: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        let mut seen = Vec::with_capacity(4);
+        [
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ]
+        .into_iter()
+        .filter_map(move |direction| self.step(position, direction))
+        .filter(move |neighbour| {
+            if *neighbour == position || seen.contains(neighbour) {
+                return false;
+            }
+            seen.push(*neighbour);
+            true
+        })
+    }
+
     pub fn neighbouring_squares(&self, position: Coordinate) -> Vec<(Coordinate, Square)> {
-        position
-            .neighbors_4_iter()
+        self.neighbours_4_iter(position)
             .filter_map(|pos| {
                 if let Ok(square) = self.get(pos) {
                     Some((pos, square))
@@ -572,6 +1417,170 @@ impl Board {
             .collect()
     }
 
+    /// The neighbours that count as connected to a position under the board's `connectivity`,
+    /// which includes the diagonals for `Connectivity::King`
+    pub fn connected_squares(&self, position: Coordinate) -> Vec<(Coordinate, Square)> {
+        match self.connectivity {
+            Connectivity::Orthogonal => self.neighbouring_squares(position),
+            Connectivity::King => [
+                Direction::NorthWest,
+                Direction::North,
+                Direction::NorthEast,
+                Direction::East,
+                Direction::SouthEast,
+                Direction::South,
+                Direction::SouthWest,
+                Direction::West,
+            ]
+            .into_iter()
+            .filter_map(|direction| self.step(position, direction))
+            .filter(|pos| *pos != position)
+            .fold(Vec::with_capacity(8), |mut distinct, pos| {
+                if !distinct.contains(&pos) {
+                    distinct.push(pos);
+                }
+                distinct
+            })
+            .into_iter()
+            .filter_map(|pos| self.get(pos).ok().map(|square| (pos, square)))
+            .collect(),
+        }
+    }
+
+    /// Returns the in-bounds orthogonal neighbours of a position, from north clockwise,
+    /// each flagged with whether tiles can be played there.
+    /// Neighbours that would fall off the board are omitted entirely.
+    pub fn neighbour_coords(&self, position: Coordinate) -> Vec<(Coordinate, bool)> {
+        self.neighbours_4_iter(position)
+            .filter_map(|pos| match self.get(pos) {
+                Ok(square) => Some((
+                    pos,
+                    matches!(square, Square::Land { .. } | Square::Occupied { .. }),
+                )),
+                Err(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns the edges of playable squares that face water or the edge of the board,
+    /// which together trace the outline of the playable area.
+    pub fn boundary_edges(&self) -> Vec<(Coordinate, Direction)> {
+        let rows = self.height();
+        let cols = self.width();
+
+        (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+            .filter(|c| {
+                matches!(
+                    self.get(*c),
+                    Ok(Square::Land { .. } | Square::Occupied { .. })
+                )
+            })
+            .flat_map(|c| {
+                [
+                    Direction::North,
+                    Direction::East,
+                    Direction::South,
+                    Direction::West,
+                ]
+                .into_iter()
+                .filter(
+                    move |direction| match self.step(c, *direction).map(|n| self.get(n)) {
+                        Some(Ok(Square::Water { .. })) | Some(Err(_)) | None => true,
+                        Some(Ok(_)) => false,
+                    },
+                )
+                .map(move |direction| (c, direction))
+            })
+            .collect()
+    }
+
+    /// Annotates how each tile changed between this board and the board after a turn.
+    ///
+    /// Removed tiles are captured if they fought the placed tile, and truncated otherwise.
+    /// Since only the two boards are compared, a placed tile that was
+    /// immediately defeated leaves no trace and won't appear in the diff.
+    pub fn review_diff(&self, after: &Board) -> ReviewDiff {
+        let rows = self.height().min(after.height());
+        let cols = self.width().min(after.width());
+
+        let occupant = |board: &Board, c: Coordinate| match board.get(c) {
+            Ok(Square::Occupied { player, tile, .. }) => Some((player, tile)),
+            _ => None,
+        };
+
+        // The board as it was after placing tiles, but before any were removed
+        let mut mid_turn = after.clone();
+        let mut placed = vec![];
+        let mut removed = vec![];
+        let mut squares = vec![];
+
+        for coordinate in (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+        {
+            match (occupant(self, coordinate), occupant(after, coordinate)) {
+                (Some((before_player, previous_tile)), Some((player, tile)))
+                    if before_player == player && previous_tile != tile =>
+                {
+                    squares.push(ReviewedSquare {
+                        coordinate,
+                        player,
+                        tile,
+                        action: ReviewAction::Swapped { previous_tile },
+                    });
+                }
+                (before, Some((player, tile))) if before.map(|(p, _)| p) != Some(player) => {
+                    placed.push(coordinate);
+                    squares.push(ReviewedSquare {
+                        coordinate,
+                        player,
+                        tile,
+                        action: ReviewAction::Placed,
+                    });
+                }
+                (Some(occupant), None) => {
+                    removed.push((coordinate, occupant));
+                    _ = mid_turn.set_square(coordinate, self.get(coordinate).unwrap());
+                }
+                _ => {}
+            }
+        }
+
+        // Squares in the attacking words, and in the words that were attacked
+        let combatants: HashSet<Coordinate> = placed
+            .iter()
+            .flat_map(|position| {
+                let mut fought = mid_turn.get_words(*position);
+                for (neighbour, square) in mid_turn.neighbouring_squares(*position) {
+                    if matches!(square, Square::Occupied { .. }) {
+                        fought.extend(mid_turn.get_words(neighbour));
+                    }
+                }
+                fought.into_iter().flatten()
+            })
+            .collect();
+
+        squares.extend(
+            removed
+                .into_iter()
+                .map(|(coordinate, (player, tile))| ReviewedSquare {
+                    coordinate,
+                    player,
+                    tile,
+                    action: if combatants.contains(&coordinate) {
+                        ReviewAction::Captured
+                    } else {
+                        ReviewAction::Truncated
+                    },
+                }),
+        );
+        squares.sort_by_key(|square| (square.coordinate.y, square.coordinate.x));
+
+        ReviewDiff { squares }
+    }
+
     pub fn reciprocal_coordinate(&self, input: Coordinate) -> Coordinate {
         Coordinate {
             x: self.width() - 1 - input.x,
@@ -639,111 +1648,750 @@ impl Board {
         }
     }
 
-    pub fn truncate(&mut self, bag: &mut TileBag, ref_dict: Option<&WordDict>) -> Vec<Change> {
+    /// The tiles cut off from their player's artifact, which `Board::truncate` would remove.
+    /// Leaves the board as it is, so that a move's losses can be previewed.
+    pub fn dead_tiles(&self) -> HashSet<Coordinate> {
         let mut attatched = HashSet::new();
         for root in self.artifacts.iter() {
             attatched.extend(self.depth_first_search(*root));
         }
 
+        let rows = self.height();
+        let cols = self.width();
+        (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+            .filter(|c| {
+                matches!(self.get(*c), Ok(Square::Occupied { .. })) && !attatched.contains(c)
+            })
+            .collect()
+    }
+
+    /// Removes tiles cut off from their player's artifact, returning them to the bag.
+    /// Tiles in `anchored` survive regardless, see `Board::anchored_tiles`.
+    pub fn truncate(
+        &mut self,
+        bag: &mut TileBag,
+        ref_dict: Option<&WordDict>,
+        anchored: &HashSet<Coordinate>,
+    ) -> Vec<Change> {
+        let dead = self.dead_tiles();
+
         let rows = self.height();
         let cols = self.width();
         let squares = (0..rows).flat_map(|y| (0..cols).zip(std::iter::repeat(y)));
 
         squares
-            .flat_map(|(x, y)| {
-                let c = Coordinate { x, y };
-                if !attatched.contains(&c) {
-                    if let Ok(Square::Occupied { tile, .. }) = self.get(c) {
-                        bag.return_tile(tile);
-                    }
-                    self.clear(c, ref_dict).map(|detail| {
-                        Change::Board(BoardChange {
-                            detail,
-                            action: BoardChangeAction::Truncated,
-                        })
-                    })
-                } else {
-                    None
+            .map(|(x, y)| Coordinate { x, y })
+            .filter(|c| dead.contains(c) && !anchored.contains(c))
+            .filter_map(|c| {
+                if let Ok(Square::Occupied { tile, .. }) = self.get(c) {
+                    bag.return_tile(tile);
                 }
+                self.clear(c, ref_dict).ok().flatten().map(|detail| {
+                    Change::Board(BoardChange {
+                        detail,
+                        action: BoardChangeAction::Truncated,
+                    })
+                })
             })
             .collect()
     }
 
-    // TODO: return iterator or rename since it doesn't matter that this is depth first when we return a HashSet
-    pub fn depth_first_search(&self, position: Coordinate) -> HashSet<Coordinate> {
-        let mut visited = HashSet::new();
+    /// Removes all but the largest connected group of each player's tiles, returning the rest to the bag.
+    /// The largest group survives whether or not it touches an artifact.
+    /// Between groups of the same size, the one reaching highest up the board is kept, then the leftmost.
+    /// Tiles in `anchored` survive regardless, see `Board::anchored_tiles`.
+    pub fn truncate_larger(
+        &mut self,
+        bag: &mut TileBag,
+        ref_dict: Option<&WordDict>,
+        anchored: &HashSet<Coordinate>,
+    ) -> Vec<Change> {
+        let rows = self.height();
+        let cols = self.width();
+        let squares: Vec<_> = (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+            .collect();
 
-        fn dfs(b: &Board, position: Coordinate, visited: &mut HashSet<Coordinate>) {
-            let player = match b.get(position) {
+        let players: HashSet<_> = squares
+            .iter()
+            .filter_map(|c| match self.get(*c) {
                 Ok(Square::Occupied { player, .. }) => Some(player),
-                Ok(Square::Artifact { player, .. }) => Some(player),
                 _ => None,
-            };
-            if let Some(player) = player {
-                visited.insert(position);
-                for (position, square) in b.neighbouring_squares(position) {
-                    if let Square::Occupied {
-                        player: neighbours_player,
-                        ..
-                    } = square
-                    {
-                        if !visited.contains(&position) && player == neighbours_player {
-                            dfs(b, position, visited);
-                        };
-                    }
-                }
-            }
-        }
+            })
+            .collect();
+        // Groups are found in reading order, so earlier groups win ties
+        let largest: HashMap<usize, HashSet<Coordinate>> = players
+            .into_iter()
+            .map(|player| {
+                let kept = self.connected_components(player).into_iter().fold(
+                    HashSet::new(),
+                    |kept, group| {
+                        if group.len() > kept.len() {
+                            group
+                        } else {
+                            kept
+                        }
+                    },
+                );
+                (player, kept)
+            })
+            .collect();
 
-        dfs(self, position, &mut visited);
-        visited
-    }
+        squares
+            .into_iter()
+            .filter_map(|c| {
+                let Ok(Square::Occupied { player, tile, .. }) = self.get(c) else {
+                    return None;
+                };
+                if largest[&player].contains(&c) || anchored.contains(&c) {
+                    return None;
+                }
 
-    pub fn flood_fill(&self, starting_pos: &Coordinate) -> BoardDistances {
-        let mut distances = BoardDistances::new(self);
-        let attacker = self
-            .get(*starting_pos)
-            .ok()
-            .map(|sq| match sq {
-                Square::Occupied { player, .. } => Some(player),
-                Square::Artifact { player, .. } => Some(player),
-                _ => None,
+                bag.return_tile(tile);
+                self.clear(c, ref_dict).ok().flatten().map(|detail| {
+                    Change::Board(BoardChange {
+                        detail,
+                        action: BoardChangeAction::Truncated,
+                    })
+                })
             })
-            .flatten();
+            .collect()
+    }
 
-        let adjacent_to_opponent = |sqs: &Vec<(Coordinate, Square)>| {
-            sqs.iter().any(|(_, n)| match n {
-                Square::Occupied { player, .. } if Some(*player) != attacker => true,
-                Square::Town { player, .. } if Some(*player) != attacker => true,
-                _ => false,
-            })
+    /// Finds the tiles that are part of a valid word at least as long as the `truncation_immune_word_length` rule,
+    /// which are protected from truncation. Returns nothing when the rule is unset.
+    pub fn anchored_tiles(
+        &self,
+        judge: &Judge,
+        rules: &GameRules,
+        dict: Option<&WordDict>,
+    ) -> HashSet<Coordinate> {
+        let mut anchored = HashSet::new();
+        let Some(min_length) = rules.truncation_immune_word_length else {
+            return anchored;
         };
 
-        distances.set_attackable(starting_pos, 0);
-        let initial_neighbors = self.neighbouring_squares(*starting_pos);
-        let mut attackable_pts: VecDeque<_> = initial_neighbors.iter().map(|n| (n.0, 0)).collect();
-        let mut direct_pts: VecDeque<(Coordinate, usize)> = VecDeque::new();
-
-        while !attackable_pts.is_empty() {
-            let (pt, dist) = attackable_pts.pop_front().unwrap();
+        let rows = self.height();
+        let cols = self.width();
+        let mut checked = HashSet::new();
+        for (x, y) in (0..rows).flat_map(|y| (0..cols).zip(std::iter::repeat(y))) {
+            let c = Coordinate { x, y };
+            if !matches!(self.get(c), Ok(Square::Occupied { .. })) {
+                continue;
+            }
 
-            match distances.attackable_distance_mut(&pt) {
-                Some(Some(visited_dist)) => {
-                    if *visited_dist > dist {
-                        // We have now found a better path to this point, so we will reprocess it
-                        *visited_dist = dist;
-                    } else {
-                        // We have previously found a better (or equal) path to this point, move to the next
-                        continue;
-                    }
+            for word in self.get_words(c) {
+                if word.len() < min_length || !checked.insert(word.clone()) {
+                    continue;
                 }
-                _ => {
-                    distances.set_attackable(&pt, dist);
+                let Ok(strings) = self.word_strings(&vec![word.clone()]) else {
+                    continue;
+                };
+                if judge
+                    .valid(&strings[0], &rules.win_condition, dict, None, &mut None)
+                    .is_some()
+                {
+                    anchored.extend(word);
                 }
             }
+        }
 
-            match self.get(pt) {
-                Ok(Square::Occupied { player, .. }) if Some(player) == attacker => {
+        anchored
+    }
+
+    /// Removes one of the player's tiles as an overtime penalty, returning it to the bag.
+    /// A tile is only removed if none of the player's other tiles would be cut off by it,
+    /// whether from their artifact or from the rest of their group. Among those tiles, the one removed:
+    ///  1. touches the fewest of the player's tiles and artifacts,
+    ///  2. then sits furthest from the player's nearest artifact,
+    ///  3. then comes first in reading order.
+    pub fn remove_penalty_tile(
+        &mut self,
+        player: usize,
+        bag: &mut TileBag,
+        ref_dict: Option<&WordDict>,
+    ) -> Option<Change> {
+        let owned = |board: &Board, c: Coordinate| {
+            matches!(
+                board.get(c),
+                Ok(Square::Occupied { player: p, .. } | Square::Artifact { player: p, .. }) if p == player
+            )
+        };
+        let own_artifacts: Vec<_> = self
+            .artifacts
+            .iter()
+            .copied()
+            .filter(|a| owned(self, *a))
+            .collect();
+
+        let rows = self.height();
+        let cols = self.width();
+        let squares: Vec<_> = (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+            .collect();
+
+        // Counts the connected groups of the player's tiles and artifacts, leaving out one square
+        let groups = |without: Option<Coordinate>| {
+            let mut grouped = HashSet::new();
+            let mut count = 0;
+            for c in &squares {
+                if Some(*c) == without || !owned(self, *c) || !grouped.insert(*c) {
+                    continue;
+                }
+                count += 1;
+                let mut to_visit = vec![*c];
+                while let Some(position) = to_visit.pop() {
+                    for (neighbour, _) in self.neighbouring_squares(position) {
+                        if Some(neighbour) != without
+                            && owned(self, neighbour)
+                            && grouped.insert(neighbour)
+                        {
+                            to_visit.push(neighbour);
+                        }
+                    }
+                }
+            }
+            count
+        };
+        let current_groups = groups(None);
+
+        let position = squares
+            .iter()
+            .copied()
+            .filter(
+                |c| matches!(self.get(*c), Ok(Square::Occupied { player: p, .. }) if p == player),
+            )
+            .filter(|c| groups(Some(*c)) <= current_groups)
+            .min_by_key(|c| {
+                let connections = self
+                    .neighbouring_squares(*c)
+                    .into_iter()
+                    .filter(|(n, _)| owned(self, *n))
+                    .count();
+                let distance = own_artifacts
+                    .iter()
+                    .map(|a| c.manhattan_distance(*a))
+                    .min()
+                    .unwrap_or_default();
+                (connections, std::cmp::Reverse(distance))
+            })?;
+
+        if let Ok(Square::Occupied { tile, .. }) = self.get(position) {
+            bag.return_tile(tile);
+        }
+        self.clear(position, ref_dict).ok().flatten().map(|detail| {
+            Change::Board(BoardChange {
+                detail,
+                action: BoardChangeAction::Truncated,
+            })
+        })
+    }
+
+    /// Undoes a single change to the board, for stepping back through a turn.
+    /// Changes from a turn should be reverted in the reverse order they were made.
+    ///  - Added tiles are cleared, and it is up to the caller to return them to the player's hand
+    ///  - Truncated and defeated tiles are put back, and pulled back out of the bag
+    ///  - Neutralized squares are put back in place of the water they became
+    ///  - Victories didn't change the board, so there is nothing to revert
+    pub fn revert_change(
+        &mut self,
+        change: &BoardChange,
+        bag: &mut TileBag,
+    ) -> Result<(), GamePlayError> {
+        let BoardChange {
+            detail: BoardChangeDetail { square, coordinate },
+            action,
+        } = change;
+
+        match action {
+            BoardChangeAction::Added => {
+                self.clear(*coordinate, None)?;
+            }
+            BoardChangeAction::Truncated | BoardChangeAction::Defeated => {
+                let Square::Occupied { tile, .. } = square else {
+                    return Err(GamePlayError::IrreversibleChange {
+                        action: action.to_string(),
+                    });
+                };
+                if !matches!(self.get(*coordinate)?, Square::Land { .. }) {
+                    return Err(GamePlayError::OccupiedPlace);
+                }
+                if !bag.take(*tile) {
+                    return Err(GamePlayError::TileNotInBag { tile: *tile });
+                }
+                self.squares[coordinate.y][coordinate.x] = *square;
+            }
+            BoardChangeAction::Neutralized => {
+                if matches!(square, Square::Water { .. } | Square::Fog {}) {
+                    return Err(GamePlayError::IrreversibleChange {
+                        action: action.to_string(),
+                    });
+                }
+                if !matches!(self.get(*coordinate)?, Square::Water { .. }) {
+                    return Err(GamePlayError::OccupiedPlace);
+                }
+                self.squares[coordinate.y][coordinate.x] = *square;
+                if matches!(square, Square::Town { .. } | Square::Artifact { .. }) {
+                    self.cache_special_squares();
+                }
+            }
+            BoardChangeAction::Victorious => {}
+            BoardChangeAction::Swapped | BoardChangeAction::Exploded => {
+                return Err(GamePlayError::IrreversibleChange {
+                    action: action.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Applies a group of changes that happened together, such as one phase of a turn.
+    /// If any change in the group can't be applied, the board is left untouched.
+    pub fn apply_change_group(&mut self, group: &[BoardChange]) -> Result<(), GamePlayError> {
+        let mut board = self.clone();
+        for change in group {
+            board.apply_change(change)?;
+        }
+        *self = board;
+
+        Ok(())
+    }
+
+    /// Undoes a group of changes that happened together, as `revert_change` does for each in reverse order.
+    /// Swaps are reverted by trading the pair's tiles back, so both halves of a swap must be in the group.
+    /// If any change in the group can't be reverted, the board and bag are left untouched.
+    pub fn revert_change_group(
+        &mut self,
+        group: &[BoardChange],
+        bag: &mut TileBag,
+    ) -> Result<(), GamePlayError> {
+        let mut board = self.clone();
+        let mut reverted_bag = bag.clone();
+
+        let (swaps, others): (Vec<_>, Vec<_>) = group
+            .iter()
+            .partition(|change| change.action == BoardChangeAction::Swapped);
+        for change in others.into_iter().rev() {
+            board.revert_change(change, &mut reverted_bag)?;
+        }
+        for pair in swaps.chunks(2) {
+            let [first, second] = pair else {
+                return Err(GamePlayError::IrreversibleChange {
+                    action: BoardChangeAction::Swapped.to_string(),
+                });
+            };
+            for (change, previous) in [(first, second), (second, first)] {
+                let position = change.detail.coordinate;
+                if !matches!(board.get(position)?, Square::Occupied { .. }) {
+                    return Err(GamePlayError::UnoccupiedSwap);
+                }
+                board.squares[position.y][position.x] = previous.detail.square;
+            }
+        }
+
+        *self = board;
+        *bag = reverted_bag;
+
+        Ok(())
+    }
+
+    fn apply_change(&mut self, change: &BoardChange) -> Result<(), GamePlayError> {
+        let BoardChange {
+            detail: BoardChangeDetail { square, coordinate },
+            action,
+        } = change;
+        let position = *coordinate;
+
+        match action {
+            BoardChangeAction::Added => match (self.get(position)?, square) {
+                (Square::Occupied { .. }, _) => return Err(GamePlayError::OccupiedPlace),
+                (Square::Land { .. }, Square::Occupied { .. }) => {
+                    self.squares[position.y][position.x] = *square;
+                }
+                _ => return Err(GamePlayError::InvalidPosition { position }),
+            },
+            BoardChangeAction::Swapped => match (self.get(position)?, square) {
+                (Square::Occupied { .. }, Square::Occupied { .. }) => {
+                    self.squares[position.y][position.x] = *square;
+                }
+                _ => return Err(GamePlayError::UnoccupiedSwap),
+            },
+            BoardChangeAction::Truncated
+            | BoardChangeAction::Defeated
+            | BoardChangeAction::Exploded => {
+                if self.clear(position, None)?.is_none() {
+                    return Err(GamePlayError::InvalidPosition { position });
+                }
+            }
+            BoardChangeAction::Neutralized => match self.get(position)? {
+                Square::Occupied { .. } | Square::Land { .. } | Square::Obelisk { .. } => {
+                    self.squares[position.y][position.x] = Square::water();
+                }
+                Square::Town { .. } | Square::Artifact { .. } => {
+                    self.squares[position.y][position.x] = Square::water();
+                    self.cache_special_squares();
+                }
+                _ => return Err(GamePlayError::InvalidPosition { position }),
+            },
+            BoardChangeAction::Victorious => {}
+        }
+
+        Ok(())
+    }
+
+    // TODO: return iterator or rename since it doesn't matter that this is depth first when we return a HashSet
+    pub fn depth_first_search(&self, position: Coordinate) -> HashSet<Coordinate> {
+        let mut visited = HashSet::new();
+
+        let player = match self.get(position) {
+            Ok(Square::Occupied { player, .. }) => player,
+            Ok(Square::Artifact { player, .. }) => player,
+            _ => return visited,
+        };
+
+        // An explicit stack keeps long chains of tiles from overflowing the call stack
+        let mut to_visit = vec![position];
+        visited.insert(position);
+        while let Some(position) = to_visit.pop() {
+            for (position, square) in self.connected_squares(position) {
+                if let Square::Occupied {
+                    player: neighbours_player,
+                    ..
+                } = square
+                {
+                    if player == neighbours_player && visited.insert(position) {
+                        to_visit.push(position);
+                    }
+                }
+            }
+        }
+
+        visited
+    }
+
+    /// Splits all of a player's tiles into groups that are connected to each other, as `depth_first_search` follows them.
+    /// Every tile belongs to exactly one group, and groups are ordered by their first tile in reading order.
+    /// Artifacts aren't tiles, so two groups touching the same artifact stay separate.
+    pub fn connected_components(&self, player: usize) -> Vec<HashSet<Coordinate>> {
+        let rows = self.height();
+        let cols = self.width();
+
+        let mut grouped = HashSet::new();
+        let mut components = vec![];
+        for (x, y) in (0..rows).flat_map(|y| (0..cols).zip(std::iter::repeat(y))) {
+            let c = Coordinate { x, y };
+            if grouped.contains(&c)
+                || !matches!(self.get(c), Ok(Square::Occupied { player: p, .. }) if p == player)
+            {
+                continue;
+            }
+
+            let group = self.depth_first_search(c);
+            grouped.extend(group.iter().copied());
+            components.push(group);
+        }
+
+        components
+    }
+
+    /// How many of a player's tiles are on the board
+    pub fn player_tile_count(&self, player: usize) -> usize {
+        self.squares
+            .iter()
+            .flatten()
+            .filter(|sq| matches!(sq, Square::Occupied { player: p, .. } if *p == player))
+            .count()
+    }
+
+    /// Whether the player is yet to get a tile onto the board, so their next placement is their opening move
+    pub fn is_opening(&self, player: usize) -> bool {
+        self.player_tile_count(player) == 0
+    }
+
+    /// The player a square belongs to, if it holds one of their tiles, their artifact, or their town
+    pub fn occupied_owner(&self, position: Coordinate) -> Option<usize> {
+        match self.get(position) {
+            Ok(
+                Square::Occupied { player, .. }
+                | Square::Artifact { player, .. }
+                | Square::Town { player, .. },
+            ) => Some(player),
+            _ => None,
+        }
+    }
+
+    /// The player whose territory a square falls in. Squares with an owner are theirs,
+    /// and empty land goes to the player whose artifact is the fewest steps away over land and tiles.
+    /// Land that is as close to two artifacts, or cut off from all of them, is nobody's.
+    pub fn territorial_owner(&self, position: Coordinate) -> Option<usize> {
+        if let Some(owner) = self.occupied_owner(position) {
+            return Some(owner);
+        }
+        if !matches!(self.get(position), Ok(Square::Land { .. })) {
+            return None;
+        }
+
+        // Search outwards one step at a time until the nearest artifacts are found
+        let mut visited = HashSet::from([position]);
+        let mut frontier = vec![position];
+        while !frontier.is_empty() {
+            let mut owners = HashSet::new();
+            let mut next_frontier = vec![];
+            for pt in frontier {
+                for (neighbour, square) in self.neighbouring_squares(pt) {
+                    match square {
+                        Square::Artifact { player, .. } => {
+                            owners.insert(player);
+                        }
+                        Square::Land { .. } | Square::Occupied { .. }
+                            if visited.insert(neighbour) =>
+                        {
+                            next_frontier.push(neighbour);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            match owners.len() {
+                0 => frontier = next_frontier,
+                1 => return owners.into_iter().next(),
+                _ => return None,
+            }
+        }
+
+        None
+    }
+
+    /// Gathers the values shown in a status bar in a single pass over the board,
+    /// after one search outwards from each artifact.
+    /// Contested squares agree with `Board::territorial_owner`.
+    pub fn summary(&self) -> BoardSummary {
+        let players = self.orientations.len();
+        let artifact_distances: Vec<_> = self
+            .artifacts
+            .iter()
+            .filter_map(|artifact| {
+                let Ok(Square::Artifact { player, .. }) = self.get(*artifact) else {
+                    return None;
+                };
+
+                let mut distances = HashMap::from([(*artifact, 0)]);
+                let mut queue = VecDeque::from([*artifact]);
+                while let Some(pt) = queue.pop_front() {
+                    let dist = distances[&pt];
+                    for (neighbour, square) in self.neighbouring_squares(pt) {
+                        if matches!(square, Square::Land { .. } | Square::Occupied { .. })
+                            && !distances.contains_key(&neighbour)
+                        {
+                            distances.insert(neighbour, dist + 1);
+                            queue.push_back(neighbour);
+                        }
+                    }
+                }
+                Some((player, distances))
+            })
+            .collect();
+
+        let mut summary = BoardSummary {
+            tile_counts: vec![0; players],
+            distance_to_enemy_root: vec![None; players],
+            contested_squares: 0,
+        };
+        let rows = self.height();
+        let cols = self.width();
+        for (x, y) in (0..rows).flat_map(|y| (0..cols).zip(std::iter::repeat(y))) {
+            let c = Coordinate { x, y };
+            match self.get(c) {
+                Ok(Square::Occupied { player, .. }) if player < players => {
+                    summary.tile_counts[player] += 1;
+
+                    let closest = artifact_distances
+                        .iter()
+                        .filter(|(owner, _)| *owner != player)
+                        .filter_map(|(_, distances)| distances.get(&c).copied())
+                        .min();
+                    let best = &mut summary.distance_to_enemy_root[player];
+                    *best = best.iter().copied().chain(closest).min();
+                }
+                Ok(Square::Land { .. }) => {
+                    let Some(nearest) = artifact_distances
+                        .iter()
+                        .filter_map(|(_, distances)| distances.get(&c))
+                        .min()
+                    else {
+                        continue;
+                    };
+                    let owners: HashSet<_> = artifact_distances
+                        .iter()
+                        .filter(|(_, distances)| distances.get(&c) == Some(nearest))
+                        .map(|(owner, _)| owner)
+                        .collect();
+                    if owners.len() > 1 {
+                        summary.contested_squares += 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        summary
+    }
+
+    /// Finds the empty land that `player` has walled in, which can't reach the edge of the board,
+    /// water, or any other player's squares without crossing `player`'s tiles or artifact.
+    pub fn enclosed_by(&self, player: usize) -> HashSet<Coordinate> {
+        let rows = self.height();
+        let cols = self.width();
+        let empties: HashSet<_> = (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+            .filter(|c| matches!(self.get(*c), Ok(Square::Land { .. })))
+            .collect();
+
+        let is_wall = |c: Coordinate| {
+            matches!(
+                self.get(c),
+                Ok(Square::Occupied { player: p, .. } | Square::Artifact { player: p, .. }) if p == player
+            )
+        };
+        // Empty squares on the edge, or touching anything other than land or the player's own squares, are free
+        let mut free: Vec<_> = empties
+            .iter()
+            .copied()
+            .filter(|c| {
+                let neighbours: Vec<_> = self.neighbours_4_iter(*c).collect();
+                neighbours.len() < 4
+                    || neighbours
+                        .into_iter()
+                        .any(|n| !is_wall(n) && !matches!(self.get(n), Ok(Square::Land { .. })))
+            })
+            .collect();
+
+        let mut reachable: HashSet<_> = free.iter().copied().collect();
+        while let Some(c) = free.pop() {
+            for n in self.neighbours_4_iter(c) {
+                if empties.contains(&n) && reachable.insert(n) {
+                    free.push(n);
+                }
+            }
+        }
+
+        empties.difference(&reachable).copied().collect()
+    }
+
+    /// Counts how many of `player`'s tiles touch at least one enemy tile,
+    /// as a rough measure of how exposed they are to attack. Lower is safer.
+    pub fn exposure(&self, player: usize) -> usize {
+        let rows = self.height();
+        let cols = self.width();
+
+        (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+            .filter(
+                |c| matches!(self.get(*c), Ok(Square::Occupied { player: p, .. }) if p == player),
+            )
+            .filter(|c| {
+                self.neighbouring_squares(*c).into_iter().any(|(_, square)| {
+                    matches!(square, Square::Occupied { player: p, .. } if p != player)
+                })
+            })
+            .count()
+    }
+
+    /// Labels every occupied square with the id of the connected group it belongs to,
+    /// numbered in reading order from zero. With `per_player`, touching tiles owned by
+    /// different players are kept in separate groups.
+    pub fn label_regions(&self, per_player: bool) -> HashMap<Coordinate, usize> {
+        let mut labels = HashMap::new();
+        let mut next_label = 0;
+
+        let rows = self.height();
+        let cols = self.width();
+        let all_squares = (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y });
+
+        for position in all_squares {
+            if labels.contains_key(&position)
+                || !matches!(self.get(position), Ok(Square::Occupied { .. }))
+            {
+                continue;
+            }
+
+            let region = if per_player {
+                self.depth_first_search(position)
+            } else {
+                let mut region = HashSet::from([position]);
+                let mut frontier = vec![position];
+                while let Some(current) = frontier.pop() {
+                    for (neighbour, square) in self.neighbouring_squares(current) {
+                        if matches!(square, Square::Occupied { .. }) && region.insert(neighbour) {
+                            frontier.push(neighbour);
+                        }
+                    }
+                }
+                region
+            };
+
+            labels.extend(region.into_iter().map(|c| (c, next_label)));
+            next_label += 1;
+        }
+
+        labels
+    }
+
+    pub fn flood_fill(&self, starting_pos: &Coordinate) -> BoardDistances {
+        let mut distances = BoardDistances::new(self);
+        let attacker = self
+            .get(*starting_pos)
+            .ok()
+            .map(|sq| match sq {
+                Square::Occupied { player, .. } => Some(player),
+                Square::Artifact { player, .. } => Some(player),
+                _ => None,
+            })
+            .flatten();
+
+        let adjacent_to_opponent = |sqs: &Vec<(Coordinate, Square)>| {
+            sqs.iter().any(|(_, n)| match n {
+                Square::Occupied { player, .. } if Some(*player) != attacker => true,
+                Square::Town { player, .. } if Some(*player) != attacker => true,
+                _ => false,
+            })
+        };
+
+        distances.set_attackable(starting_pos, 0);
+        let initial_neighbors = self.neighbouring_squares(*starting_pos);
+        let mut attackable_pts: VecDeque<_> = initial_neighbors.iter().map(|n| (n.0, 0)).collect();
+        let mut direct_pts: VecDeque<(Coordinate, usize)> = VecDeque::new();
+
+        while !attackable_pts.is_empty() {
+            let (pt, dist) = attackable_pts.pop_front().unwrap();
+
+            match distances.attackable_distance_mut(&pt) {
+                Some(Some(visited_dist)) => {
+                    if *visited_dist > dist {
+                        // We have now found a better path to this point, so we will reprocess it
+                        *visited_dist = dist;
+                    } else {
+                        // We have previously found a better (or equal) path to this point, move to the next
+                        continue;
+                    }
+                }
+                _ => {
+                    distances.set_attackable(&pt, dist);
+                }
+            }
+
+            match self.get(pt) {
+                Ok(Square::Occupied { player, .. }) if Some(player) == attacker => {
                     let neighbors = self.neighbouring_squares(pt);
 
                     // We found another one of our tiles — search its neighbors with a new starting distance
@@ -973,31 +2621,109 @@ impl Board {
 
             path.push(pt);
 
-            match distances.direct_distance_mut(&pt) {
-                Some(Some(visited_dist)) => {
-                    if *visited_dist > path.len() {
-                        // We have now found a better path to this point, so we will reprocess it
-                        *visited_dist = path.len();
-                    } else {
-                        // We have previously found a better (or equal) path to this point, move to the next
-                        continue;
-                    }
-                }
-                _ => {
-                    distances.set_direct(&pt, path.len());
+            match distances.direct_distance_mut(&pt) {
+                Some(Some(visited_dist)) => {
+                    if *visited_dist > path.len() {
+                        // We have now found a better path to this point, so we will reprocess it
+                        *visited_dist = path.len();
+                    } else {
+                        // We have previously found a better (or equal) path to this point, move to the next
+                        continue;
+                    }
+                }
+                _ => {
+                    distances.set_direct(&pt, path.len());
+                }
+            }
+
+            match self.get(pt) {
+                Ok(Square::Land { .. }) => {
+                    let neighbors = self.neighbouring_squares(pt);
+                    bfs_queue.extend(neighbors.iter().map(|n| (n.0, path.clone())));
+                }
+                _ => continue,
+            }
+        }
+
+        return None;
+    }
+
+    /// Whether two points are joined by squares that can hold tiles, now or in the future.
+    /// Tiles from either player count as passable, since they can be beaten and replaced,
+    /// so only water, obelisks, and towns ever wall off a path.
+    pub fn path_exists(&self, starting_pos: Coordinate, ending_pos: Coordinate) -> bool {
+        let mut visited = HashSet::from([starting_pos]);
+        let mut bfs_queue = VecDeque::from([starting_pos]);
+
+        while let Some(pt) = bfs_queue.pop_front() {
+            for (neighbour, square) in self.neighbouring_squares(pt) {
+                if neighbour == ending_pos {
+                    return true;
+                }
+                if matches!(square, Square::Land { .. } | Square::Occupied { .. })
+                    && visited.insert(neighbour)
+                {
+                    bfs_queue.push_back(neighbour);
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Whether `player` can still ever reach another player's artifact from their own.
+    /// When this is false for every player the game can no longer be won outright.
+    pub fn enemy_root_reachable(&self, player: usize) -> bool {
+        let owner = |artifact: &Coordinate| match self.get(*artifact) {
+            Ok(Square::Artifact { player, .. }) => Some(player),
+            _ => None,
+        };
+        let Some(root) = self.artifacts.iter().find(|a| owner(a) == Some(player)) else {
+            return false;
+        };
+
+        self.artifacts
+            .iter()
+            .filter(|a| owner(a).is_some_and(|p| p != player))
+            .any(|enemy_root| self.path_exists(*root, *enemy_root))
+    }
+
+    /// Finds the enemy tile closest to any of `player`'s tiles or their artifact,
+    /// along with the number of steps needed to reach it through empty land.
+    /// Returns `None` if there are no enemy tiles that can be reached.
+    pub fn nearest_enemy_tile(&self, player: usize) -> Option<(Coordinate, usize)> {
+        let rows = self.height();
+        let cols = self.width();
+        let own_squares: Vec<_> = (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+            .filter(|c| {
+                matches!(
+                    self.get(*c),
+                    Ok(Square::Occupied { player: p, .. } | Square::Artifact { player: p, .. }) if p == player
+                )
+            })
+            .collect();
+
+        let mut visited: HashSet<_> = own_squares.iter().copied().collect();
+        let mut bfs_queue: VecDeque<_> = own_squares.into_iter().map(|c| (c, 0)).collect();
+
+        while let Some((pt, dist)) = bfs_queue.pop_front() {
+            for (neighbour, square) in self.neighbouring_squares(pt) {
+                if !visited.insert(neighbour) {
+                    continue;
                 }
-            }
-
-            match self.get(pt) {
-                Ok(Square::Land { .. }) => {
-                    let neighbors = self.neighbouring_squares(pt);
-                    bfs_queue.extend(neighbors.iter().map(|n| (n.0, path.clone())));
+                match square {
+                    Square::Occupied { player: p, .. } if p != player => {
+                        return Some((neighbour, dist + 1));
+                    }
+                    Square::Land { .. } => bfs_queue.push_back((neighbour, dist + 1)),
+                    _ => {}
                 }
-                _ => continue,
             }
         }
 
-        return None;
+        None
     }
 
     /// Finds the nearest non-land tile (assuming all play must happen on land).
@@ -1117,7 +2843,17 @@ impl Board {
         out
     }
 
+    /// The words running through a tile, reading diagonally as well when the board's `diagonal_words` is set
     pub fn get_words(&self, position: Coordinate) -> Vec<Vec<Coordinate>> {
+        self.words_through(position, self.diagonal_words)
+    }
+
+    /// The words running through a tile, with the two diagonal words following the vertical and horizontal ones
+    pub fn get_words_with_diagonals(&self, position: Coordinate) -> Vec<Vec<Coordinate>> {
+        self.words_through(position, true)
+    }
+
+    fn words_through(&self, position: Coordinate, diagonals: bool) -> Vec<Vec<Coordinate>> {
         let mut words: Vec<Vec<Coordinate>> = Vec::new();
         let owner = match self.get(position) {
             Ok(Square::Occupied { player, .. }) => player,
@@ -1126,21 +2862,26 @@ impl Board {
             _ => return words,
         };
 
-        let axes = [
+        let mut axes = vec![
             [Direction::South, Direction::North],
             [Direction::East, Direction::West],
         ];
+        if diagonals {
+            axes.push([Direction::SouthEast, Direction::NorthWest]);
+            axes.push([Direction::SouthWest, Direction::NorthEast]);
+        }
 
-        // Build each of the two possible words from either side
-        for axis in axes {
+        // Build each of the possible words from either side
+        for axis in &axes {
             let mut word = vec![position];
-            for direction in axis {
-                let fowards = direction == Direction::South || direction == Direction::East;
-                let mut location = position.add(direction);
+            for direction in axis.iter().copied() {
+                let fowards = direction == axis[0];
+                let mut location = self.step(position, direction);
 
                 if let Some(location) = location.as_mut() {
                     while let Ok(Square::Occupied { player, .. }) = self.get(*location) {
-                        if player != owner {
+                        // On a wrapping board, a line of tiles can loop back on itself
+                        if player != owner || word.contains(location) {
                             break;
                         }
                         if fowards {
@@ -1148,7 +2889,7 @@ impl Board {
                         } else {
                             word.insert(0, *location);
                         }
-                        if let Some(next_location) = location.add(direction) {
+                        if let Some(next_location) = self.step(*location, direction) {
                             *location = next_location;
                         } else {
                             break;
@@ -1159,23 +2900,108 @@ impl Board {
             words.push(word);
         }
 
-        // Reverse words based on the player's orientation
-        let orientation = self.orientations[owner];
+        // Reverse words based on the player's orientation, reading normally for players without one
+        let orientation = self
+            .orientations
+            .get(owner)
+            .copied()
+            .unwrap_or(Direction::South);
         if !orientation.read_top_to_bottom() {
             words[0].reverse();
         }
         if !orientation.read_left_to_right() {
             words[1].reverse();
         }
+        for (word, axis) in words.iter_mut().zip(&axes).skip(2) {
+            if !orientation.read_diagonal_forwards(axis[0]) {
+                word.reverse();
+            }
+        }
 
-        // 1 letter words don't count except when there's only one tile, in which case it does count as a word
-        if words.iter().all(|w| w.len() == 1) {
+        // 1 letter words don't count except when there's only one tile,
+        // in which case it counts as a word if the rules allow
+        if self.lone_tiles_are_words && words.iter().all(|w| w.len() == 1) {
+            words.truncate(2);
             words
         } else {
             words.into_iter().filter(|word| word.len() > 1).collect()
         }
     }
 
+    /// Every distinct word of two or more tiles on the board, in the order their first tiles are found.
+    pub fn all_words(&self) -> Vec<Vec<Coordinate>> {
+        let rows = self.height();
+        let cols = self.width();
+        let mut words: Vec<Vec<Coordinate>> = vec![];
+
+        for coord in (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+            .filter(|c| matches!(self.get(*c), Ok(Square::Occupied { .. })))
+        {
+            for word in self.get_words(coord) {
+                if word.len() > 1 && !words.contains(&word) {
+                    words.push(word);
+                }
+            }
+        }
+
+        words
+    }
+
+    /// Words at least `min_length` tiles long, such as those spanning the playable width of the board.
+    pub fn words_spanning(&self, min_length: usize) -> Vec<Vec<Coordinate>> {
+        self.all_words()
+            .into_iter()
+            .filter(|word| word.len() >= min_length)
+            .collect()
+    }
+
+    /// Lists the squares from `a` to `b` inclusive, if they lie on a line that words can run along
+    /// and every square along the way holds a tile from the same player. Lines only run diagonally
+    /// when the board's `diagonal_words` is set, and can cross the edges of a wrapping board,
+    /// in which case the shortest line is given.
+    pub fn tiles_between(&self, a: Coordinate, b: Coordinate) -> Option<Vec<Coordinate>> {
+        let owner = match self.get(a) {
+            Ok(Square::Occupied { player, .. }) => player,
+            _ => return None,
+        };
+
+        let mut directions = vec![
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ];
+        if self.diagonal_words {
+            directions.extend([
+                Direction::NorthEast,
+                Direction::SouthEast,
+                Direction::SouthWest,
+                Direction::NorthWest,
+            ]);
+        }
+
+        directions
+            .into_iter()
+            .filter_map(|direction| {
+                let mut line = vec![a];
+                let mut position = a;
+                while position != b {
+                    position = self.step(position, direction)?;
+                    // Coming back around to the start means the line never reaches `b`
+                    if position == a
+                        || !matches!(self.get(position), Ok(Square::Occupied { player, .. }) if player == owner)
+                    {
+                        return None;
+                    }
+                    line.push(position);
+                }
+                Some(line)
+            })
+            .min_by_key(|line| line.len())
+    }
+
     pub fn collect_combanants(
         &self,
         player: usize,
@@ -1216,41 +3042,104 @@ impl Board {
         (attackers, defenders)
     }
 
+    /// Finds the longest word through `position` that touches a defeated town or artifact,
+    /// which is the word that won the game if it reached its destination.
+    pub fn winning_word(&self, position: Coordinate) -> Option<(Vec<Coordinate>, String)> {
+        let word = self
+            .get_words(position)
+            .into_iter()
+            .filter(|word| {
+                word.iter().any(|c| {
+                    self.neighbouring_squares(*c).iter().any(|(_, square)| {
+                        matches!(
+                            square,
+                            Square::Town { defeated: true, .. }
+                                | Square::Artifact { defeated: true, .. }
+                        )
+                    })
+                })
+            })
+            .max_by_key(|word| word.len())?;
+        let text = self.word_strings(&vec![word.clone()]).ok()?.pop()?;
+
+        Some((word, text))
+    }
+
+    /// Spells out each word. Fails on the first square that is off the board or has no tile on it,
+    /// with an error naming that square.
     pub fn word_strings(
         &self,
         coordinates: &Vec<Vec<Coordinate>>,
     ) -> Result<Vec<String>, GamePlayError> {
-        let mut err = None; // TODO: is this a reasonable error handling method? We can't return an Err from the function from within the closure passed to map.
         use Square::*;
-        let strings = coordinates
+        coordinates
             .iter()
             .map(|word| {
                 word.iter()
-                    .map(|&square| match self.get(square) {
-                        Ok(sq) => match sq {
-                            Water { .. } | Land { .. } | Fog { .. } | Obelisk { .. } => {
-                                debug_assert!(false);
-                                err = Some(GamePlayError::EmptySquareInWord);
-                                '_'
-                            }
-                            Artifact { .. } => '|',
-                            Town { .. } => '#',
-                            Occupied { tile, .. } => tile,
-                        },
-                        Err(e) => {
-                            err = Some(e);
-                            '_'
+                    .map(|&position| match self.get(position)? {
+                        Water { .. } | Land { .. } | Fog { .. } | Obelisk { .. } => {
+                            Err(GamePlayError::EmptySquareInWord { position })
                         }
+                        Artifact { .. } => Ok('|'),
+                        Town { .. } => Ok('#'),
+                        Occupied { tile, .. } => Ok(tile),
                     })
-                    .collect::<String>()
+                    .collect::<Result<String, _>>()
             })
-            .collect::<Vec<String>>();
+            .collect()
+    }
 
-        if let Some(err_string) = err {
-            Err(err_string)
-        } else {
-            Ok(strings)
+    /// Lists the words that would form if `player` placed `tile` on the empty square at `position`,
+    /// without touching the board itself. Placements that don't join onto any other tiles form no words.
+    pub fn preview_words(
+        &self,
+        player: usize,
+        position: Coordinate,
+        tile: char,
+    ) -> Result<Vec<(Vec<Coordinate>, String)>, GamePlayError> {
+        match self.get(position)? {
+            Square::Land { .. } => {}
+            Square::Occupied { .. } => return Err(GamePlayError::OccupiedPlace),
+            _ => return Err(GamePlayError::InvalidPosition { position }),
         }
+
+        let mut preview = self.clone();
+        preview.set(position, player, tile, None)?;
+
+        let words: Vec<_> = preview
+            .get_words(position)
+            .into_iter()
+            .filter(|word| word.len() > 1)
+            .collect();
+        let strings = preview.word_strings(&words)?;
+
+        Ok(words.into_iter().zip(strings).collect())
+    }
+
+    /// Lists the letters `player` could place on the empty square at `gap` so that
+    /// every word it forms, across and down, is a valid word. Used to hint at what fits in a gap.
+    /// Gaps that wouldn't join onto any of the player's tiles have no suggestions.
+    pub fn gap_suggestions(
+        &self,
+        gap: Coordinate,
+        player: usize,
+        judge: &Judge,
+        rules: &GameRules,
+        dict: Option<&WordDict>,
+    ) -> Vec<char> {
+        ('A'..='Z')
+            .filter(|letter| {
+                let Ok(words) = self.preview_words(player, gap, *letter) else {
+                    return false;
+                };
+                !words.is_empty()
+                    && words.iter().all(|(_, word)| {
+                        judge
+                            .valid(word, &rules.win_condition, dict, None, &mut None)
+                            .is_some()
+                    })
+            })
+            .collect()
     }
 
     pub fn playable_positions(
@@ -1275,7 +3164,7 @@ impl Board {
                     );
                 }
             }
-            rules::Truncation::None => {
+            rules::Truncation::None | rules::Truncation::Larger => {
                 let rows = self.height();
                 let cols = self.width();
 
@@ -1294,7 +3183,6 @@ impl Board {
                         .flat_map(|sq| sq.neighbors_4_iter()),
                 );
             }
-            rules::Truncation::Larger => unimplemented!(),
         }
         playable_squares
             .into_iter()
@@ -1302,14 +3190,204 @@ impl Board {
             .collect()
     }
 
+    /// Finds the placement from `hand` that wins the battle capturing the most enemy tiles,
+    /// or `None` if no placement captures anything. Squares in `unavailable` are never suggested.
+    pub fn best_attack(
+        &self,
+        player: usize,
+        hand: &player::Hand,
+        judge: &Judge,
+        rules: &GameRules,
+        unavailable: &HashSet<Coordinate>,
+        dict: Option<&WordDict>,
+    ) -> Option<(Coordinate, char, BattleReport)> {
+        let mut positions: Vec<_> = self
+            .playable_positions(player, &rules.truncation)
+            .into_iter()
+            .filter(|position| !unavailable.contains(position))
+            .filter(|position| {
+                self.neighbouring_squares(*position).iter().any(|(_, square)| {
+                    matches!(square, Square::Occupied { player: p, .. } if *p != player)
+                })
+            })
+            .collect();
+        positions.sort_by_key(|c| (c.y, c.x));
+
+        let mut tiles: Vec<_> = hand.iter().cloned().collect();
+        tiles.sort();
+        tiles.dedup();
+
+        let mut best: Option<(usize, (Coordinate, char, BattleReport))> = None;
+        let placements = positions
+            .into_iter()
+            .flat_map(|position| tiles.iter().map(move |tile| (position, *tile)))
+            .take(MAX_ATTACK_SEARCH);
+
+        for (position, tile) in placements {
+            let mut board = self.clone();
+            if board.set(position, player, tile, dict).is_err() {
+                continue;
+            }
+
+            let (attackers, defenders) = board.collect_combanants(player, position, rules);
+            let (Ok(attacking_words), Ok(defending_words)) = (
+                board.word_strings(&attackers),
+                board.word_strings(&defenders),
+            ) else {
+                continue;
+            };
+
+            let Some(battle) = judge.battle(
+                attacking_words,
+                defending_words,
+                &rules.battle_rules,
+                &rules.win_condition,
+                dict,
+                dict,
+                None,
+            ) else {
+                continue;
+            };
+
+            let Outcome::AttackerWins(losers) = &battle.outcome else {
+                continue;
+            };
+
+            // Count each defeated letter once, including those destroyed by the explosion around the attacker
+            let captured: HashSet<_> = losers
+                .iter()
+                .flat_map(|loser| defenders[*loser].iter().cloned())
+                .chain(board.neighbouring_squares(position).into_iter().map(|(c, _)| c))
+                .filter(|c| {
+                    matches!(board.get(*c), Ok(Square::Occupied { player: p, .. }) if p != player)
+                })
+                .collect();
+
+            if captured.is_empty() || best.as_ref().is_some_and(|(n, _)| *n >= captured.len()) {
+                continue;
+            }
+            best = Some((captured.len(), (position, tile, battle)));
+        }
+
+        best.map(|(_, attack)| attack)
+    }
+
+    /// Estimates the fewest placements `player` needs to reach an enemy artifact,
+    /// searching no more than `depth_cap` placements deep, so that reviews can point out missed wins.
+    /// The placement that reaches the artifact must only form valid words.
+    /// Tiles come from `hand`, with replacements drawn from a copy of `bag` as they would be in play.
+    /// The opponent is assumed to pass, and battles along the way aren't simulated.
+    pub fn min_moves_to_win(
+        &self,
+        player: usize,
+        hand: &player::Hand,
+        bag: &TileBag,
+        dict: Option<&WordDict>,
+        depth_cap: usize,
+    ) -> Option<usize> {
+        // Steps through land from each square to one touching an enemy artifact
+        let mut distances = HashMap::new();
+        let mut bfs_queue = VecDeque::new();
+        for artifact in &self.artifacts {
+            if !matches!(
+                self.get(*artifact),
+                Ok(Square::Artifact { player: p, defeated: false, .. }) if p != player
+            ) {
+                continue;
+            }
+            for (neighbour, square) in self.neighbouring_squares(*artifact) {
+                if matches!(square, Square::Land { .. }) && !distances.contains_key(&neighbour) {
+                    distances.insert(neighbour, 0);
+                    bfs_queue.push_back(neighbour);
+                }
+            }
+        }
+        while let Some(pt) = bfs_queue.pop_front() {
+            let dist = distances[&pt];
+            for (neighbour, square) in self.neighbouring_squares(pt) {
+                if matches!(square, Square::Land { .. }) && !distances.contains_key(&neighbour) {
+                    distances.insert(neighbour, dist + 1);
+                    bfs_queue.push_back(neighbour);
+                }
+            }
+        }
+
+        (1..=depth_cap).find(|moves| self.wins_within(player, hand, bag, dict, &distances, *moves))
+    }
+
+    fn wins_within(
+        &self,
+        player: usize,
+        hand: &player::Hand,
+        bag: &TileBag,
+        dict: Option<&WordDict>,
+        distances: &HashMap<Coordinate, usize>,
+        moves: usize,
+    ) -> bool {
+        // Only placements that leave enough moves to walk the rest of the way are worth trying
+        let mut positions: Vec<_> = self
+            .playable_positions(player, &rules::Truncation::None)
+            .into_iter()
+            .filter(|position| distances.get(position).is_some_and(|dist| *dist < moves))
+            .collect();
+        positions.sort_by_key(|c| (c.y, c.x));
+
+        let mut tiles: Vec<_> = hand.iter().cloned().collect();
+        tiles.sort();
+        tiles.dedup();
+
+        for position in positions {
+            for tile in &tiles {
+                let mut board = self.clone();
+                if board.set(position, player, *tile, dict).is_err() {
+                    continue;
+                }
+
+                if distances[&position] == 0 {
+                    let words: Vec<_> = board
+                        .get_words(position)
+                        .into_iter()
+                        .filter(|word| word.len() > 1)
+                        .collect();
+                    let valid = board.word_strings(&words).is_ok_and(|strings| {
+                        strings.iter().all(|word| {
+                            dict.is_none_or(|dict| dict.contains_key(&word.to_ascii_lowercase()))
+                        })
+                    });
+                    if valid {
+                        return true;
+                    }
+                    continue;
+                }
+
+                let mut hand = hand.clone();
+                let mut bag = bag.clone();
+                if let Some(index) = hand.find(*tile) {
+                    hand.replace(index, bag.draw_tile());
+                }
+                if board.wins_within(player, &hand, &bag, dict, distances, moves - 1) {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Hides what `player_index` can't see, with the board's `vision_radius`. See `Board::fog_of_war_with_radius`.
     pub fn fog_of_war(
         &self,
         player_index: usize,
         visibility: &rules::Visibility,
         seen_tiles: &HashSet<Coordinate>,
     ) -> Self {
+        self.fog_of_war_with_radius(player_index, visibility, seen_tiles, self.vision_radius)
+    }
+
+    /// Every square that `player_index` can see, where each of their tiles can see `radius` steps away,
+    /// or further if it is part of a valid word.
+    pub(crate) fn visible_coords(&self, player_index: usize, radius: usize) -> HashSet<Coordinate> {
         let mut visible_coords: HashSet<Coordinate> = HashSet::new();
-        let mut all_towns: HashSet<Coordinate> = HashSet::new();
 
         let rows = self.height();
         let cols = self.width();
@@ -1318,29 +3396,15 @@ impl Board {
         for (coord, square) in
             squares.map(|(x, y)| (Coordinate { x, y }, self.get(Coordinate { x, y })))
         {
-            if matches!(square, Ok(Square::Town { .. })) {
-                all_towns.insert(coord);
-            }
-
             match square {
                 Ok(Square::Artifact { player, .. }) | Ok(Square::Town { player, .. })
                     if player == player_index =>
                 {
-                    let mut sqs = HashSet::new();
-                    sqs.insert(coord);
-
-                    for _ in 0..6 {
-                        let pts = sqs.iter().cloned().collect::<Vec<_>>();
-                        for pt in pts {
-                            sqs.extend(pt.neighbors_4_iter());
-                        }
-                    }
-
-                    for pt in sqs.iter() {
-                        visible_coords.insert(*pt);
-                        match self.get(*pt) {
+                    for pt in coord.within_distance(6) {
+                        visible_coords.insert(pt);
+                        match self.get(pt) {
                             Ok(Square::Occupied { player, .. }) if player != player_index => {
-                                visible_coords.extend(self.get_words(*pt).iter().flatten());
+                                visible_coords.extend(self.get_words(pt).iter().flatten());
                             }
                             _ => {}
                         }
@@ -1370,26 +3434,16 @@ impl Board {
                         .max_by_key(|w| w.len());
 
                     let vision_dist = if let Some(valid) = valid {
-                        valid.len().saturating_sub(4) + 3
+                        valid.len().saturating_sub(4) + radius + 1
                     } else {
-                        2
+                        radius
                     };
 
-                    let mut sqs = HashSet::new();
-                    sqs.insert(coord);
-
-                    for _ in 0..vision_dist {
-                        let pts = sqs.iter().cloned().collect::<Vec<_>>();
-                        for pt in pts {
-                            sqs.extend(pt.neighbors_4_iter());
-                        }
-                    }
-
-                    for pt in sqs.iter() {
-                        visible_coords.insert(*pt);
-                        match self.get(*pt) {
+                    for pt in coord.within_distance(vision_dist) {
+                        visible_coords.insert(pt);
+                        match self.get(pt) {
                             Ok(Square::Occupied { player, .. }) if player != player_index => {
-                                visible_coords.extend(self.get_words(*pt).iter().flatten());
+                                visible_coords.extend(self.get_words(pt).iter().flatten());
                             }
                             _ => {}
                         }
@@ -1402,11 +3456,41 @@ impl Board {
             }
         }
 
+        visible_coords
+    }
+
+    /// How many enemy tiles `player_index` can currently see through the fog,
+    /// with the same vision as `fog_of_war`.
+    pub fn visible_enemy_tiles(&self, player_index: usize) -> usize {
+        self.visible_coords(player_index, self.vision_radius)
+            .into_iter()
+            .filter(|c| {
+                matches!(self.get(*c), Ok(Square::Occupied { player, .. }) if player != player_index)
+            })
+            .count()
+    }
+
+    /// Hides what `player_index` can't see, where each of their tiles can see `radius` steps away,
+    /// or further if it is part of a valid word.
+    pub fn fog_of_war_with_radius(
+        &self,
+        player_index: usize,
+        visibility: &rules::Visibility,
+        seen_tiles: &HashSet<Coordinate>,
+        radius: usize,
+    ) -> Self {
+        let visible_coords = self.visible_coords(player_index, radius);
+
         let mut new_board = self.clone();
 
         let rows = self.height();
         let cols = self.width();
         let squares = (0..rows).flat_map(|y| (0..cols).zip(std::iter::repeat(y)));
+        let all_towns: HashSet<Coordinate> = squares
+            .clone()
+            .map(|(x, y)| Coordinate { x, y })
+            .filter(|c| matches!(self.get(*c), Ok(Square::Town { .. })))
+            .collect();
 
         match visibility {
             rules::Visibility::Standard => {}
@@ -1419,6 +3503,15 @@ impl Board {
                     }
                 }
             }
+            rules::Visibility::LetterFog => {
+                for (x, y) in squares {
+                    if !visible_coords.contains(&Coordinate { x, y }) {
+                        if let Square::Occupied { tile, .. } = &mut new_board.squares[y][x] {
+                            *tile = '*';
+                        }
+                    }
+                }
+            }
             rules::Visibility::LandFog | rules::Visibility::OnlyHouseFog => {
                 for (x, y) in squares {
                     let c = Coordinate { x, y };
@@ -1466,7 +3559,9 @@ impl Board {
         seen_tiles: &HashSet<Coordinate>,
     ) -> Coordinate {
         let foggy_board = match visibility {
-            rules::Visibility::Standard | rules::Visibility::TileFog => {
+            rules::Visibility::Standard
+            | rules::Visibility::TileFog
+            | rules::Visibility::LetterFog => {
                 // In these modes, the player knows the full coordinate space, so no remapping is required.
                 return player_coordinate;
             }
@@ -1494,7 +3589,9 @@ impl Board {
         seen_tiles: &HashSet<Coordinate>,
     ) -> Option<Coordinate> {
         let foggy_board = match visibility {
-            rules::Visibility::Standard | rules::Visibility::TileFog => {
+            rules::Visibility::Standard
+            | rules::Visibility::TileFog
+            | rules::Visibility::LetterFog => {
                 // In these modes, the player knows the full coordinate space, so no remapping is required.
                 return Some(game_coordinate);
             }
@@ -1538,81 +3635,346 @@ impl Board {
         match visibility {
             rules::Visibility::Standard => self.clone(),
             rules::Visibility::TileFog
+            | rules::Visibility::LetterFog
             | rules::Visibility::LandFog
             | rules::Visibility::OnlyHouseFog => {
                 let mut foggy = self.fog_of_war(player_index, visibility, seen_tiles);
 
-                if trim_coords {
-                    // Remove extraneous water, so the client doesn't know the dimensions of the play area
-                    foggy.trim();
-                }
+                if trim_coords {
+                    // Remove extraneous water, so the client doesn't know the dimensions of the play area
+                    foggy.trim();
+                }
+
+                foggy
+            }
+        }
+    }
+
+    /// Prepares the board to send to a player, hiding what they can't see and
+    /// noting how the client should turn the board to match their seat.
+    pub fn client_payload(
+        &self,
+        player_index: usize,
+        visibility: &rules::Visibility,
+        winner: &Option<usize>,
+        seen_tiles: &HashSet<Coordinate>,
+    ) -> ClientBoard {
+        let board = self.filter_to_player(player_index, visibility, winner, seen_tiles, true);
+        let view = self
+            .orientations
+            .get(player_index)
+            .map(|seat| seat.view_transform())
+            .unwrap_or(ViewTransform::Upright);
+        let root = board.artifacts.iter().copied().find(|artifact| {
+            matches!(board.get(*artifact), Ok(Square::Artifact { player, .. }) if player == player_index)
+        });
+
+        ClientBoard { board, view, root }
+    }
+
+    /// Moves the coordinates of board changes into the view of the given player,
+    /// so that a client showing the board turned to their seat can apply them directly.
+    /// The changes should already be in this board's coordinates, e.g. from `Game::filter_game_to_player`.
+    pub fn reflect_move_for_opponent_view(
+        &self,
+        player_index: usize,
+        changes: &[Change],
+    ) -> Vec<Change> {
+        let view = self
+            .orientations
+            .get(player_index)
+            .map(|seat| seat.view_transform())
+            .unwrap_or(ViewTransform::Upright);
+
+        changes
+            .iter()
+            .cloned()
+            .map(|mut change| {
+                if let Change::Board(board_change) = &mut change {
+                    board_change.detail.coordinate =
+                        view.apply(board_change.detail.coordinate, self.width(), self.height());
+                }
+                change
+            })
+            .collect()
+    }
+
+    /// Moves a coordinate sent by the given player out of their view and back into this board's coordinates,
+    /// undoing `reflect_move_for_opponent_view`.
+    pub fn coordinate_from_player_view(
+        &self,
+        player_index: usize,
+        coordinate: Coordinate,
+    ) -> Coordinate {
+        self.orientations
+            .get(player_index)
+            .map(|seat| seat.view_transform())
+            .unwrap_or(ViewTransform::Upright)
+            .undo(coordinate, self.width(), self.height())
+    }
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self::new(9, 9)
+    }
+}
+
+impl Board {
+    /// Builds a board from text, panicking if it can't be parsed. See `Board::parse`.
+    pub fn from_string<S: AsRef<str>>(s: S) -> Board {
+        match Self::parse(s) {
+            Ok(board) => board,
+            Err(e) => panic!("Couldn't build board from string: {e}"),
+        }
+    }
+
+    /// Builds a board from text in the format written by its `Display` impl:
+    /// one line per row, with each square as two characters separated by a single space.
+    /// `~~` is water, `__` is land, `^^` an obelisk and `░░` fog,
+    /// while `|N`, `#N`, `⊭N` and `AN` are player N's artifact, town, defeated town and tile `A`.
+    /// Blank lines and the whitespace around each line are ignored.
+    ///
+    /// Player 0 sits to the north and player 1 to the south, so only they can own squares.
+    /// Use `Board::parse_oriented` for boards with more players.
+    pub fn parse<S: AsRef<str>>(s: S) -> Result<Board, BoardParseError> {
+        Self::parse_oriented(s, vec![Direction::North, Direction::South])
+    }
+
+    /// Builds a board from text as `Board::parse` does, with a player for each of the given orientations.
+    pub fn parse_oriented<S: AsRef<str>>(
+        s: S,
+        orientations: Vec<Direction>,
+    ) -> Result<Board, BoardParseError> {
+        let mut squares: Vec<Vec<Square>> = vec![];
+        for line in s.as_ref().split('\n') {
+            if line.chars().all(|c| c.is_whitespace()) {
+                continue;
+            };
+            let row = squares.len();
+            let parsed = line
+                .trim()
+                .split(' ')
+                .enumerate()
+                .map(|(column, token)| {
+                    let malformed = || BoardParseError::MalformedSquare {
+                        row,
+                        column,
+                        token: token.to_string(),
+                    };
+                    let mut chars = token.chars();
+                    let (Some(first), Some(second), None) =
+                        (chars.next(), chars.next(), chars.next())
+                    else {
+                        return Err(malformed());
+                    };
+
+                    match (first, second) {
+                        ('~', '~') => return Ok(Square::water()),
+                        ('_', '_') => return Ok(Square::land()),
+                        ('^', '^') => return Ok(Square::obelisk()),
+                        ('░', '░') => return Ok(Square::fog()),
+                        ('~' | '_' | '^' | '░', _) => return Err(malformed()),
+                        _ => {}
+                    }
+
+                    let player = second.to_digit(10).ok_or_else(malformed)? as usize;
+                    if player >= orientations.len() {
+                        return Err(BoardParseError::MissingOrientation {
+                            row,
+                            column,
+                            player,
+                            orientations: orientations.len(),
+                        });
+                    }
+
+                    Ok(match first {
+                        '|' => Square::artifact(player),
+                        '#' => Square::town(player),
+                        '⊭' => Square::Town {
+                            player,
+                            defeated: true,
+                            foggy: false,
+                        },
+                        tile => Square::Occupied {
+                            player,
+                            tile,
+                            validity: SquareValidity::Unknown,
+                            foggy: false,
+                        },
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            squares.push(parsed);
+        }
+
+        // Make sure the board is an valid non-jagged grid
+        let Some(expected) = squares.first().map(|row| row.len()) else {
+            return Err(BoardParseError::Empty);
+        };
+        if let Some((row, line)) = squares
+            .iter()
+            .enumerate()
+            .find(|(_, line)| line.len() != expected)
+        {
+            return Err(BoardParseError::JaggedRow {
+                row,
+                expected,
+                found: line.len(),
+            });
+        }
+
+        let mut board = Board {
+            squares,
+            towns: vec![],
+            artifacts: vec![],
+            obelisks: vec![],
+            orientations,
+            wrap: false,
+            diagonal_words: false,
+            connectivity: Connectivity::Orthogonal,
+            lone_tiles_are_words: true,
+            vision_radius: vision_radius_default(),
+        };
+        board.cache_special_squares();
 
-                foggy
-            }
-        }
+        Ok(board)
     }
 }
 
-impl Default for Board {
-    fn default() -> Self {
-        Self::new(9, 9)
+/// The order directions are stored in by `Board::to_packed`
+const PACKED_DIRECTIONS: [Direction; 8] = [
+    Direction::NorthWest,
+    Direction::North,
+    Direction::NorthEast,
+    Direction::East,
+    Direction::SouthEast,
+    Direction::South,
+    Direction::SouthWest,
+    Direction::West,
+];
+
+/// The leading byte of `Board::to_compact`, bumped whenever its layout changes
+const COMPACT_VERSION: u8 = 2;
+
+/// How many bytes a UTF-8 character takes, judging by its first byte
+fn utf8_len(first: u8) -> Option<usize> {
+    match first {
+        0x00..=0x7F => Some(1),
+        0xC0..=0xDF => Some(2),
+        0xE0..=0xEF => Some(3),
+        0xF0..=0xF7 => Some(4),
+        _ => None,
     }
 }
 
 impl Board {
-    pub fn from_string<S: AsRef<str>>(s: S) -> Board {
-        // Transform string into a board
-        let mut squares: Vec<Vec<Square>> = vec![];
-        for line in s.as_ref().split('\n') {
-            if line.chars().all(|c| c.is_whitespace()) {
-                continue;
-            };
-            squares.push(
-                line.trim()
-                    .split(' ')
-                    .map(|tile| {
-                        let mut chars = tile.chars();
-                        match chars.next() {
-                            Some('~') => Square::water(),
-                            Some('_') => Square::land(),
-                            Some('|') => Square::artifact(
-                                chars
-                                    .next()
-                                    .expect("Square needs player")
-                                    .to_digit(10)
-                                    .unwrap() as usize,
-                            ),
-                            Some('#') => Square::town(
-                                chars
-                                    .next()
-                                    .expect("Square needs player")
-                                    .to_digit(10)
-                                    .unwrap() as usize,
-                            ),
-                            Some(tile) => Square::Occupied {
-                                player: chars
-                                    .next()
-                                    .expect("Square needs player")
-                                    .to_digit(10)
-                                    .unwrap() as usize,
-                                tile,
-                                validity: SquareValidity::Unknown,
-                                foggy: false,
-                            },
-                            _ => panic!("Couldn't build board from string"),
-                        }
+    /// A plain grid of characters for external tools, indexed as `grid[y][x]`.
+    /// Water and fog are `None`, and tiles are `Some` of their letter.
+    /// Other squares use a fixed sentinel, mostly borrowed from `Board::to_string`:
+    /// `' '` for empty land, `'#'` for towns (defeated or not), `'|'` for artifacts and `'^'` for obelisks.
+    pub fn to_char_grid(&self) -> Vec<Vec<Option<char>>> {
+        self.squares
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|square| match square {
+                        Square::Water { .. } | Square::Fog {} => None,
+                        Square::Land { .. } => Some(' '),
+                        Square::Town { .. } => Some('#'),
+                        Square::Artifact { .. } => Some('|'),
+                        Square::Obelisk { .. } => Some('^'),
+                        Square::Occupied { tile, .. } => Some(*tile),
                     })
-                    .collect(),
-            );
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Packs the board into bytes: the width and height as big-endian `u16`s,
+    /// a wrap flag, the player orientations, then each square in reading order
+    /// as its `Square::to_code` player byte followed by its UTF-8 character.
+    pub fn to_packed(&self) -> Vec<u8> {
+        let mut packed = vec![];
+        packed.extend((self.width() as u16).to_be_bytes());
+        packed.extend((self.height() as u16).to_be_bytes());
+        packed.push(self.wrap as u8);
+
+        packed.push(self.orientations.len() as u8);
+        packed.extend(self.orientations.iter().map(|orientation| {
+            PACKED_DIRECTIONS
+                .iter()
+                .position(|d| d == orientation)
+                .expect("All directions are packable") as u8
+        }));
+
+        let mut buf = [0; 4];
+        for square in self.squares.iter().flatten() {
+            let (player, code) = square.to_code();
+            packed.push(player);
+            packed.extend(code.encode_utf8(&mut buf).as_bytes());
         }
 
-        // Make sure the board is an valid non-jagged grid
-        if squares
-            .iter()
-            .skip(1)
-            .any(|line| line.len() != squares[0].len())
-        {
-            panic!("Tried to make a jagged board");
+        packed
+    }
+
+    /// Unpacks a board from the output of `Board::to_packed`
+    pub fn from_packed(packed: &[u8]) -> Result<Self, GamePlayError> {
+        let invalid = |reason: &str| GamePlayError::InvalidShareCode {
+            reason: reason.to_string(),
+        };
+        let mut bytes = packed.iter().copied();
+        let mut next = |what: &str| {
+            bytes
+                .next()
+                .ok_or_else(|| invalid(&format!("ran out of bytes reading {what}")))
+        };
+
+        let width = u16::from_be_bytes([next("the width")?, next("the width")?]) as usize;
+        let height = u16::from_be_bytes([next("the height")?, next("the height")?]) as usize;
+        if width == 0 || height == 0 {
+            return Err(invalid("the board has no squares"));
+        }
+        let wrap = match next("the wrap flag")? {
+            0 => false,
+            1 => true,
+            _ => return Err(invalid("the wrap flag is not a boolean")),
+        };
+
+        let orientations = (0..next("the orientations")?)
+            .map(|_| {
+                PACKED_DIRECTIONS
+                    .get(next("the orientations")? as usize)
+                    .copied()
+                    .ok_or_else(|| invalid("an orientation is not a direction"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut squares = Vec::with_capacity(height);
+        for _ in 0..height {
+            let mut row = Vec::with_capacity(width);
+            for _ in 0..width {
+                let player = next("a square")?;
+                let first = next("a square")?;
+                let len = utf8_len(first).ok_or_else(|| invalid("a square is not valid UTF-8"))?;
+                let mut buf = [first, 0, 0, 0];
+                for byte in buf.iter_mut().take(len).skip(1) {
+                    *byte = next("a square")?;
+                }
+                let code = std::str::from_utf8(&buf[..len])
+                    .ok()
+                    .and_then(|s| s.chars().next())
+                    .ok_or_else(|| invalid("a square is not valid UTF-8"))?;
+                row.push(
+                    Square::from_code((player, code))
+                        .ok_or_else(|| invalid("a square has an unknown code"))?,
+                );
+            }
+            squares.push(row);
+        }
+
+        if next("the end").is_ok() {
+            return Err(invalid("there are bytes after the last square"));
         }
 
         let mut board = Board {
@@ -1620,11 +3982,113 @@ impl Board {
             towns: vec![],
             artifacts: vec![],
             obelisks: vec![],
-            orientations: vec![Direction::North, Direction::South],
+            orientations,
+            wrap,
+            diagonal_words: false,
+            connectivity: Connectivity::Orthogonal,
+            lone_tiles_are_words: true,
+            vision_radius: vision_radius_default(),
         };
         board.cache_special_squares();
 
-        board
+        Ok(board)
+    }
+
+    /// A short, URL-safe encoding of the board that can be pasted into chat
+    pub fn to_share_code(&self) -> String {
+        URL_SAFE_NO_PAD.encode(self.to_packed())
+    }
+
+    /// Reads a board back from the output of `Board::to_share_code`
+    pub fn from_share_code(code: &str) -> Result<Self, GamePlayError> {
+        let packed =
+            URL_SAFE_NO_PAD
+                .decode(code.trim())
+                .map_err(|e| GamePlayError::InvalidShareCode {
+                    reason: e.to_string(),
+                })?;
+
+        Self::from_packed(&packed)
+    }
+
+    /// A shorter share code for sharing maps in URLs. After a version byte it holds
+    /// the same header as `Board::to_packed`, then the squares in reading order as runs:
+    /// a count of up to 255 identical squares followed by their `Square::to_code` bytes.
+    pub fn to_compact(&self) -> String {
+        let packed = self.to_packed();
+        let header = 6 + self.orientations.len();
+
+        let mut compact = vec![COMPACT_VERSION];
+        compact.extend(&packed[..header]);
+
+        let mut buf = [0; 4];
+        let mut squares = self
+            .squares
+            .iter()
+            .flatten()
+            .map(|s| s.to_code())
+            .peekable();
+        while let Some(code) = squares.next() {
+            let mut run = 1;
+            while run < u8::MAX && squares.next_if_eq(&code).is_some() {
+                run += 1;
+            }
+            compact.push(run);
+            compact.push(code.0);
+            compact.extend(code.1.encode_utf8(&mut buf).as_bytes());
+        }
+
+        URL_SAFE_NO_PAD.encode(compact)
+    }
+
+    /// Reads a board back from the output of `Board::to_compact`
+    pub fn from_compact(code: &str) -> Result<Self, GamePlayError> {
+        let invalid = |reason: &str| GamePlayError::InvalidShareCode {
+            reason: reason.to_string(),
+        };
+        let compact = URL_SAFE_NO_PAD
+            .decode(code.trim())
+            .map_err(|e| invalid(&e.to_string()))?;
+
+        match compact.first() {
+            Some(&COMPACT_VERSION) => {}
+            Some(version) => return Err(invalid(&format!("unknown format version {version}"))),
+            None => return Err(invalid("the code is empty")),
+        }
+        let Some(&orientations) = compact.get(6) else {
+            return Err(invalid("ran out of bytes reading the header"));
+        };
+        let header = 7 + orientations as usize;
+        if compact.len() < header {
+            return Err(invalid("ran out of bytes reading the header"));
+        }
+        let width = u16::from_be_bytes([compact[1], compact[2]]) as usize;
+        let height = u16::from_be_bytes([compact[3], compact[4]]) as usize;
+
+        // Expand the runs back out into the layout of `Board::to_packed`
+        let mut packed = compact[1..header].to_vec();
+        let mut squares = 0;
+        let mut runs = &compact[header..];
+        while let [run, player, first, ..] = *runs {
+            let len = utf8_len(first).ok_or_else(|| invalid("a square is not valid UTF-8"))?;
+            let Some(code) = runs.get(2..2 + len) else {
+                return Err(invalid("ran out of bytes reading a square"));
+            };
+            squares += run as usize;
+            if run == 0 || squares > width * height {
+                return Err(invalid("the runs don't fit the board"));
+            }
+            for _ in 0..run {
+                packed.push(player);
+                packed.extend(code);
+            }
+            runs = &runs[2 + len..];
+        }
+        if !runs.is_empty() {
+            return Err(invalid("ran out of bytes reading a square"));
+        }
+
+        Self::from_packed(&packed)
     }
 }
 
@@ -1725,7 +4189,29 @@ impl Coordinate {
     }
 
     pub fn distance_to(&self, other: &Coordinate) -> usize {
-        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+        self.manhattan_distance(*other)
+    }
+
+    /// Number of horizontal and vertical steps between two coordinates
+    pub fn manhattan_distance(&self, other: Coordinate) -> usize {
+        self.x
+            .abs_diff(other.x)
+            .saturating_add(self.y.abs_diff(other.y))
+    }
+
+    /// All coordinates within `radius` horizontal and vertical steps of this one, stopping at the zero edges
+    pub fn within_distance(&self, radius: usize) -> impl Iterator<Item = Coordinate> {
+        let centre = *self;
+        (centre.y.saturating_sub(radius)..=centre.y.saturating_add(radius)).flat_map(move |y| {
+            let remaining = radius - centre.y.abs_diff(y);
+            (centre.x.saturating_sub(remaining)..=centre.x.saturating_add(remaining))
+                .map(move |x| Coordinate { x, y })
+        })
+    }
+
+    /// Number of steps between two coordinates when diagonal steps are allowed
+    pub fn chebyshev_distance(&self, other: Coordinate) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
     }
 }
 
@@ -1899,6 +4385,87 @@ impl Square {
             Square::Fog {} => true,
         }
     }
+
+    /// Packs the square into a player byte and a character, for compact storage.
+    ///
+    /// Squares without an owner use `NO_PLAYER` alongside a symbol:
+    ///  - `~` for water, the disabled cells that can never be played on
+    ///  - `_` for land, the empty cells that can be played on
+    ///  - `^` for obelisks and `░` for fog
+    ///
+    /// Owned squares carry their player alongside, with `TOWN_CODE` or `ARTIFACT_CODE`
+    /// set on the player byte for towns and artifacts:
+    ///  - `#` / `⊭` for towns and defeated towns
+    ///  - `|` / `¦` for artifacts and defeated artifacts
+    ///  - the tile itself for occupied squares, whatever its letter
+    ///
+    /// Fog state and tile validity aren't stored, as they are recalculated for each view of the board.
+    pub fn to_code(&self) -> (u8, char) {
+        debug_assert!(
+            !matches!(
+                self,
+                Square::Town { player, .. }
+                    | Square::Artifact { player, .. }
+                    | Square::Occupied { player, .. }
+                    if *player > PLAYER_CODE as usize
+            ),
+            "Player index is too large to encode"
+        );
+
+        match self {
+            Square::Water { .. } => (NO_PLAYER, '~'),
+            Square::Land { .. } => (NO_PLAYER, '_'),
+            Square::Obelisk { .. } => (NO_PLAYER, '^'),
+            Square::Fog {} => (NO_PLAYER, '░'),
+            Square::Town {
+                player, defeated, ..
+            } => (*player as u8 | TOWN_CODE, if *defeated { '⊭' } else { '#' }),
+            Square::Artifact {
+                player, defeated, ..
+            } => (
+                *player as u8 | ARTIFACT_CODE,
+                if *defeated { '¦' } else { '|' },
+            ),
+            Square::Occupied { player, tile, .. } => (*player as u8, *tile),
+        }
+    }
+
+    /// Unpacks a square from the output of `Square::to_code`,
+    /// returning `None` if the code doesn't describe a square.
+    pub fn from_code((player, code): (u8, char)) -> Option<Self> {
+        if player == NO_PLAYER {
+            return match code {
+                '~' => Some(Square::water()),
+                '_' => Some(Square::land()),
+                '^' => Some(Square::obelisk()),
+                '░' => Some(Square::fog()),
+                _ => None,
+            };
+        }
+
+        let kind = player & !PLAYER_CODE;
+        let player = (player & PLAYER_CODE) as usize;
+        match (kind, code) {
+            (TOWN_CODE, '#' | '⊭') => Some(Square::Town {
+                player,
+                defeated: code == '⊭',
+                foggy: false,
+            }),
+            (ARTIFACT_CODE, '|' | '¦') => Some(Square::Artifact {
+                player,
+                defeated: code == '¦',
+                foggy: false,
+            }),
+            (0, '~' | '_' | '^' | '░') => None,
+            (0, tile) => Some(Square::Occupied {
+                player,
+                tile,
+                validity: SquareValidity::Unknown,
+                foggy: false,
+            }),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Square {
@@ -2038,6 +4605,7 @@ impl BoardDistances {
 
 #[cfg(test)]
 pub mod tests {
+    use crate::bag::tests as TileUtils;
     use crate::{judge::Judge, rules::SwapPenalty};
 
     use super::*;
@@ -2100,6 +4668,234 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn rejects_tiny_boards() {
+        assert_eq!(
+            Board::try_new(0, 0),
+            Err(GamePlayError::BoardTooSmall {
+                width: 0,
+                height: 0
+            })
+        );
+        assert_eq!(
+            Board::try_new(1, 0),
+            Err(GamePlayError::BoardTooSmall {
+                width: 1,
+                height: 0
+            })
+        );
+        assert_eq!(
+            Board::try_new(3, 2),
+            Err(GamePlayError::BoardTooSmall {
+                width: 3,
+                height: 2
+            })
+        );
+
+        let smallest = Board::try_new(MIN_LAND_SIZE, MIN_LAND_SIZE).expect("Board is big enough");
+        assert_eq!(smallest.validate(), Ok(()));
+        assert_eq!(smallest.artifacts().len(), 2);
+    }
+
+    #[test]
+    fn merge_fragments() {
+        let mut b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ __ __ __ __\n\
+             __ __ A0 __ __\n\
+             __ __ __ __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let fragment = Board::from_string(
+            "~~ B0 |1\n\
+             ^^ A0 __\n\
+             ~~ #1 ~~",
+        );
+
+        assert_eq!(b.merge(&fragment, Coordinate::new(1, 1)), Ok(()));
+        // Matching tiles can overlap, while the fragment's water and artifact are left out
+        assert_eq!(
+            b.to_string(),
+            "~~ ~~ |0 ~~ ~~\n\
+             __ __ B0 __ __\n\
+             __ ^^ A0 __ __\n\
+             __ __ #1 __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        assert_eq!(b.towns().count(), 1);
+        assert_eq!(b.obelisks.len(), 1);
+        assert_eq!(b.artifacts().len(), 2);
+    }
+
+    #[test]
+    fn merge_conflicts() {
+        let mut b = Board::from_string(
+            "~~ |0 ~~\n\
+             __ A0 __\n\
+             __ __ __\n\
+             ~~ |1 ~~",
+        );
+        let before = b.clone();
+
+        let fragment = Board::from_string("__ C1\n__ __");
+        assert_eq!(
+            b.merge(&fragment, Coordinate::new(0, 1)),
+            Err(GamePlayError::MergeConflict {
+                position: Coordinate::new(1, 1)
+            })
+        );
+        assert_eq!(
+            b.merge(&fragment, Coordinate::new(2, 2)),
+            Err(GamePlayError::OutSideBoardDimensions {
+                position: Coordinate::new(3, 2)
+            })
+        );
+        // Nothing is merged when any square fails
+        assert_eq!(b, before);
+
+        // Land in the fragment doesn't clear what was underneath
+        assert_eq!(b.merge(&fragment, Coordinate::new(1, 2)), Ok(()));
+        assert_eq!(b.artifacts().len(), 2);
+        assert_eq!(
+            b.get(Coordinate::new(2, 2)),
+            Ok(Square::Occupied {
+                player: 1,
+                tile: 'C',
+                validity: SquareValidity::Unknown,
+                foggy: false,
+            })
+        );
+    }
+
+    #[test]
+    fn resize_board() {
+        let mut b = Board::from_string(
+            "~~ ~~ ~~ |0 ~~\n\
+             __ A0 B0 C0 #0\n\
+             __ __ F1 D0 ^^\n\
+             __ E1 __ __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let original = b.clone();
+
+        // Growing adds water to the right and bottom
+        assert_eq!(b.resize(6, 6), Ok(vec![]));
+        assert_eq!(
+            b.to_string(),
+            "~~ ~~ ~~ |0 ~~ ~~\n\
+             __ A0 B0 C0 #0 ~~\n\
+             __ __ F1 D0 ^^ ~~\n\
+             __ E1 __ __ __ ~~\n\
+             ~~ ~~ |1 ~~ ~~ ~~\n\
+             ~~ ~~ ~~ ~~ ~~ ~~"
+        );
+
+        // Shrinking reports every tile, town and obelisk lost,
+        // and moves the south artifact to the nearest water that borders land
+        let mut b = original.clone();
+        let dropped = b.resize(3, 3).unwrap();
+        assert_eq!(
+            dropped
+                .iter()
+                .map(|detail| (detail.coordinate, detail.square.to_string()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Coordinate::new(3, 1), "C0".to_string()),
+                (Coordinate::new(4, 1), "#0".to_string()),
+                (Coordinate::new(3, 2), "D0".to_string()),
+                (Coordinate::new(4, 2), "^^".to_string()),
+                (Coordinate::new(1, 3), "E1".to_string()),
+            ]
+        );
+        assert_eq!(
+            b.to_string(),
+            "~~ |1 |0\n\
+             __ A0 B0\n\
+             __ __ F1"
+        );
+        assert_eq!(
+            b.artifacts(),
+            &[Coordinate::new(1, 0), Coordinate::new(2, 0)]
+        );
+
+        // Without any water next to land, the artifact covers the nearest land and reports what was there
+        let mut b = Board::from_string(
+            "__ __\n\
+             __ A0\n\
+             ~~ |0",
+        );
+        assert_eq!(
+            b.resize(2, 2),
+            Ok(vec![BoardChangeDetail {
+                square: Square::Occupied {
+                    player: 0,
+                    tile: 'A',
+                    validity: SquareValidity::Unknown,
+                    foggy: false,
+                },
+                coordinate: Coordinate::new(1, 1),
+            }])
+        );
+        assert_eq!(
+            b.to_string(),
+            "__ __\n\
+             __ |0"
+        );
+
+        assert_eq!(
+            original.clone().resize(0, 3),
+            Err(GamePlayError::BoardTooSmall {
+                width: 0,
+                height: 3
+            })
+        );
+    }
+
+    #[test]
+    fn trim_board_offsets() {
+        let mut b = Board::from_string(
+            "~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~\n\
+             ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~\n\
+             ~~ ~~ ~~ ~~ |0 ~~ ~~ ~~\n\
+             ~~ ~~ ~~ __ R0 __ ~~ ~~\n\
+             ~~ ~~ ~~ W0 O0 R0 ~~ ~~\n\
+             ~~ ~~ ~~ __ S0 __ |1 ~~\n\
+             ~~ ~~ ~~ ~~ ~~ ~~ ~~ ~~",
+        );
+        let tile = Coordinate::new(4, 4);
+        let square = b.get(tile);
+
+        let resize = b.trim();
+        assert_eq!((resize.top, resize.left), (2, 2));
+        assert_eq!(
+            b.get(Coordinate::new(tile.x - resize.left, tile.y - resize.top)),
+            square
+        );
+
+        let resize = b.trim();
+        assert_eq!(
+            (resize.top, resize.right, resize.bottom, resize.left),
+            (0, 0, 0, 0),
+            "Nothing left to trim"
+        );
+
+        // Growing reports the edges it added, and undoes the trim's shift of the artifacts
+        let resize = b.grow();
+        assert_eq!(
+            resize,
+            BoardResize {
+                top: 1,
+                right: 1,
+                bottom: 1,
+                left: 1,
+                artifacts: vec![
+                    (Coordinate::new(2, 0), Coordinate::new(3, 1)),
+                    (Coordinate::new(4, 3), Coordinate::new(5, 4)),
+                ],
+            }
+        );
+    }
+
     #[test]
     fn trim_board() {
         let mut b = Board::from_string(
@@ -2149,7 +4945,19 @@ pub mod tests {
              ~~ ~~ ~~ ~~ ~~ ~~ ~~\n\
              ~~ ~~ ~~ ~~ ~~ ~~ ~~",
         );
-        b.trim();
+        assert_eq!(
+            b.trim(),
+            BoardResize {
+                top: 1,
+                right: 1,
+                bottom: 1,
+                left: 1,
+                artifacts: vec![
+                    (Coordinate::new(3, 1), Coordinate::new(2, 0)),
+                    (Coordinate::new(5, 4), Coordinate::new(4, 3)),
+                ],
+            }
+        );
         assert_eq!(
             b.to_string(),
             "~~ ~~ |0 ~~ ~~\n\
@@ -2188,16 +4996,169 @@ pub mod tests {
              ~~ ~~ ~~ ~~ ~~ ~~ ~~\n\
              ~~ ~~ ~~ |1 ~~ ~~ ~~",
         );
-        b.trim();
+        assert_eq!(
+            b.trim(),
+            BoardResize {
+                top: 1,
+                right: 1,
+                bottom: 1,
+                left: 1,
+                artifacts: vec![(Coordinate::new(5, 4), Coordinate::new(4, 3))],
+            },
+            "Artifacts that were trimmed aren't reported"
+        );
+        assert_eq!(
+            b.to_string(),
+            "~~ ~~ ~~ ~~ ~~\n\
+             ~~ __ R0 __ ~~\n\
+             ~~ W0 O0 R0 ~~\n\
+             ~~ __ S0 __ |0\n\
+             ~~ ~~ ~~ ~~ ~~",
+            "Do trim unconnected artifacts"
+        );
+    }
+
+    #[test]
+    fn eliminate_player() {
+        let board = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ __ A0 __ __\n\
+             __ __ B0 C1 __\n\
+             __ __ __ D1 __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+
+        {
+            let mut b = board.clone();
+            let mut bag = TileBag::explicit(vec![], Some(1));
+            let changes = b.eliminate_player(0, EliminationMode::ReturnTiles, &mut bag);
+            assert_eq!(changes.len(), 2);
+            assert_eq!(
+                b.to_string(),
+                "~~ ~~ |0 ~~ ~~\n\
+                 __ __ __ __ __\n\
+                 __ __ __ C1 __\n\
+                 __ __ __ D1 __\n\
+                 ~~ ~~ |1 ~~ ~~",
+                "Tiles should be cleared"
+            );
+            assert_eq!(bag.to_string(), "Letters in the bag:\n['A', 'B']");
+        }
+
+        {
+            let mut b = board.clone();
+            let mut bag = TileBag::explicit(vec![], Some(1));
+            let changes = b.eliminate_player(0, EliminationMode::Neutralize, &mut bag);
+            assert_eq!(changes.len(), 2);
+            assert!(changes.iter().all(|change| matches!(
+                change,
+                Change::Board(BoardChange {
+                    action: BoardChangeAction::Neutralized,
+                    detail: BoardChangeDetail {
+                        square: Square::Occupied { player: 0, .. },
+                        ..
+                    },
+                })
+            )));
+            assert_eq!(
+                b.to_string(),
+                "~~ ~~ |0 ~~ ~~\n\
+                 __ __ ~~ __ __\n\
+                 __ __ ~~ C1 __\n\
+                 __ __ __ D1 __\n\
+                 ~~ ~~ |1 ~~ ~~",
+                "Tiles should become obstacles"
+            );
+            assert_eq!(bag.to_string(), "Letters in the bag:\n[]");
+
+            for change in changes.iter().rev() {
+                let Change::Board(change) = change else {
+                    unreachable!()
+                };
+                b.revert_change(change, &mut bag).unwrap();
+            }
+            assert_eq!(b, board, "Neutralized tiles should revert to tiles");
+        }
+
+        {
+            let mut b = board.clone();
+            let mut bag = TileBag::explicit(vec![], Some(1));
+            let changes = b.eliminate_player(0, EliminationMode::Leave, &mut bag);
+            assert!(changes.is_empty());
+            assert_eq!(b, board, "Tiles should be untouched");
+            assert_eq!(bag.to_string(), "Letters in the bag:\n[]");
+        }
+    }
+
+    #[test]
+    fn reclaim_player() {
+        let board = Board::parse_oriented(
+            "~~ ~~ ~~ ~~ |0 ~~ ~~\n\
+             ~~ __ __ __ A0 __ __\n\
+             ~~ __ __ __ __ __ __\n\
+             |2 C2 __ __ __ __ __\n\
+             ~~ __ __ __ __ __ __\n\
+             ~~ __ __ __ B1 __ __\n\
+             ~~ ~~ ~~ ~~ |1 ~~ ~~",
+            vec![Direction::North, Direction::South, Direction::West],
+        )
+        .unwrap();
+
+        let mut b = board.clone();
+        let mut bag = TileBag::explicit(vec![], Some(1));
+        let (changes, resize) = b.reclaim_player(2, EliminationMode::ReturnTiles, &mut bag, false);
+        assert_eq!(resize, None);
+        assert_eq!(bag.to_string(), "Letters in the bag:\n['C']");
+        // Contested land and the other players' territory is kept
+        assert_eq!(
+            b.to_string(),
+            "~~ ~~ ~~ ~~ |0 ~~ ~~\n\
+             ~~ ~~ __ __ A0 __ __\n\
+             ~~ ~~ ~~ __ __ __ __\n\
+             ~~ ~~ ~~ ~~ __ __ __\n\
+             ~~ ~~ ~~ __ __ __ __\n\
+             ~~ ~~ __ __ B1 __ __\n\
+             ~~ ~~ ~~ ~~ |1 ~~ ~~",
+        );
+        // Every square that became water is reported, so the whole reclaim can be undone
+        assert_eq!(changes.len(), 11);
+        assert!(changes[1..].iter().all(|change| matches!(
+            change,
+            Change::Board(BoardChange {
+                action: BoardChangeAction::Neutralized,
+                ..
+            })
+        )));
+        let mut reverted = b.clone();
+        for change in changes.iter().rev() {
+            if let Change::Board(change) = change {
+                reverted.revert_change(change, &mut bag).unwrap();
+            }
+        }
+        assert_eq!(reverted, board);
+
+        let mut b = board.clone();
+        let mut bag = TileBag::explicit(vec![], Some(1));
+        let (_, resize) = b.reclaim_player(2, EliminationMode::ReturnTiles, &mut bag, true);
         assert_eq!(
             b.to_string(),
-            "~~ ~~ ~~ ~~ ~~\n\
-             ~~ __ R0 __ ~~\n\
-             ~~ W0 O0 R0 ~~\n\
-             ~~ __ S0 __ |0\n\
-             ~~ ~~ ~~ ~~ ~~",
-            "Do trim unconnected artifacts"
+            "~~ ~~ ~~ |0 ~~ ~~\n\
+             ~~ __ __ A0 __ __\n\
+             ~~ ~~ __ __ __ __\n\
+             ~~ ~~ ~~ __ __ __\n\
+             ~~ ~~ __ __ __ __\n\
+             ~~ __ __ B1 __ __\n\
+             ~~ ~~ ~~ |1 ~~ ~~",
+        );
+        assert_eq!(
+            resize.map(|r| r.artifacts),
+            Some(vec![
+                (Coordinate::new(4, 0), Coordinate::new(3, 0)),
+                (Coordinate::new(4, 6), Coordinate::new(3, 6)),
+            ])
         );
+        // The survivors' artifacts still sit on the board next to their land
+        assert_eq!(b.validate(), Ok(()));
     }
 
     #[test]
@@ -2246,6 +5207,120 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn replace_with_validates() {
+        let original = Board::from_string(
+            "~~ |0 ~~\n\
+             __ __ __\n\
+             ~~ |1 ~~",
+        );
+        let mut b = original.clone();
+
+        let missing_artifact = Board::from_string(
+            "~~ |0 ~~\n\
+             __ __ __\n\
+             ~~ ~~ ~~",
+        );
+        assert_eq!(
+            b.replace_with(missing_artifact),
+            Err(BoardError::MissingArtifacts { found: 1 })
+        );
+        assert_eq!(b, original);
+
+        let stranded_artifact = Board::from_string(
+            "|0 ~~ ~~\n\
+             ~~ __ __\n\
+             ~~ |1 ~~",
+        );
+        assert_eq!(
+            b.replace_with(stranded_artifact),
+            Err(BoardError::StrandedArtifact {
+                position: Coordinate::new(0, 0)
+            })
+        );
+        assert_eq!(b, original);
+
+        let split_land = Board::from_string(
+            "~~ |0 ~~\n\
+             __ __ __\n\
+             ~~ ~~ ~~\n\
+             __ A1 __\n\
+             ~~ |1 ~~",
+        );
+        assert_eq!(
+            b.replace_with(split_land),
+            Err(BoardError::DisconnectedLand {
+                position: Coordinate::new(0, 3)
+            })
+        );
+        assert_eq!(b, original);
+
+        // Towns and artifacts join the land around them, as generated boards rely on
+        let bridged_by_artifact = Board::from_string(
+            "__ __ ~~\n\
+             ~~ |0 ~~\n\
+             ~~ __ __\n\
+             ~~ |1 ~~",
+        );
+        assert_eq!(bridged_by_artifact.validate(), Ok(()));
+
+        let mut ragged = original.clone();
+        ragged.squares[1].pop();
+        assert_eq!(b.replace_with(ragged), Err(BoardError::Malformed));
+        assert_eq!(b, original);
+
+        let larger = Board::from_string(
+            "~~ ~~ |0 ~~\n\
+             __ __ __ __\n\
+             __ __ __ __\n\
+             ~~ |1 ~~ ~~",
+        );
+        assert_eq!(b.replace_with(larger.clone()), Ok(()));
+        assert_eq!(b, larger);
+    }
+
+    #[test]
+    fn set_artifact() {
+        let mut b = Board::from_string(
+            "~~ |0 ~~\n\
+             __ __ __\n\
+             ~~ |1 ~~",
+        );
+
+        b.set_artifact(0, Coordinate::new(0, 1)).unwrap();
+        assert_eq!(
+            b.to_string(),
+            "~~ ~~ ~~\n\
+             |0 __ __\n\
+             ~~ |1 ~~"
+        );
+        assert_eq!(
+            b.artifacts(),
+            &[Coordinate::new(0, 1), Coordinate::new(1, 2)]
+        );
+        b.set_artifact(0, Coordinate::new(0, 1)).unwrap();
+
+        let position = Coordinate::new(3, 0);
+        assert_eq!(
+            b.set_artifact(0, position),
+            Err(GamePlayError::OutSideBoardDimensions { position })
+        );
+        // Only empty land can take an artifact
+        for position in [Coordinate::new(1, 2), Coordinate::new(0, 0)] {
+            assert_eq!(
+                b.set_artifact(0, position),
+                Err(GamePlayError::InvalidPosition { position })
+            );
+        }
+        b.set(Coordinate::new(1, 1), 0, 'A', None).unwrap();
+        let position = Coordinate::new(1, 1);
+        assert_eq!(
+            b.set_artifact(0, position),
+            Err(GamePlayError::InvalidPosition { position })
+        );
+        assert_eq!(b.get(Coordinate::new(0, 1)), Ok(Square::artifact(0)));
+    }
+
     #[test]
     fn getset_handles_empty_squares() {
         let mut b = Board::from_string(
@@ -2271,112 +5346,499 @@ pub mod tests {
             })
         );
         assert_eq!(
-            b.set(Coordinate { x: 0, y: 1 }, 0, 'a', Some(&short_dict())),
-            Ok(BoardChangeDetail {
-                square: Square::Occupied {
-                    player: 0,
-                    tile: 'a',
-                    validity: SquareValidity::Invalid,
-                    foggy: false
-                },
-                coordinate: Coordinate { x: 0, y: 1 },
-            })
+            b.set(Coordinate { x: 0, y: 1 }, 0, 'a', Some(&short_dict())),
+            Ok(BoardChangeDetail {
+                square: Square::Occupied {
+                    player: 0,
+                    tile: 'a',
+                    validity: SquareValidity::Invalid,
+                    foggy: false
+                },
+                coordinate: Coordinate { x: 0, y: 1 },
+            })
+        );
+        assert_eq!(
+            b.set(Coordinate { x: 2, y: 0 }, 0, 'a', Some(&short_dict())),
+            Ok(BoardChangeDetail {
+                square: Square::Occupied {
+                    player: 0,
+                    tile: 'a',
+                    validity: SquareValidity::Invalid,
+                    foggy: false
+                },
+                coordinate: Coordinate { x: 2, y: 0 },
+            })
+        );
+        assert_eq!(
+            b.set(Coordinate { x: 2, y: 1 }, 0, 'a', Some(&short_dict())),
+            Ok(BoardChangeDetail {
+                square: Square::Occupied {
+                    player: 0,
+                    tile: 'a',
+                    validity: SquareValidity::Invalid,
+                    foggy: false
+                },
+                coordinate: Coordinate { x: 2, y: 1 },
+            })
+        );
+    }
+
+    #[test]
+    fn clear_distinguishes_squares() {
+        let mut b = Board::from_string(
+            "A0 |0 __\n\
+             __ ~~ __\n\
+             __ |1 __",
+        );
+
+        let position = Coordinate { x: 0, y: 0 };
+        assert!(matches!(
+            b.clear(position, None),
+            Ok(Some(BoardChangeDetail {
+                square: Square::Occupied {
+                    player: 0,
+                    tile: 'A',
+                    ..
+                },
+                coordinate,
+            })) if coordinate == position
+        ));
+        assert_eq!(b.get(position), Ok(Square::land()));
+
+        // Nothing left to clear
+        assert_eq!(b.clear(position, None), Ok(None));
+        assert_eq!(b.clear(Coordinate { x: 1, y: 0 }, None), Ok(None));
+
+        let position = Coordinate { x: 1, y: 1 };
+        assert!(b.is_dead_square(position));
+        assert_eq!(
+            b.clear(position, None),
+            Err(GamePlayError::InvalidPosition { position })
+        );
+
+        let position = Coordinate { x: 3, y: 0 };
+        assert!(!b.is_dead_square(position));
+        assert_eq!(
+            b.clear(position, None),
+            Err(GamePlayError::OutSideBoardDimensions { position })
+        );
+    }
+
+    #[test]
+    fn set_requires_valid_player() {
+        let mut b = Board::from_string(
+            "__ |0 __\n\
+             __ |1 __",
+        );
+
+        assert_eq!(
+            b.set(Coordinate { x: 0, y: 0 }, 0, 'a', Some(&short_dict())),
+            Ok(BoardChangeDetail {
+                square: Square::Occupied {
+                    player: 0,
+                    tile: 'a',
+                    validity: SquareValidity::Invalid,
+                    foggy: false
+                },
+                coordinate: Coordinate { x: 0, y: 0 },
+            })
+        );
+        assert_eq!(
+            b.set(Coordinate { x: 0, y: 1 }, 1, 'a', Some(&short_dict())),
+            Ok(BoardChangeDetail {
+                square: Square::Occupied {
+                    player: 1,
+                    tile: 'a',
+                    validity: SquareValidity::Invalid,
+                    foggy: false
+                },
+                coordinate: Coordinate { x: 0, y: 1 },
+            })
+        );
+        assert_eq!(
+            b.set(Coordinate { x: 2, y: 0 }, 2, 'a', None),
+            Err(GamePlayError::NonExistentPlayer { index: 2 })
+        );
+        assert_eq!(
+            b.set(Coordinate { x: 2, y: 0 }, 3, 'a', None),
+            Err(GamePlayError::NonExistentPlayer { index: 3 })
+        );
+        assert_eq!(
+            b.set(Coordinate { x: 2, y: 0 }, 100, 'a', None),
+            Err(GamePlayError::NonExistentPlayer { index: 100 })
+        );
+    }
+
+    #[test]
+    fn set_changes_get() {
+        let mut b = Board::new(3, 3); // Note, height is 3 from home rows
+        assert_eq!(b.get(Coordinate { x: 2, y: 2 }), Ok(Square::land()));
+        assert_eq!(
+            b.set(Coordinate { x: 2, y: 2 }, 0, 'a', Some(&short_dict())),
+            Ok(BoardChangeDetail {
+                square: Square::Occupied {
+                    player: 0,
+                    tile: 'a',
+                    validity: SquareValidity::Invalid,
+                    foggy: false
+                },
+                coordinate: Coordinate { x: 2, y: 2 },
+            })
+        );
+        assert_eq!(
+            b.get(Coordinate { x: 2, y: 2 }),
+            Ok(Square::Occupied {
+                player: 0,
+                tile: 'a',
+                validity: SquareValidity::Invalid,
+                foggy: false
+            })
+        );
+    }
+
+    #[test]
+    fn enemy_root_reachable() {
+        let open = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             __ A0 __ __\n\
+             __ B1 __ ~~\n\
+             ~~ ~~ |1 ~~",
+        );
+        assert!(open.enemy_root_reachable(0));
+        assert!(open.enemy_root_reachable(1));
+        assert!(!open.enemy_root_reachable(2));
+
+        // A wall of water permanently separates the two roots
+        let walled = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             __ A0 __ __\n\
+             ~~ ~~ ~~ ~~\n\
+             __ B1 __ __\n\
+             ~~ ~~ |1 ~~",
+        );
+        assert!(!walled.enemy_root_reachable(0));
+        assert!(!walled.enemy_root_reachable(1));
+        assert!(!walled.path_exists(Coordinate::new(0, 1), Coordinate::new(0, 3)));
+        assert!(walled.path_exists(Coordinate::new(0, 1), Coordinate::new(3, 1)));
+    }
+
+    #[test]
+    fn truncate_larger() {
+        let mut b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~ ~~\n\
+             A0 B0 __ C0 D0 __\n\
+             __ __ __ __ __ E0\n\
+             F0 __ G1 H1 __ I0\n\
+             J0 __ __ __ __ K0\n\
+             __ L1 M1 __ N1 O1\n\
+             ~~ ~~ ~~ |1 ~~ ~~",
+        );
+        let mut bag = TileBag::explicit(vec![], Some(1));
+
+        let changes = b.truncate_larger(&mut bag, None, &HashSet::new());
+        let truncated: Vec<_> = changes
+            .iter()
+            .map(|change| match change {
+                Change::Board(BoardChange {
+                    detail: BoardChangeDetail { coordinate, .. },
+                    action: BoardChangeAction::Truncated,
+                }) => *coordinate,
+                _ => panic!("Truncation only removes tiles from the board"),
+            })
+            .collect();
+
+        // E0 I0 K0 is player 0's largest group, whether or not it reaches the artifact.
+        // Player 1 has three pairs, so the highest pair is kept.
+        assert_eq!(
+            truncated,
+            vec![
+                Coordinate::new(0, 1),
+                Coordinate::new(1, 1),
+                Coordinate::new(3, 1),
+                Coordinate::new(4, 1),
+                Coordinate::new(0, 3),
+                Coordinate::new(0, 4),
+                Coordinate::new(1, 5),
+                Coordinate::new(2, 5),
+                Coordinate::new(4, 5),
+                Coordinate::new(5, 5),
+            ]
+        );
+        assert_eq!(
+            bag,
+            TileBag::explicit("ABCDFJLMNO".chars().collect(), Some(1))
+        );
+
+        // Between pairs on the same row, the leftmost is kept
+        let mut b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             A0 B0 __ C0 D0\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        b.truncate_larger(&mut bag, None, &HashSet::new());
+        assert_eq!(
+            b,
+            Board::from_string(
+                "~~ ~~ |0 ~~ ~~\n\
+                 A0 B0 __ __ __\n\
+                 ~~ ~~ |1 ~~ ~~",
+            )
+        );
+    }
+
+    #[test]
+    fn remove_penalty_tile() {
+        let mut b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ A0 B0 C0 __\n\
+             __ __ D0 __ __\n\
+             __ __ E0 __ __\n\
+             __ __ __ __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let mut bag = TileBag::explicit(vec![], Some(1));
+
+        // Leaves go before the tiles holding them up, furthest from the artifact first
+        let mut removed = vec![];
+        while let Some(Change::Board(change)) = b.remove_penalty_tile(0, &mut bag, None) {
+            assert_eq!(change.action, BoardChangeAction::Truncated);
+            removed.push(change.detail.coordinate);
+        }
+        assert_eq!(
+            removed,
+            vec![
+                Coordinate::new(2, 3),
+                Coordinate::new(1, 1),
+                Coordinate::new(3, 1),
+                Coordinate::new(2, 2),
+                Coordinate::new(2, 1),
+            ]
+        );
+        assert_eq!(
+            bag.to_string(),
+            "Letters in the bag:\n['E', 'A', 'C', 'D', 'B']"
+        );
+
+        // A0 and B0 are the only links to the loop, so it is worn down first
+        // and nothing is ever left cut off from the artifact
+        let mut b = Board::from_string(
+            "|0 __ __ __\n\
+             A0 __ __ __\n\
+             B0 C0 D0 __\n\
+             __ E0 F0 __\n\
+             __ __ __ |1",
+        );
+        for remaining in (0..6).rev() {
+            assert!(b.remove_penalty_tile(0, &mut bag, None).is_some());
+            assert_eq!(b.truncate(&mut bag, None, &HashSet::new()), vec![]);
+            assert_eq!(
+                b.squares
+                    .iter()
+                    .flatten()
+                    .filter(|sq| matches!(sq, Square::Occupied { .. }))
+                    .count(),
+                remaining
+            );
+        }
+        assert_eq!(b.remove_penalty_tile(0, &mut bag, None), None);
+    }
+
+    #[test]
+    fn diagonal_connectivity() {
+        let board = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             __ A0 __ __\n\
+             __ __ B0 __\n\
+             __ __ __ C0\n\
+             ~~ ~~ |1 ~~",
+        );
+
+        // Orthogonally, the diagonal chain is cut off from A0
+        let mut b = board.clone();
+        let mut bag = TileBag::explicit(vec![], None);
+        assert_eq!(b.truncate(&mut bag, None, &HashSet::new()).len(), 2);
+
+        // Tiles touching at a corner stay attached under King connectivity
+        let mut b = board.clone();
+        b.connectivity = Connectivity::King;
+        assert_eq!(b.depth_first_search(Coordinate::new(1, 0)).len(), 4);
+        assert!(b.truncate(&mut bag, None, &HashSet::new()).is_empty());
+        assert_eq!(b.squares, board.squares);
+    }
+
+    #[test]
+    fn anchored_words_survive_truncation() {
+        let board = Board::from_string(
+            "~~ ~~ |0 ~~ ~~ ~~\n\
+             __ __ __ __ __ __\n\
+             J1 O1 L1 L1 Y1 __\n\
+             __ __ __ __ __ __\n\
+             B1 I1 G1 __ __ __\n\
+             __ __ __ __ __ __\n\
+             ~~ ~~ ~~ ~~ |1 ~~",
+        );
+        let judge = Judge::new(vec!["JOLLY".into(), "BIG".into()]);
+        let mut rules = GameRules::generation(0);
+
+        // Without the rule, everything cut off from the artifact goes
+        let mut b = board.clone();
+        let anchored = b.anchored_tiles(&judge, &rules, None);
+        assert!(anchored.is_empty());
+        assert_eq!(
+            b.truncate(&mut TileBag::explicit(vec![], None), None, &anchored)
+                .len(),
+            8
         );
+
+        // Only the five letter word is long enough to anchor itself
+        rules.truncation_immune_word_length = Some(5);
+        let mut b = board.clone();
+        let anchored = b.anchored_tiles(&judge, &rules, None);
         assert_eq!(
-            b.set(Coordinate { x: 2, y: 0 }, 0, 'a', Some(&short_dict())),
-            Ok(BoardChangeDetail {
-                square: Square::Occupied {
-                    player: 0,
-                    tile: 'a',
-                    validity: SquareValidity::Invalid,
-                    foggy: false
-                },
-                coordinate: Coordinate { x: 2, y: 0 },
-            })
+            anchored,
+            (0..5)
+                .map(|x| Coordinate::new(x, 2))
+                .collect::<HashSet<_>>()
         );
+        let changes = b.truncate(&mut TileBag::explicit(vec![], None), None, &anchored);
+        assert_eq!(changes.len(), 3);
         assert_eq!(
-            b.set(Coordinate { x: 2, y: 1 }, 0, 'a', Some(&short_dict())),
-            Ok(BoardChangeDetail {
-                square: Square::Occupied {
-                    player: 0,
-                    tile: 'a',
-                    validity: SquareValidity::Invalid,
-                    foggy: false
-                },
-                coordinate: Coordinate { x: 2, y: 1 },
-            })
+            b.to_string(),
+            "~~ ~~ |0 ~~ ~~ ~~\n\
+             __ __ __ __ __ __\n\
+             J1 O1 L1 L1 Y1 __\n\
+             __ __ __ __ __ __\n\
+             __ __ __ __ __ __\n\
+             __ __ __ __ __ __\n\
+             ~~ ~~ ~~ ~~ |1 ~~",
         );
+
+        // Invalid words aren't anchored, however long
+        let judge = Judge::new(vec!["BIG".into()]);
+        assert!(board.anchored_tiles(&judge, &rules, None).is_empty());
     }
 
     #[test]
-    fn set_requires_valid_player() {
+    fn dead_tiles() {
         let mut b = Board::from_string(
-            "__ |0 __\n\
-             __ |1 __",
+            "~~ ~~ |0 ~~ ~~\n\
+             __ A0 B0 __ __\n\
+             C0 __ __ __ D1\n\
+             E0 __ F1 __ G1\n\
+             __ __ H1 __ __\n\
+             ~~ ~~ |1 ~~ ~~",
         );
+        let mut bag = TileBag::explicit(vec![], Some(1));
+        let before = b.clone();
 
+        let dead = b.dead_tiles();
         assert_eq!(
-            b.set(Coordinate { x: 0, y: 0 }, 0, 'a', Some(&short_dict())),
-            Ok(BoardChangeDetail {
-                square: Square::Occupied {
-                    player: 0,
-                    tile: 'a',
-                    validity: SquareValidity::Invalid,
-                    foggy: false
-                },
-                coordinate: Coordinate { x: 0, y: 0 },
-            })
+            dead,
+            HashSet::from([
+                Coordinate::new(4, 2),
+                Coordinate::new(0, 2),
+                Coordinate::new(0, 3),
+                Coordinate::new(4, 3),
+            ])
         );
-        assert_eq!(
-            b.set(Coordinate { x: 0, y: 1 }, 1, 'a', Some(&short_dict())),
-            Ok(BoardChangeDetail {
-                square: Square::Occupied {
-                    player: 1,
-                    tile: 'a',
-                    validity: SquareValidity::Invalid,
-                    foggy: false
-                },
-                coordinate: Coordinate { x: 0, y: 1 },
+        // Nothing has been removed yet
+        assert_eq!(b, before);
+        assert_eq!(bag.remaining(), 0);
+
+        let cleared: HashSet<_> = b
+            .truncate(&mut bag, None, &HashSet::new())
+            .into_iter()
+            .map(|change| match change {
+                Change::Board(change) => change.detail.coordinate,
+                _ => unreachable!(),
             })
+            .collect();
+        assert_eq!(cleared, dead);
+        assert!(b.dead_tiles().is_empty());
+    }
+
+    #[test]
+    fn revert_truncation() {
+        let mut b = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             __ A0 __ __\n\
+             __ __ B0 C0\n\
+             ~~ ~~ |1 ~~",
         );
+        let mut bag = TileBag::explicit(vec!['X'], Some(1));
+        let (original_board, original_bag) = (b.clone(), bag.clone());
+
+        let changes = b.truncate(&mut bag, None, &HashSet::new());
+        assert_eq!(changes.len(), 2);
+        assert_eq!(bag, TileBag::explicit(vec!['X', 'B', 'C'], Some(1)));
+
+        for change in changes.iter().rev() {
+            let Change::Board(change) = change else {
+                panic!("Truncation only changes the board");
+            };
+            assert_eq!(b.revert_change(change, &mut bag), Ok(()));
+        }
+        assert_eq!(b, original_board);
+        assert_eq!(bag, original_bag);
+
+        // The tiles can't be pulled out of the bag twice
+        let Change::Board(first) = &changes[0] else {
+            unreachable!()
+        };
+        b.clear(first.detail.coordinate, None).unwrap();
         assert_eq!(
-            b.set(Coordinate { x: 2, y: 0 }, 2, 'a', None),
-            Err(GamePlayError::NonExistentPlayer { index: 2 })
-        );
-        assert_eq!(
-            b.set(Coordinate { x: 2, y: 0 }, 3, 'a', None),
-            Err(GamePlayError::NonExistentPlayer { index: 3 })
-        );
-        assert_eq!(
-            b.set(Coordinate { x: 2, y: 0 }, 100, 'a', None),
-            Err(GamePlayError::NonExistentPlayer { index: 100 })
+            b.revert_change(first, &mut bag),
+            Err(GamePlayError::TileNotInBag { tile: 'B' })
         );
     }
 
     #[test]
-    fn set_changes_get() {
-        let mut b = Board::new(3, 3); // Note, height is 3 from home rows
-        assert_eq!(b.get(Coordinate { x: 2, y: 2 }), Ok(Square::land()));
+    fn apply_change_groups() {
+        let mut b = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             __ A0 __ __\n\
+             __ __ B1 __\n\
+             ~~ ~~ |1 ~~",
+        );
+        let original_board = b.clone();
+        let change = |x: usize, y: usize, square: Square, action: BoardChangeAction| BoardChange {
+            detail: BoardChangeDetail {
+                square,
+                coordinate: Coordinate::new(x, y),
+            },
+            action,
+        };
+        let tile = |player: usize, tile: char| Square::Occupied {
+            player,
+            tile,
+            validity: SquareValidity::Unknown,
+            foggy: false,
+        };
+
+        // Placing on top of B1 is invalid, so the valid placement before it is rolled back too
+        let group = [
+            change(2, 1, tile(0, 'C'), BoardChangeAction::Added),
+            change(2, 2, tile(0, 'D'), BoardChangeAction::Added),
+        ];
         assert_eq!(
-            b.set(Coordinate { x: 2, y: 2 }, 0, 'a', Some(&short_dict())),
-            Ok(BoardChangeDetail {
-                square: Square::Occupied {
-                    player: 0,
-                    tile: 'a',
-                    validity: SquareValidity::Invalid,
-                    foggy: false
-                },
-                coordinate: Coordinate { x: 2, y: 2 },
-            })
+            b.apply_change_group(&group),
+            Err(GamePlayError::OccupiedPlace)
         );
+        assert_eq!(b, original_board);
+
+        let group = [
+            change(2, 1, tile(0, 'C'), BoardChangeAction::Added),
+            change(2, 2, tile(1, 'B'), BoardChangeAction::Defeated),
+        ];
+        assert_eq!(b.apply_change_group(&group), Ok(()));
         assert_eq!(
-            b.get(Coordinate { x: 2, y: 2 }),
-            Ok(Square::Occupied {
-                player: 0,
-                tile: 'a',
-                validity: SquareValidity::Invalid,
-                foggy: false
-            })
+            b,
+            Board::from_string(
+                "~~ |0 ~~ ~~\n\
+                 __ A0 C0 __\n\
+                 __ __ __ __\n\
+                 ~~ ~~ |1 ~~",
+            )
         );
     }
 
@@ -2419,33 +5881,304 @@ pub mod tests {
             assert!(b.depth_first_search(part).is_superset(&parts_set));
         }
 
-        // Set a remaining unoccupied square on the board to be occupied by another player
-        let other = Coordinate { x: 2, y: 2 };
-        // When unoccupied it should give the empty set, when occupied, just itself
-        assert!(b
-            .depth_first_search(other)
-            .iter()
-            .collect::<Vec<_>>()
-            .is_empty());
+        // Set a remaining unoccupied square on the board to be occupied by another player
+        let other = Coordinate { x: 2, y: 2 };
+        // When unoccupied it should give the empty set, when occupied, just itself
+        assert!(b
+            .depth_first_search(other)
+            .iter()
+            .collect::<Vec<_>>()
+            .is_empty());
+        assert_eq!(
+            b.set(other, 1, 'a', Some(&short_dict())),
+            Ok(BoardChangeDetail {
+                square: Square::Occupied {
+                    player: 1,
+                    tile: 'a',
+                    validity: SquareValidity::Invalid,
+                    foggy: false
+                },
+                coordinate: other,
+            })
+        );
+        assert!(b.depth_first_search(other).iter().eq([other].iter()));
+
+        // The result of DFS on the main tree should not have changed
+        for part in parts {
+            assert!(b.depth_first_search(part).is_subset(&parts_set));
+            assert!(b.depth_first_search(part).is_superset(&parts_set));
+        }
+    }
+
+    #[test]
+    fn gap_suggestions() {
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ __ __ __ __\n\
+             __ F1 __ T1 __\n\
+             __ __ N1 __ __\n\
+             B1 __ D1 __ __\n\
+             __ __ __ __ G1\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let judge = Judge::new(vec!["BIG".into(), "FAT".into(), "FIT".into(), "AND".into()]);
+        let rules = GameRules::generation(0);
+        let suggest = |x, y| b.gap_suggestions(Coordinate::new(x, y), 1, &judge, &rules, None);
+
+        // FAT and FIT both fit across the gap, but only AND fits down it
+        assert_eq!(suggest(2, 2), vec!['A']);
+        assert_eq!(suggest(1, 4), vec![]);
+        // A gap joining nothing, or joining only the other player's tiles, has no suggestions
+        assert_eq!(suggest(4, 1), vec![]);
+        assert_eq!(
+            b.gap_suggestions(Coordinate::new(2, 2), 0, &judge, &rules, None),
+            vec![]
+        );
+        // Squares that aren't empty can't be filled
+        assert_eq!(suggest(1, 2), vec![]);
+    }
+
+    #[test]
+    fn connected_components() {
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ A0 B0 __ __\n\
+             __ __ C0 X1 __\n\
+             D0 __ __ __ __\n\
+             E0 F0 __ G0 __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let coords = |coords: &[(usize, usize)]| {
+            coords
+                .iter()
+                .map(|(x, y)| Coordinate::new(*x, *y))
+                .collect()
+        };
+
+        let components = b.connected_components(0);
+        assert_eq!(
+            components,
+            vec![
+                coords(&[(1, 1), (2, 1), (2, 2)]),
+                coords(&[(0, 3), (0, 4), (1, 4)]),
+                coords(&[(3, 4)]),
+            ]
+        );
+        // Each of the player's tiles is in exactly one group
+        let tiles = b
+            .squares
+            .iter()
+            .flatten()
+            .filter(|sq| matches!(sq, Square::Occupied { player: 0, .. }))
+            .count();
+        assert_eq!(components.iter().map(|c| c.len()).sum::<usize>(), tiles);
+
+        assert_eq!(b.connected_components(1), vec![coords(&[(3, 2)])]);
+        assert!(b.connected_components(2).is_empty());
+    }
+
+    #[test]
+    fn depth_first_search_long_path() {
+        // A single tile wide path snaking back and forth across the board
+        let (width, height) = (100, 60);
+        let mut b = Board::new_with_players(width, height, vec![], vec![]);
+        let mut path = vec![];
+        for y in (1..=height).step_by(2) {
+            let row: Vec<_> = (1..=width).map(|x| Coordinate::new(x, y)).collect();
+            if (y / 2) % 2 == 0 {
+                path.extend(row);
+                path.push(Coordinate::new(width, y + 1));
+            } else {
+                path.extend(row.into_iter().rev());
+                path.push(Coordinate::new(1, y + 1));
+            }
+        }
+        path.retain(|c| c.y <= height);
+        for c in &path {
+            b.set_square(
+                *c,
+                Square::Occupied {
+                    player: 0,
+                    tile: 'A',
+                    validity: SquareValidity::Unknown,
+                    foggy: false,
+                },
+            )
+            .unwrap();
+        }
+
+        assert!(path.len() > 3000);
+        assert_eq!(
+            b.depth_first_search(path[0]),
+            path.iter().copied().collect::<HashSet<_>>()
+        );
+    }
+
+    #[test]
+    fn territorial_owner() {
+        let b = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             __ A0 __ __\n\
+             __ __ __ __\n\
+             __ __ __ __\n\
+             __ B1 __ __\n\
+             ~~ ~~ |1 ~~",
+        );
+
+        let tile = Coordinate::new(1, 1);
+        assert_eq!(b.occupied_owner(tile), Some(0));
+        assert_eq!(b.territorial_owner(tile), Some(0));
+        assert_eq!(b.occupied_owner(Coordinate::new(2, 5)), Some(1));
+
+        // Empty land has no owner, but does fall in the nearest player's territory
+        let near_north = Coordinate::new(0, 1);
+        assert_eq!(b.occupied_owner(near_north), None);
+        assert_eq!(b.territorial_owner(near_north), Some(0));
+        assert_eq!(b.territorial_owner(Coordinate::new(3, 4)), Some(1));
+
+        // Land as close to both artifacts is contested, and water is never owned
+        assert_eq!(b.territorial_owner(Coordinate::new(0, 3)), None);
+        assert_eq!(b.territorial_owner(Coordinate::new(0, 0)), None);
+    }
+
+    #[test]
+    fn board_summary() {
+        let b = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             __ A0 __ __\n\
+             __ __ __ __\n\
+             __ __ __ __\n\
+             __ B1 C1 __\n\
+             ~~ ~~ |1 ~~",
+        );
+
+        let summary = b.summary();
+        assert_eq!(summary.tile_counts, vec![1, 2]);
+        // B1 is four steps from the north artifact, while A0 is five from the south one
+        assert_eq!(summary.distance_to_enemy_root, vec![Some(5), Some(4)]);
+        assert_eq!(summary.contested_squares, 4);
+
+        // The single pass agrees with checking each square's territory separately
+        let rows = b.height();
+        let cols = b.width();
+        let contested = (0..rows)
+            .flat_map(|y| (0..cols).zip(std::iter::repeat(y)))
+            .map(|(x, y)| Coordinate { x, y })
+            .filter(|c| {
+                matches!(b.get(*c), Ok(Square::Land { .. })) && b.territorial_owner(*c).is_none()
+            })
+            .count();
+        assert_eq!(summary.contested_squares, contested);
+
+        // Players without tiles have nothing to measure
+        let empty = Board::new(4, 4).summary();
+        assert_eq!(empty.tile_counts, vec![0, 0]);
+        assert_eq!(empty.distance_to_enemy_root, vec![None, None]);
+    }
+
+    #[test]
+    fn enclosed_by() {
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~ ~~ ~~\n\
+             __ A0 A0 A0 __ __ __\n\
+             __ A0 __ A0 __ B1 __\n\
+             __ A0 __ A0 B1 __ B1\n\
+             __ A0 A0 A0 __ B1 __\n\
+             __ __ __ __ __ __ __\n\
+             __ A0 A0 __ __ __ __\n\
+             __ A0 __ __ __ __ __\n\
+             __ A0 A0 A0 __ __ __\n\
+             ~~ ~~ ~~ |1 ~~ ~~ ~~",
+        );
+
+        // The pocket at the top is fully walled in by player 0
+        assert_eq!(
+            b.enclosed_by(0),
+            HashSet::from([Coordinate::new(2, 2), Coordinate::new(2, 3)])
+        );
+        // Player 1's pocket is enclosed too, while the gap in player 0's lower wall leaves it open
+        assert_eq!(b.enclosed_by(1), HashSet::from([Coordinate::new(5, 3)]));
+        assert!(b.enclosed_by(2).is_empty());
+    }
+
+    #[test]
+    fn exposure() {
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ A0 B0 C0 __\n\
+             __ D1 E0 F1 __\n\
+             __ G1 H1 __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        // B0 and G1 only touch their own side's tiles
+        assert_eq!(b.exposure(0), 3);
+        assert_eq!(b.exposure(1), 3);
+
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             A0 B0 __ __ __\n\
+             __ __ __ __ __\n\
+             __ __ __ C1 D1\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        assert_eq!(b.exposure(0), 0);
+        assert_eq!(b.exposure(1), 0);
+    }
+
+    #[test]
+    fn nearest_enemy_tile() {
+        // B1 sits straight below A0 but behind water, so the nearest enemy is C1 around the corner
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~ ~~\n\
+             __ __ A0 __ __ __\n\
+             __ ~~ ~~ ~~ __ __\n\
+             __ __ B1 __ C1 __\n\
+             __ __ __ __ __ __\n\
+             ~~ ~~ ~~ |1 ~~ ~~",
+        );
+        assert_eq!(b.nearest_enemy_tile(0), Some((Coordinate::new(4, 3), 4)));
+        assert_eq!(b.nearest_enemy_tile(1), Some((Coordinate::new(2, 1), 4)));
+
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ __ A0 __ __\n\
+             ~~ ~~ ~~ ~~ ~~\n\
+             __ __ B1 __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        assert_eq!(b.nearest_enemy_tile(0), None);
+        assert_eq!(b.nearest_enemy_tile(2), None);
+    }
+
+    #[test]
+    fn label_regions() {
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             A0 B0 __ __ C0\n\
+             __ __ __ __ D0\n\
+             __ __ __ __ E1\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+
+        let labels = b.label_regions(true);
+        assert_eq!(labels.len(), 5);
         assert_eq!(
-            b.set(other, 1, 'a', Some(&short_dict())),
-            Ok(BoardChangeDetail {
-                square: Square::Occupied {
-                    player: 1,
-                    tile: 'a',
-                    validity: SquareValidity::Invalid,
-                    foggy: false
-                },
-                coordinate: other,
-            })
+            labels[&Coordinate::new(0, 1)],
+            labels[&Coordinate::new(1, 1)]
         );
-        assert!(b.depth_first_search(other).iter().eq([other].iter()));
+        assert_eq!(
+            labels[&Coordinate::new(4, 1)],
+            labels[&Coordinate::new(4, 2)]
+        );
+        assert_eq!(labels.values().collect::<HashSet<_>>().len(), 3);
 
-        // The result of DFS on the main tree should not have changed
-        for part in parts {
-            assert!(b.depth_first_search(part).is_subset(&parts_set));
-            assert!(b.depth_first_search(part).is_superset(&parts_set));
-        }
+        // Ignoring ownership joins the two players' tiles on the right
+        let labels = b.label_regions(false);
+        assert_eq!(
+            labels[&Coordinate::new(4, 2)],
+            labels[&Coordinate::new(4, 3)]
+        );
+        assert_eq!(labels.values().collect::<HashSet<_>>().len(), 2);
     }
 
     #[test]
@@ -2520,131 +6253,654 @@ pub mod tests {
             Some(3)
         );
         assert_eq!(
-            dists.attackable_distance(&Coordinate { x: 0, y: 5 }),
-            Some(4)
+            dists.attackable_distance(&Coordinate { x: 0, y: 5 }),
+            Some(4)
+        );
+        assert_eq!(dists.direct_distance(&Coordinate { x: 0, y: 6 }), Some(5));
+        assert_eq!(dists.attackable_distance(&Coordinate { x: 1, y: 6 }), None);
+
+        // If we had a direct path there though, we can look up how far it is.
+        assert_eq!(dists.direct_distance(&Coordinate { x: 1, y: 6 }), Some(4));
+
+        // In the middle, the G1 blocks us from being adjacent to the A1
+        assert_eq!(dists.attackable_distance(&Coordinate { x: 3, y: 6 }), None);
+
+        // Far right, we have to go back and around the Q1 tower to reach the end
+        assert_eq!(
+            dists.attackable_distance(&Coordinate { x: 9, y: 7 }),
+            Some(13)
+        );
+        // Though if we could go straight there...
+        assert_eq!(dists.direct_distance(&Coordinate { x: 9, y: 7 }), Some(9));
+        // And to attack the bottom-most Q1, we would have to visit all the way from the right
+        assert_eq!(
+            dists.attackable_distance(&Coordinate { x: 7, y: 7 }),
+            Some(15)
+        );
+        // Though if we could go straight there...
+        assert_eq!(dists.direct_distance(&Coordinate { x: 7, y: 7 }), Some(7));
+        // The one above it we could attack from the left, though
+        assert_eq!(
+            dists.attackable_distance(&Coordinate { x: 7, y: 6 }),
+            Some(6)
+        );
+        // Which is also the best we could do anyway
+        assert_eq!(dists.direct_distance(&Coordinate { x: 7, y: 6 }), Some(6));
+    }
+
+    #[test]
+    fn flood_fill_towns() {
+        let board = Board::from_string(
+            r###"
+            ~~ ~~ |0 ~~ ~~
+            __ #0 R0 __ __
+            __ __ A0 __ #0
+            __ G1 __ __ __
+            B1 A1 #1 __ __
+            __ T1 N1 X1 __
+            ~~ ~~ |1 ~~ ~~
+            "###,
+        );
+
+        let zero_dists = board.flood_fill_from_towns(0);
+        let one_dists = board.flood_fill_from_towns(1);
+
+        let zd = |x: usize, y: usize| zero_dists.direct_distance(&Coordinate { x, y });
+        let od = |x: usize, y: usize| one_dists.direct_distance(&Coordinate { x, y });
+
+        assert_eq!(zd(0, 1), Some(0));
+        assert_eq!(zd(0, 2), Some(1));
+        assert_eq!(zd(4, 1), Some(0));
+        assert_eq!(zd(4, 5), Some(2));
+        assert_eq!(zd(3, 5), Some(3));
+        assert_eq!(zd(2, 5), Some(4));
+        assert_eq!(zd(1, 5), Some(3));
+
+        assert_eq!(od(0, 1), Some(4));
+        assert_eq!(od(2, 3), Some(0));
+    }
+
+    #[test]
+    fn proximity_scores() {
+        let board = Board::from_string(
+            r###"
+            ~~ ~~ |0 ~~ ~~
+            __ #0 R0 __ __
+            __ __ A0 __ #0
+            __ G1 __ __ __
+            B1 A1 #1 __ __
+            __ T1 N1 X1 __
+            ~~ ~~ |1 ~~ ~~
+            "###,
+        );
+
+        let zero_prox = board.proximity_to_enemy_town(0);
+        let one_prox = board.proximity_to_enemy_town(1);
+
+        assert_eq!(zero_prox, vec![2, 1]);
+        assert_eq!(one_prox, vec![4, 3, 3, 3, 2, 1]);
+    }
+
+    #[test]
+    fn coordinate_distances() {
+        let origin = Coordinate::new(0, 0);
+        let c = Coordinate::new(3, 5);
+
+        assert_eq!(c.manhattan_distance(c), 0);
+        assert_eq!(c.chebyshev_distance(c), 0);
+
+        assert_eq!(origin.manhattan_distance(c), 8);
+        assert_eq!(c.manhattan_distance(origin), 8);
+        assert_eq!(origin.chebyshev_distance(c), 5);
+        assert_eq!(c.chebyshev_distance(origin), 5);
+
+        // Distances never wrap around the zero edge, or overflow at the far one
+        let far = Coordinate::new(usize::MAX, usize::MAX);
+        assert_eq!(origin.manhattan_distance(far), usize::MAX);
+        assert_eq!(far.manhattan_distance(origin), usize::MAX);
+        assert_eq!(far.chebyshev_distance(origin), usize::MAX);
+        assert_eq!(
+            Coordinate::new(0, 2).manhattan_distance(Coordinate::new(1, 0)),
+            3
+        );
+    }
+
+    #[test]
+    fn get_neighbours() {
+        // (0,0) (1,0) (2,0) (3,0) (4,0) (5,0)
+        // (0,1) (1,1) (2,1) (3,1) (4,1) (5,1)
+        // (0,2) (1,2) (2,2) (3,2) (4,2) (5,2)
+        // (0,3) (1,3) (2,3) (3,3) (4,3) (5,3)
+        // (0,4) (1,4) (2,4) (3,4) (4,4) (5,4)
+        // (0,5) (1,5) (2,5) (3,5) (4,5) (5,5)
+        let b = Board::new(4, 4);
+
+        assert_eq!(
+            // TODO: should we allow you to find neighbours of an invalid square?
+            b.neighbouring_squares(Coordinate { x: 0, y: 0 }),
+            [
+                (Coordinate { x: 1, y: 0 }, Square::water()),
+                (Coordinate { x: 0, y: 1 }, Square::water()),
+            ]
+        );
+
+        assert_eq!(
+            b.neighbouring_squares(Coordinate { x: 0, y: 4 }),
+            [
+                (Coordinate { x: 0, y: 3 }, Square::water()),
+                (Coordinate { x: 1, y: 4 }, Square::artifact(1)),
+                (Coordinate { x: 0, y: 5 }, Square::water()),
+            ]
+        );
+
+        assert_eq!(
+            b.neighbouring_squares(Coordinate { x: 2, y: 2 }),
+            [
+                (Coordinate { x: 2, y: 1 }, Square::town(0)),
+                (Coordinate { x: 3, y: 2 }, Square::land()),
+                (Coordinate { x: 2, y: 3 }, Square::land()),
+                (Coordinate { x: 1, y: 2 }, Square::town(1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn review_capturing_turn() {
+        let before = Board::from_string(
+            "__ S0 X0 |0 __\n\
+             __ T0 __ __ __\n\
+             __ R0 __ __ __\n\
+             __ __ I1 __ __\n\
+             __ __ T1 Q1 __\n\
+             __ __ __ __ __\n\
+             __ __ |1 __ __",
+        );
+        let after = Board::from_string(
+            "__ S0 X0 |0 __\n\
+             __ T0 __ __ __\n\
+             __ R0 __ __ __\n\
+             __ A0 __ __ __\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             __ __ |1 __ __",
+        );
+
+        let reviewed = |x, y, player, tile, action| ReviewedSquare {
+            coordinate: Coordinate::new(x, y),
+            player,
+            tile,
+            action,
+        };
+        assert_eq!(
+            before.review_diff(&after).squares,
+            vec![
+                reviewed(1, 3, 0, 'A', ReviewAction::Placed),
+                reviewed(2, 3, 1, 'I', ReviewAction::Captured),
+                reviewed(2, 4, 1, 'T', ReviewAction::Captured),
+                reviewed(3, 4, 1, 'Q', ReviewAction::Truncated),
+            ]
+        );
+
+        let swapped = Board::from_string(
+            "__ T0 X0 |0 __\n\
+             __ S0 __ __ __\n\
+             __ R0 __ __ __\n\
+             __ __ I1 __ __\n\
+             __ __ T1 Q1 __\n\
+             __ __ __ __ __\n\
+             __ __ |1 __ __",
+        );
+        assert_eq!(
+            before.review_diff(&swapped).squares,
+            vec![
+                reviewed(1, 0, 0, 'T', ReviewAction::Swapped { previous_tile: 'S' }),
+                reviewed(1, 1, 0, 'S', ReviewAction::Swapped { previous_tile: 'T' }),
+            ]
+        );
+        assert_eq!(before.review_diff(&before), ReviewDiff::default());
+    }
+
+    #[test]
+    fn get_neighbour_coords() {
+        let b = Board::from_string(
+            "__ A0 ~~\n\
+             ~~ __ |0\n\
+             __ __ __",
+        );
+
+        assert_eq!(
+            b.neighbour_coords(Coordinate { x: 0, y: 0 }),
+            [
+                (Coordinate { x: 1, y: 0 }, true),
+                (Coordinate { x: 0, y: 1 }, false),
+            ],
+            "Corners should only report neighbours on the board"
+        );
+
+        assert_eq!(
+            b.neighbour_coords(Coordinate { x: 2, y: 1 }),
+            [
+                (Coordinate { x: 2, y: 0 }, false),
+                (Coordinate { x: 2, y: 2 }, true),
+                (Coordinate { x: 1, y: 1 }, true),
+            ],
+        );
+    }
+
+    #[test]
+    fn get_boundary_edges() {
+        let b = Board::from_string(
+            "~~ ~~ ~~\n\
+             ~~ __ __\n\
+             ~~ __ #0",
+        );
+        use Direction::*;
+
+        assert_eq!(
+            b.boundary_edges(),
+            vec![
+                (Coordinate::new(1, 1), North),
+                (Coordinate::new(1, 1), West),
+                (Coordinate::new(2, 1), North),
+                (Coordinate::new(2, 1), East),
+                (Coordinate::new(1, 2), South),
+                (Coordinate::new(1, 2), West),
+            ]
+        );
+    }
+
+    #[test]
+    fn best_attack() {
+        let b = Board::from_string(
+            "__ S0 |0 S0 __\n\
+             __ T0 __ T0 __\n\
+             __ R0 __ R0 __\n\
+             __ __ __ __ __\n\
+             __ I1 __ B1 __\n\
+             __ T1 __ I1 __\n\
+             __ __ __ X1 __\n\
+             __ __ |1 __ __",
+        );
+        let judge = Judge::new(vec!["ARTS".into(), "BIG".into()]);
+        let rules = GameRules::generation(0);
+
+        let hand = player::Hand(vec!['E', 'A', 'A']);
+        let (position, tile, battle) = b
+            .best_attack(0, &hand, &judge, &rules, &HashSet::new(), None)
+            .expect("Capturing moves exist");
+        assert_eq!(position, Coordinate::new(3, 3));
+        assert_eq!(tile, 'A');
+        assert_eq!(battle.outcome, Outcome::AttackerWins(vec![0]));
+
+        // Squares that can't be played on are passed over
+        let unavailable = HashSet::from([Coordinate::new(3, 3)]);
+        let other = b.best_attack(0, &hand, &judge, &rules, &unavailable, None);
+        assert!(other.is_some_and(|(position, _, _)| position != Coordinate::new(3, 3)));
+
+        // No letter forms a valid attacking word
+        let hand = player::Hand(vec!['E', 'Q']);
+        assert_eq!(
+            b.best_attack(0, &hand, &judge, &rules, &HashSet::new(), None),
+            None
+        );
+    }
+
+    #[test]
+    fn min_moves_to_win() {
+        let b = Board::from_string(
+            "~~ |0 ~~\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ G1 __\n\
+             ~~ |1 ~~",
+        );
+        let dict = short_dict();
+        let hand = player::Hand(vec!['X', 'B', 'I']);
+
+        // BIG reaches up to the enemy artifact
+        assert_eq!(
+            b.min_moves_to_win(1, &hand, &TileUtils::trivial_bag(), Some(&dict), 4),
+            Some(2)
+        );
+        assert_eq!(
+            b.min_moves_to_win(1, &hand, &TileUtils::trivial_bag(), Some(&dict), 1),
+            None
+        );
+
+        // Without a B no valid word can reach the artifact
+        let hand = player::Hand(vec!['X', 'I']);
+        assert_eq!(
+            b.min_moves_to_win(1, &hand, &TileUtils::trivial_bag(), Some(&dict), 4),
+            None
+        );
+
+        // Unless the B is drawn after playing the I
+        let mut dist = [0; 26];
+        dist[1] = 1;
+        assert_eq!(
+            b.min_moves_to_win(1, &hand, &TileBag::custom(dist, Some(1)), Some(&dict), 4),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn square_codes() {
+        let squares = [
+            Square::water(),
+            Square::land(),
+            Square::obelisk(),
+            Square::fog(),
+            Square::town(1),
+            Square::Town {
+                player: 0,
+                defeated: true,
+                foggy: false,
+            },
+            Square::artifact(0),
+            Square::Artifact {
+                player: 1,
+                defeated: true,
+                foggy: false,
+            },
+            Square::Occupied {
+                player: 1,
+                tile: 'Q',
+                validity: SquareValidity::Unknown,
+                foggy: false,
+            },
+            Square::Occupied {
+                player: 0,
+                tile: '*',
+                validity: SquareValidity::Unknown,
+                foggy: false,
+            },
+        ];
+        for square in squares {
+            assert_eq!(Square::from_code(square.to_code()), Some(square));
+        }
+
+        // Tiles can carry the same letters as towns and artifacts without turning into them
+        for tile in ['#', '|', '⊭', '¦'] {
+            let square = Square::Occupied {
+                player: 1,
+                tile,
+                validity: SquareValidity::Unknown,
+                foggy: false,
+            };
+            assert_eq!(square.to_code(), (1, tile));
+            assert_eq!(Square::from_code(square.to_code()), Some(square));
+        }
+        assert_eq!(Square::town(1).to_code(), (1 | TOWN_CODE, '#'));
+        assert_eq!(Square::from_code((1 | ARTIFACT_CODE, '#')), None);
+
+        // Disabled, empty, and occupied cells are kept apart
+        assert_eq!(Square::water().to_code(), (NO_PLAYER, '~'));
+        assert_eq!(Square::land().to_code(), (NO_PLAYER, '_'));
+        assert_eq!(
+            Square::Occupied {
+                player: 3,
+                tile: 'A',
+                validity: SquareValidity::Valid,
+                foggy: true,
+            }
+            .to_code(),
+            (3, 'A')
         );
-        assert_eq!(dists.direct_distance(&Coordinate { x: 0, y: 6 }), Some(5));
-        assert_eq!(dists.attackable_distance(&Coordinate { x: 1, y: 6 }), None);
 
-        // If we had a direct path there though, we can look up how far it is.
-        assert_eq!(dists.direct_distance(&Coordinate { x: 1, y: 6 }), Some(4));
+        assert_eq!(Square::from_code((NO_PLAYER, 'A')), None);
+        assert_eq!(Square::from_code((0, '~')), None);
+    }
 
-        // In the middle, the G1 blocks us from being adjacent to the A1
-        assert_eq!(dists.attackable_distance(&Coordinate { x: 3, y: 6 }), None);
+    /// A board with tiles lettered like towns and artifacts
+    fn glyph_tiles() -> Board {
+        let mut board = Board::from_string("|0 A0 B0 C0 D0 |1");
+        for (x, glyph) in [(1, '#'), (2, '|'), (3, '⊭'), (4, '¦')] {
+            if let Square::Occupied { tile, .. } = &mut board.squares[0][x] {
+                *tile = glyph;
+            }
+        }
+        board
+    }
 
-        // Far right, we have to go back and around the Q1 tower to reach the end
-        assert_eq!(
-            dists.attackable_distance(&Coordinate { x: 9, y: 7 }),
-            Some(13)
+    #[test]
+    fn share_codes() {
+        let mut defeated = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             #0 A0 ¤0 __\n\
+             __ __ B1 #1\n\
+             ~~ ~~ |1 ~~",
         );
-        // Though if we could go straight there...
-        assert_eq!(dists.direct_distance(&Coordinate { x: 9, y: 7 }), Some(9));
-        // And to attack the bottom-most Q1, we would have to visit all the way from the right
-        assert_eq!(
-            dists.attackable_distance(&Coordinate { x: 7, y: 7 }),
-            Some(15)
+        defeated.squares[1][0] = Square::Town {
+            player: 0,
+            defeated: true,
+            foggy: false,
+        };
+        defeated.squares[2][0] = Square::fog();
+        defeated.wrap = true;
+
+        let boards = [
+            Board::new(9, 9),
+            Board::from_string("|0 A0 B0 C0 __ |1"),
+            glyph_tiles(),
+            defeated,
+            Board::new_with_players(
+                5,
+                5,
+                vec![Coordinate::new(1, 0), Coordinate::new(5, 6)],
+                vec![Direction::NorthWest, Direction::SouthEast],
+            ),
+        ];
+        for board in boards {
+            let code = board.to_share_code();
+            assert!(code
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+            assert_eq!(Board::from_share_code(&code), Ok(board));
+        }
+
+        let code = Board::new(3, 3).to_share_code();
+        assert!(matches!(
+            Board::from_share_code("not a code!"),
+            Err(GamePlayError::InvalidShareCode { .. })
+        ));
+        assert!(matches!(
+            Board::from_share_code(&code[..code.len() - 2]),
+            Err(GamePlayError::InvalidShareCode { .. })
+        ));
+        assert!(matches!(
+            Board::from_share_code(&format!("{code}AAAA")),
+            Err(GamePlayError::InvalidShareCode { .. })
+        ));
+    }
+
+    #[test]
+    fn char_grid() {
+        let mut board = Board::from_string(
+            "~~ |0 ~~\n\
+             A0 __ ^^\n\
+             ⊭1 B1 #1\n\
+             ~~ |1 ~~",
         );
-        // Though if we could go straight there...
-        assert_eq!(dists.direct_distance(&Coordinate { x: 7, y: 7 }), Some(7));
-        // The one above it we could attack from the left, though
+        board.squares[3][2] = Square::fog();
+
         assert_eq!(
-            dists.attackable_distance(&Coordinate { x: 7, y: 6 }),
-            Some(6)
+            board.to_char_grid(),
+            vec![
+                vec![None, Some('|'), None],
+                vec![Some('A'), Some(' '), Some('^')],
+                vec![Some('#'), Some('B'), Some('#')],
+                vec![None, Some('|'), None],
+            ]
         );
-        // Which is also the best we could do anyway
-        assert_eq!(dists.direct_distance(&Coordinate { x: 7, y: 6 }), Some(6));
     }
 
     #[test]
-    fn flood_fill_towns() {
-        let board = Board::from_string(
-            r###"
-            ~~ ~~ |0 ~~ ~~
-            __ #0 R0 __ __
-            __ __ A0 __ #0
-            __ G1 __ __ __
-            B1 A1 #1 __ __
-            __ T1 N1 X1 __
-            ~~ ~~ |1 ~~ ~~
-            "###,
+    fn compact_codes() {
+        let mut defeated = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             #0 A0 ¤0 __\n\
+             __ __ B1 #1\n\
+             ~~ ~~ |1 ~~",
         );
+        defeated.squares[1][0] = Square::Town {
+            player: 0,
+            defeated: true,
+            foggy: false,
+        };
+        defeated.squares[2][0] = Square::fog();
+        defeated.wrap = true;
+
+        let boards = [
+            Board::new(9, 9),
+            Board::new(40, 40),
+            Board::from_string("|0 A0 B0 C0 __ |1"),
+            glyph_tiles(),
+            defeated,
+            Board::new_with_players(
+                5,
+                5,
+                vec![Coordinate::new(1, 0), Coordinate::new(5, 6)],
+                vec![Direction::NorthWest, Direction::SouthEast],
+            ),
+        ];
+        for board in boards {
+            let code = board.to_compact();
+            assert!(code
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+            assert_eq!(Board::from_compact(&code), Ok(board));
+        }
 
-        let zero_dists = board.flood_fill_from_towns(0);
-        let one_dists = board.flood_fill_from_towns(1);
-
-        let zd = |x: usize, y: usize| zero_dists.direct_distance(&Coordinate { x, y });
-        let od = |x: usize, y: usize| one_dists.direct_distance(&Coordinate { x, y });
-
-        assert_eq!(zd(0, 1), Some(0));
-        assert_eq!(zd(0, 2), Some(1));
-        assert_eq!(zd(4, 1), Some(0));
-        assert_eq!(zd(4, 5), Some(2));
-        assert_eq!(zd(3, 5), Some(3));
-        assert_eq!(zd(2, 5), Some(4));
-        assert_eq!(zd(1, 5), Some(3));
-
-        assert_eq!(od(0, 1), Some(4));
-        assert_eq!(od(2, 3), Some(0));
+        let board = Board::new(9, 9);
+        let code = board.to_compact();
+        assert!(code.len() * 2 < board.to_string().len());
+        assert!(code.len() * 2 < board.to_share_code().len());
+
+        // The same board under a version that doesn't exist yet
+        let mut future = URL_SAFE_NO_PAD.decode(&code).unwrap();
+        future[0] = COMPACT_VERSION + 1;
+        let future = URL_SAFE_NO_PAD.encode(future);
+
+        for broken in [&code[..code.len() - 2], &code[..6], "", &future] {
+            assert!(matches!(
+                Board::from_compact(broken),
+                Err(GamePlayError::InvalidShareCode { .. })
+            ));
+        }
     }
 
     #[test]
-    fn proximity_scores() {
-        let board = Board::from_string(
-            r###"
-            ~~ ~~ |0 ~~ ~~
-            __ #0 R0 __ __
-            __ __ A0 __ #0
-            __ G1 __ __ __
-            B1 A1 #1 __ __
-            __ T1 N1 X1 __
-            ~~ ~~ |1 ~~ ~~
-            "###,
+    fn text_round_trip() {
+        let mut varied = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             #0 A0 ^^ __\n\
+             __ __ B1 #1\n\
+             ~~ ~~ |1 ~~",
         );
-
-        let zero_prox = board.proximity_to_enemy_town(0);
-        let one_prox = board.proximity_to_enemy_town(1);
-
-        assert_eq!(zero_prox, vec![2, 1]);
-        assert_eq!(one_prox, vec![4, 3, 3, 3, 2, 1]);
+        varied.squares[1][0] = Square::Town {
+            player: 0,
+            defeated: true,
+            foggy: false,
+        };
+        varied.squares[2][0] = Square::fog();
+
+        for board in [
+            Board::new(9, 9),
+            Board::from_string("|0 A0 B0 __ |1"),
+            varied,
+        ] {
+            let parsed = Board::parse(board.to_string()).unwrap();
+            assert_eq!(parsed, board);
+            assert_eq!(parsed.artifacts(), board.artifacts());
+        }
     }
 
     #[test]
-    fn get_neighbours() {
-        // (0,0) (1,0) (2,0) (3,0) (4,0) (5,0)
-        // (0,1) (1,1) (2,1) (3,1) (4,1) (5,1)
-        // (0,2) (1,2) (2,2) (3,2) (4,2) (5,2)
-        // (0,3) (1,3) (2,3) (3,3) (4,3) (5,3)
-        // (0,4) (1,4) (2,4) (3,4) (4,4) (5,4)
-        // (0,5) (1,5) (2,5) (3,5) (4,5) (5,5)
-        let b = Board::new(4, 4);
-
+    fn text_parse_errors() {
+        assert_eq!(Board::parse("\n  \n"), Err(BoardParseError::Empty));
         assert_eq!(
-            // TODO: should we allow you to find neighbours of an invalid square?
-            b.neighbouring_squares(Coordinate { x: 0, y: 0 }),
-            [
-                (Coordinate { x: 1, y: 0 }, Square::water()),
-                (Coordinate { x: 0, y: 1 }, Square::water()),
-            ]
+            Board::parse("__ __ __\n__ __"),
+            Err(BoardParseError::JaggedRow {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
         );
-
         assert_eq!(
-            b.neighbouring_squares(Coordinate { x: 0, y: 4 }),
-            [
-                (Coordinate { x: 0, y: 3 }, Square::water()),
-                (Coordinate { x: 1, y: 4 }, Square::artifact(1)),
-                (Coordinate { x: 0, y: 5 }, Square::water()),
-            ]
+            Board::parse("__ A0B0 __"),
+            Err(BoardParseError::MalformedSquare {
+                row: 0,
+                column: 1,
+                token: "A0B0".into()
+            })
+        );
+        assert_eq!(
+            Board::parse("__  __"),
+            Err(BoardParseError::MalformedSquare {
+                row: 0,
+                column: 1,
+                token: "".into()
+            })
         );
+        assert_eq!(
+            Board::parse("|0 AB"),
+            Err(BoardParseError::MalformedSquare {
+                row: 0,
+                column: 1,
+                token: "AB".into()
+            })
+        );
+        assert_eq!(
+            Board::parse("|0 __\n__ |2"),
+            Err(BoardParseError::MissingOrientation {
+                row: 1,
+                column: 1,
+                player: 2,
+                orientations: 2
+            })
+        );
+    }
+
+    #[test]
+    fn legal_swaps() {
+        let b = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             __ A0 B0 __\n\
+             __ __ A0 __\n\
+             C0 __ __ __\n\
+             __ __ D1 __\n\
+             ~~ ~~ |1 ~~",
+        );
+        let penalty = SwapPenalty::Disallowed { allowed_swaps: 1 };
+        let c = Coordinate::new;
+
+        // Matching letters aren't worth swapping, and the stray C0 is in a group of its own
+        let contiguous = rules::Swapping::Contiguous(penalty.clone());
+        let pairs = b.legal_swaps(0, &contiguous);
+        assert_eq!(pairs, vec![[c(1, 1), c(2, 1)], [c(2, 1), c(2, 2)]]);
+        for pair in &pairs {
+            assert!(b.clone().swap(0, *pair, &contiguous, None).is_ok());
+        }
+        assert!(b
+            .clone()
+            .swap(0, [c(1, 1), c(0, 3)], &contiguous, None)
+            .is_err());
 
+        let universal = rules::Swapping::Universal(penalty);
         assert_eq!(
-            b.neighbouring_squares(Coordinate { x: 2, y: 2 }),
-            [
-                (Coordinate { x: 2, y: 1 }, Square::town(0)),
-                (Coordinate { x: 3, y: 2 }, Square::land()),
-                (Coordinate { x: 2, y: 3 }, Square::land()),
-                (Coordinate { x: 1, y: 2 }, Square::town(1)),
+            b.legal_swaps(0, &universal),
+            vec![
+                [c(1, 1), c(2, 1)],
+                [c(1, 1), c(0, 3)],
+                [c(2, 1), c(2, 2)],
+                [c(2, 1), c(0, 3)],
+                [c(2, 2), c(0, 3)],
             ]
         );
+
+        // A lone tile has nothing to swap with, and nothing can be swapped when swapping is off
+        assert!(b.legal_swaps(1, &universal).is_empty());
+        assert!(b.legal_swaps(0, &rules::Swapping::None).is_empty());
     }
 
     #[test]
@@ -2959,48 +7215,301 @@ pub mod tests {
             }
         }
 
-        // Gets two words in the middle of a cross
-        let b = Board::from_string(
-            "__ __ C0 __ __\n\
-             __ __ R0 __ __\n\
-             S0 W0 O0 R0 D0\n\
-             __ __ S0 __ __\n\
-             __ __ S0 __ __",
+        // Gets two words in the middle of a cross
+        let b = Board::from_string(
+            "__ __ C0 __ __\n\
+             __ __ R0 __ __\n\
+             S0 W0 O0 R0 D0\n\
+             __ __ S0 __ __\n\
+             __ __ S0 __ __",
+        );
+        let cross = ([4, 3, 2, 1, 0]).map(|y| Coordinate { x: 2, y }); // TODO: range
+        let sword = ([4, 3, 2, 1, 0]).map(|x| Coordinate { x, y: 2 }); // TODO: range
+        assert_eq!(b.get_words(Coordinate { x: 2, y: 2 }), vec![cross, sword]);
+
+        let just_cross = ([0, 1, 3, 4]).map(|y| Coordinate { x: 2, y });
+        for square in just_cross {
+            assert_eq!(b.get_words(square), vec![cross]);
+        }
+
+        let just_sword = ([0, 1, 3, 4]).map(|x| Coordinate { x, y: 2 });
+        for square in just_sword {
+            assert_eq!(b.get_words(square), vec![sword]);
+        }
+
+        // Don't cross other players
+        let b = Board::from_string(
+            "__ __ C0 __ __\n\
+             __ __ R0 __ __\n\
+             __ __ O1 __ __\n\
+             __ __ S0 __ __\n\
+             __ __ S0 __ __",
+        );
+        assert_eq!(
+            b.get(Coordinate { x: 2, y: 4 }),
+            Ok(Square::Occupied {
+                player: 0,
+                tile: 'S',
+                validity: SquareValidity::Unknown,
+                foggy: false
+            })
+        );
+        assert_eq!(
+            b.get_words(Coordinate { x: 2, y: 4 }),
+            vec![[Coordinate { x: 2, y: 4 }, Coordinate { x: 2, y: 3 }]]
+        );
+    }
+
+    #[test]
+    fn tiles_between() {
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ B0 I0 G0 __\n\
+             __ __ A0 __ __\n\
+             __ __ __ T1 __\n\
+             __ F1 A0 __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+
+        assert_eq!(
+            b.tiles_between(Coordinate::new(1, 1), Coordinate::new(3, 1)),
+            Some(vec![
+                Coordinate::new(1, 1),
+                Coordinate::new(2, 1),
+                Coordinate::new(3, 1)
+            ])
+        );
+        assert_eq!(
+            b.tiles_between(Coordinate::new(2, 2), Coordinate::new(2, 1)),
+            Some(vec![Coordinate::new(2, 2), Coordinate::new(2, 1)])
+        );
+
+        // Points that aren't in a line have nothing between them
+        assert_eq!(
+            b.tiles_between(Coordinate::new(1, 1), Coordinate::new(2, 4)),
+            None
+        );
+        // Or lines broken by empty squares or another player's tiles
+        assert_eq!(
+            b.tiles_between(Coordinate::new(2, 1), Coordinate::new(2, 4)),
+            None
+        );
+        assert_eq!(
+            b.tiles_between(Coordinate::new(1, 4), Coordinate::new(2, 4)),
+            None
+        );
+    }
+
+    #[test]
+    fn tiles_between_diagonals_and_wrapped_edges() {
+        let mut b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             G0 B0 I0 __ A0\n\
+             __ __ A0 __ __\n\
+             __ __ __ T0 __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let diagonal = Some(vec![
+            Coordinate::new(1, 1),
+            Coordinate::new(2, 2),
+            Coordinate::new(3, 3),
+        ]);
+
+        // Diagonal lines only count when words can run diagonally
+        assert_eq!(
+            b.tiles_between(Coordinate::new(1, 1), Coordinate::new(3, 3)),
+            None
+        );
+        b.diagonal_words = true;
+        assert_eq!(
+            b.tiles_between(Coordinate::new(1, 1), Coordinate::new(3, 3)),
+            diagonal
+        );
+
+        // Lines can cross the edge of a wrapping board, taking the shorter way around
+        assert_eq!(
+            b.tiles_between(Coordinate::new(4, 1), Coordinate::new(1, 1)),
+            None
+        );
+        b.wrap = true;
+        assert_eq!(
+            b.tiles_between(Coordinate::new(4, 1), Coordinate::new(1, 1)),
+            Some(vec![
+                Coordinate::new(4, 1),
+                Coordinate::new(0, 1),
+                Coordinate::new(1, 1)
+            ])
+        );
+        assert_eq!(
+            b.tiles_between(Coordinate::new(0, 1), Coordinate::new(2, 1)),
+            Some(vec![
+                Coordinate::new(0, 1),
+                Coordinate::new(1, 1),
+                Coordinate::new(2, 1)
+            ])
+        );
+    }
+
+    #[test]
+    fn normalize_player_order() {
+        let mut a = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             __ A0 #1 __\n\
+             __ B1 __ __\n\
+             ~~ ~~ |1 ~~",
+        );
+        let mut b = Board::from_string(
+            "~~ |1 ~~ ~~\n\
+             __ A1 #0 __\n\
+             __ B0 __ __\n\
+             ~~ ~~ |0 ~~",
+        );
+        b.orientations = vec![Direction::South, Direction::North];
+        assert_ne!(a, b);
+
+        a.normalize();
+        b.normalize();
+        assert_eq!(a, b);
+        assert_eq!(b.to_string(), a.to_string());
+        assert_eq!(
+            b.get_orientations(),
+            &vec![Direction::North, Direction::South]
+        );
+
+        // Normalizing is idempotent
+        let before = a.clone();
+        a.normalize();
+        assert_eq!(a, before);
+
+        // A player with two artifacts keeps a single index
+        let mut c = Board::from_string(
+            "~~ |1 ~~ |1 ~~\n\
+             __ A1 __ B0 __\n\
+             ~~ ~~ |0 ~~ ~~",
+        );
+        c.orientations = vec![Direction::South, Direction::North];
+        c.normalize();
+        assert_eq!(
+            c.to_string(),
+            "~~ |0 ~~ |0 ~~\n\
+             __ A0 __ B1 __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        assert_eq!(
+            c.get_orientations(),
+            &vec![Direction::North, Direction::South]
+        );
+    }
+
+    #[test]
+    fn lone_tile_words() {
+        let mut b = Board::from_string(
+            "~~ |0 ~~\n\
+             __ A0 __\n\
+             __ B1 __\n\
+             __ C1 __\n\
+             ~~ |1 ~~",
         );
-        let cross = ([4, 3, 2, 1, 0]).map(|y| Coordinate { x: 2, y }); // TODO: range
-        let sword = ([4, 3, 2, 1, 0]).map(|x| Coordinate { x, y: 2 }); // TODO: range
-        assert_eq!(b.get_words(Coordinate { x: 2, y: 2 }), vec![cross, sword]);
+        let lone = Coordinate::new(1, 1);
+        let rules = GameRules::generation(0);
 
-        let just_cross = ([0, 1, 3, 4]).map(|y| Coordinate { x: 2, y });
-        for square in just_cross {
-            assert_eq!(b.get_words(square), vec![cross]);
-        }
+        assert_eq!(b.get_words(lone), vec![vec![lone], vec![lone]]);
+        let (attackers, defenders) = b.collect_combanants(0, lone, &rules);
+        assert_eq!(attackers.len(), 2);
+        assert_eq!(defenders.len(), 1);
 
-        let just_sword = ([0, 1, 3, 4]).map(|x| Coordinate { x, y: 2 });
-        for square in just_sword {
-            assert_eq!(b.get_words(square), vec![sword]);
-        }
+        // Without lone tiles counting, the tile has no words to battle with
+        b.lone_tiles_are_words = false;
+        assert!(b.get_words(lone).is_empty());
+        let (attackers, _) = b.collect_combanants(0, lone, &rules);
+        assert!(attackers.is_empty());
 
-        // Don't cross other players
+        // Tiles with a neighbour still form words as usual
+        assert_eq!(
+            b.get_words(Coordinate::new(1, 2)),
+            vec![vec![Coordinate::new(1, 2), Coordinate::new(1, 3)]]
+        );
+    }
+
+    #[test]
+    fn get_words_wrapping() {
+        let mut b = Board::from_string(
+            "R1 T1 __ A1\n\
+             __ __ __ __",
+        );
+        let end = Coordinate::new(3, 0);
+
+        assert_eq!(
+            b.word_strings(&b.get_words(end)),
+            Ok(vec!["A".into(), "A".into()])
+        );
+        assert_eq!(
+            b.neighbouring_squares(Coordinate::new(0, 0))
+                .iter()
+                .map(|(c, _)| *c)
+                .collect::<Vec<_>>(),
+            vec![Coordinate::new(1, 0), Coordinate::new(0, 1)]
+        );
+
+        b.wrap = true;
+
+        assert_eq!(b.word_strings(&b.get_words(end)), Ok(vec!["ART".into()]));
+        assert_eq!(
+            b.neighbouring_squares(Coordinate::new(0, 0))
+                .iter()
+                .map(|(c, _)| *c)
+                .collect::<Vec<_>>(),
+            vec![
+                Coordinate::new(0, 1),
+                Coordinate::new(1, 0),
+                Coordinate::new(3, 0),
+            ]
+        );
+
+        // A line of tiles filling a whole row shouldn't repeat around the board
+        let mut b = Board::from_string("B1 I1 G1");
+        b.wrap = true;
+        assert_eq!(
+            b.word_strings(&b.get_words(Coordinate::new(0, 0))),
+            Ok(vec!["BIG".into()])
+        );
+    }
+
+    #[test]
+    fn preview_words() {
         let b = Board::from_string(
-            "__ __ C0 __ __\n\
-             __ __ R0 __ __\n\
-             __ __ O1 __ __\n\
-             __ __ S0 __ __\n\
-             __ __ S0 __ __",
+            "~~ |0 ~~ ~~\n\
+             __ __ __ __\n\
+             __ C1 __ __\n\
+             __ __ T1 O1\n\
+             __ __ __ __\n\
+             ~~ ~~ |1 ~~",
         );
+
+        // Filling the gap joins a vertical and a horizontal word at once
         assert_eq!(
-            b.get(Coordinate { x: 2, y: 4 }),
-            Ok(Square::Occupied {
-                player: 0,
-                tile: 'S',
-                validity: SquareValidity::Unknown,
-                foggy: false
-            })
+            b.preview_words(1, Coordinate::new(1, 3), 'A'),
+            Ok(vec![
+                (
+                    vec![Coordinate::new(1, 2), Coordinate::new(1, 3)],
+                    "CA".into()
+                ),
+                (
+                    vec![
+                        Coordinate::new(1, 3),
+                        Coordinate::new(2, 3),
+                        Coordinate::new(3, 3)
+                    ],
+                    "ATO".into()
+                ),
+            ])
         );
+        assert_eq!(b.get(Coordinate::new(1, 3)), Ok(Square::land()));
+
+        assert_eq!(b.preview_words(1, Coordinate::new(3, 1), 'A'), Ok(vec![]));
         assert_eq!(
-            b.get_words(Coordinate { x: 2, y: 4 }),
-            vec![[Coordinate { x: 2, y: 4 }, Coordinate { x: 2, y: 3 }]]
+            b.preview_words(1, Coordinate::new(1, 2), 'A'),
+            Err(GamePlayError::OccupiedPlace)
         );
     }
 
@@ -3029,6 +7538,248 @@ pub mod tests {
             words.sort();
             assert_eq!(words, vec!["GAN", "ZEN"]);
         }
+
+        // Words running over empty squares or off the board point at the first bad square
+        let words = vec![vec![
+            Coordinate::new(1, 1),
+            Coordinate::new(1, 4),
+            Coordinate::new(2, 2),
+        ]];
+        assert_eq!(
+            b.word_strings(&words),
+            Err(GamePlayError::EmptySquareInWord {
+                position: Coordinate::new(1, 4)
+            })
+        );
+        let words = vec![vec![Coordinate::new(1, 1)], vec![Coordinate::new(9, 1)]];
+        assert_eq!(
+            b.word_strings(&words),
+            Err(GamePlayError::OutSideBoardDimensions {
+                position: Coordinate::new(9, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn rotated_boards() {
+        let b = Board::from_string(
+            "~~ ~~ ~~ |0 ~~ ~~ ~~\n\
+             ~~ N0 U0 B0 #0 __ ~~\n\
+             ~~ E0 __ __ __ G1 ~~\n\
+             ~~ B0 __ __ __ A1 ~~\n\
+             ~~ __ #1 Z1 E1 N1 ~~\n\
+             ~~ ~~ ~~ |1 ~~ ~~ ~~",
+        );
+        let words = |b: &Board| {
+            let mut words = b.word_strings(&b.all_words()).unwrap();
+            words.sort();
+            words
+        };
+
+        let turned = b.rotated(1);
+        assert_eq!(
+            turned.to_string(),
+            "~~ ~~ ~~ ~~ ~~ ~~\n\
+             ~~ __ B0 E0 N0 ~~\n\
+             ~~ #1 __ __ U0 ~~\n\
+             |1 Z1 __ __ B0 |0\n\
+             ~~ E1 __ __ #0 ~~\n\
+             ~~ N1 A1 G1 __ ~~\n\
+             ~~ ~~ ~~ ~~ ~~ ~~",
+        );
+        assert_eq!(turned.orientations, vec![Direction::East, Direction::West]);
+        assert_eq!(
+            turned.artifacts(),
+            &[Coordinate::new(0, 3), Coordinate::new(5, 3)]
+        );
+
+        // Every player still reads the same words from their new seat
+        for quarter_turns in 0..4 {
+            assert_eq!(
+                words(&b.rotated(quarter_turns)),
+                vec!["BEN", "BUN", "GAN", "ZEN"]
+            );
+        }
+        assert_eq!(
+            turned
+                .word_strings(&turned.get_words(Coordinate::new(4, 1)))
+                .unwrap(),
+            vec!["BUN", "BEN"]
+        );
+
+        // Turning all the way round, or back again, gives the original board
+        assert_eq!(b.rotated(4), b);
+        assert_eq!(b.rotated(1).rotated(3), b);
+        assert_eq!(b.rotated(2).rotated(2), b);
+    }
+
+    #[test]
+    fn get_words_diagonals() {
+        let mut b = Board::from_string(
+            "~~ ~~ ~~ |0 ~~ ~~ ~~\n\
+             ~~ G0 __ __ __ __ ~~\n\
+             ~~ __ I0 __ __ __ ~~\n\
+             ~~ __ __ B0 __ __ ~~\n\
+             ~~ B1 __ __ __ __ ~~\n\
+             ~~ __ I1 __ __ __ ~~\n\
+             ~~ __ __ G1 __ __ ~~\n\
+             ~~ ~~ ~~ |1 ~~ ~~ ~~",
+        );
+        let words_at = |b: &Board, x: usize, y: usize, diagonals: bool| {
+            let position = Coordinate::new(x, y);
+            let words = if diagonals {
+                b.get_words_with_diagonals(position)
+            } else {
+                b.get_words(position)
+            };
+            b.word_strings(&words).unwrap()
+        };
+
+        // The south player reads down the diagonal, and the north player reads up it
+        assert_eq!(words_at(&b, 2, 5, true), vec!["BIG"]);
+        assert_eq!(words_at(&b, 2, 2, true), vec!["BIG"]);
+
+        // Without diagonals, the middle tiles are left on their own
+        assert_eq!(words_at(&b, 2, 5, false), vec!["I", "I"]);
+        // Once the board reads diagonals, get_words finds the diagonal word,
+        b.diagonal_words = true;
+        assert_eq!(words_at(&b, 2, 5, false), vec!["BIG"]);
+        // which the judge accepts like any other word
+        let judge = Judge::new(vec!["BIG".into()]);
+        let rules = GameRules::generation(0);
+        for word in words_at(&b, 2, 5, false) {
+            assert!(judge
+                .valid(word, &rules.win_condition, None, None, &mut None)
+                .is_some());
+        }
+
+        // Players on the east and west read diagonals like their horizontal words
+        let mut b = Board::from_string(
+            "~~ ~~ ~~ ~~ ~~\n\
+             ~~ __ __ G0 |0\n\
+             ~~ __ I0 __ ~~\n\
+             |1 B0 __ __ ~~\n\
+             ~~ ~~ ~~ ~~ ~~",
+        );
+        b.orientations = vec![Direction::East, Direction::West];
+        assert_eq!(words_at(&b, 2, 2, true), vec!["BIG"]);
+        b.orientations = vec![Direction::West, Direction::East];
+        assert_eq!(words_at(&b, 2, 2, true), vec!["GIB"]);
+    }
+
+    #[test]
+    fn four_player_corners() {
+        let mut b = Board::new_with_players(
+            5,
+            5,
+            vec![
+                Coordinate::new(1, 0),
+                Coordinate::new(5, 0),
+                Coordinate::new(1, 6),
+                Coordinate::new(5, 6),
+            ],
+            vec![
+                Direction::NorthWest,
+                Direction::NorthEast,
+                Direction::SouthWest,
+                Direction::SouthEast,
+            ],
+        );
+        assert_eq!(b.validate(), Ok(()));
+
+        // Each player grows a tree out of their corner, then plays a stray tile
+        let trees = [
+            (0, [(1, 1, 'A'), (2, 1, 'B'), (1, 2, 'C')], (3, 2)),
+            (1, [(5, 1, 'A'), (4, 1, 'B'), (5, 2, 'C')], (4, 3)),
+            (2, [(1, 5, 'A'), (2, 5, 'B'), (1, 4, 'C')], (2, 3)),
+            (3, [(5, 5, 'A'), (4, 5, 'B'), (5, 4, 'C')], (3, 4)),
+        ];
+        for (player, tree, stray) in trees {
+            for (x, y, tile) in tree {
+                b.set(Coordinate::new(x, y), player, tile, None).unwrap();
+            }
+            b.set(Coordinate::new(stray.0, stray.1), player, 'X', None)
+                .unwrap();
+        }
+        assert_eq!(
+            b.to_string(),
+            "~~ |0 ~~ ~~ ~~ |1 ~~\n\
+             ~~ A0 B0 __ B1 A1 ~~\n\
+             ~~ C0 __ X0 __ C1 ~~\n\
+             ~~ __ X2 __ X1 __ ~~\n\
+             ~~ C2 __ X3 __ C3 ~~\n\
+             ~~ A2 B2 __ B3 A3 ~~\n\
+             ~~ |2 ~~ ~~ ~~ |3 ~~",
+        );
+
+        // Everyone reads their words towards their own corner
+        for (_, tree, _) in trees {
+            let (x, y, _) = tree[0];
+            let mut words = b.word_strings(&b.get_words(Coordinate::new(x, y))).unwrap();
+            words.sort();
+            assert_eq!(words, vec!["BA", "CA"]);
+        }
+
+        let mut bag = TileBag::explicit(vec![], Some(1));
+        let changes = b.truncate(&mut bag, None, &HashSet::new());
+        assert_eq!(changes.len(), 4);
+        assert_eq!(
+            b.to_string(),
+            "~~ |0 ~~ ~~ ~~ |1 ~~\n\
+             ~~ A0 B0 __ B1 A1 ~~\n\
+             ~~ C0 __ __ __ C1 ~~\n\
+             ~~ __ __ __ __ __ ~~\n\
+             ~~ C2 __ __ __ C3 ~~\n\
+             ~~ A2 B2 __ B3 A3 ~~\n\
+             ~~ |2 ~~ ~~ ~~ |3 ~~",
+        );
+    }
+
+    #[test]
+    fn all_orientations() {
+        let b = Board::from_string(
+            "~~ |0 ~~ ~~ ~~\n\
+             ~~ N0 __ N1 ~~\n\
+             ~~ A0 __ A1 ~~\n\
+             ~~ G0 __ G1 ~~\n\
+             ~~ ~~ ~~ |1 ~~",
+        );
+        let words = |b: &Board, x: usize| {
+            b.word_strings(&b.get_words(Coordinate { x, y: 2 }))
+                .unwrap()
+        };
+
+        // By default the players sit on opposite sides of the board
+        assert_eq!(words(&b, 1), vec!["GAN"]);
+        assert_eq!(words(&b, 3), vec!["NAG"]);
+
+        let b = b.with_all_orientations(Direction::North);
+        assert_eq!(b.get_orientations(), &vec![Direction::North; 2]);
+        assert_eq!(words(&b, 1), vec!["GAN"]);
+        assert_eq!(words(&b, 3), vec!["GAN"]);
+    }
+
+    #[test]
+    fn words_spanning() {
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             S0 P0 A0 N0 S0\n\
+             __ __ T0 __ O0\n\
+             __ __ __ __ N0\n\
+             A1 T1 __ __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+
+        let strings = |words: Vec<Vec<Coordinate>>| {
+            let mut strings = b.word_strings(&words).unwrap();
+            strings.sort();
+            strings
+        };
+
+        assert_eq!(strings(b.words_spanning(5)), vec!["SNAPS"]);
+        assert_eq!(strings(b.words_spanning(3)), vec!["NOS", "SNAPS"]);
+        assert_eq!(strings(b.all_words()), vec!["AT", "NOS", "SNAPS", "TA"]);
+        assert!(b.words_spanning(6).is_empty());
     }
 
     #[test]
@@ -3054,6 +7805,265 @@ pub mod tests {
              A0 __ B1 B1 __\n\
              ~~ ~~ B1 ~~ ~~",
         );
+
+        let masked = board.fog_of_war(1, &rules::Visibility::LetterFog, &HashSet::new());
+        assert_eq!(
+            masked.to_string(),
+            "~~ ~~ *0 ~~ ~~\n\
+             A0 *0 *0 A0 *0\n\
+             A0 __ __ A0 __\n\
+             A0 __ __ __ __\n\
+             A0 A0 __ B1 __\n\
+             A0 __ B1 B1 __\n\
+             ~~ ~~ B1 ~~ ~~",
+        );
+        let tile_count = |b: &Board, player: usize| {
+            b.squares
+                .iter()
+                .flatten()
+                .filter(|sq| matches!(sq, Square::Occupied { player: p, .. } if *p == player))
+                .count()
+        };
+        assert_eq!(tile_count(&masked, 0), tile_count(&board, 0));
+        assert_eq!(tile_count(&masked, 1), tile_count(&board, 1));
+    }
+
+    #[test]
+    fn visible_enemy_tiles() {
+        let board = Board::from_string(
+            "~~ ~~ A0 ~~ ~~\n\
+             A0 A0 A0 A0 A0\n\
+             A0 __ __ A0 __\n\
+             A0 __ __ __ __\n\
+             A0 A0 __ B1 __\n\
+             A0 __ B1 B1 __\n\
+             ~~ ~~ B1 ~~ ~~",
+        );
+
+        let revealed = |player: usize, visibility: &rules::Visibility| {
+            board
+                .fog_of_war(player, visibility, &HashSet::new())
+                .squares
+                .iter()
+                .flatten()
+                .filter(|sq| matches!(sq, Square::Occupied { player: p, .. } if *p != player))
+                .count()
+        };
+
+        assert_eq!(board.visible_enemy_tiles(1), 8);
+        assert_eq!(
+            board.visible_enemy_tiles(1),
+            revealed(1, &rules::Visibility::TileFog)
+        );
+        assert_eq!(
+            board.visible_enemy_tiles(1),
+            revealed(1, &rules::Visibility::LandFog)
+        );
+        assert_eq!(
+            board.visible_enemy_tiles(0),
+            revealed(0, &rules::Visibility::LandFog)
+        );
+    }
+
+    #[test]
+    fn client_payload() {
+        let board = Board::from_string(
+            "~~ |0 ~~\n\
+             __ A0 __\n\
+             __ B0 __\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ C1 __\n\
+             ~~ |1 ~~",
+        );
+
+        let payload = board.client_payload(1, &rules::Visibility::TileFog, &None, &HashSet::new());
+        assert_eq!(
+            payload.board.to_string(),
+            "~~ |0 ~~\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ __ __\n\
+             __ C1 __\n\
+             ~~ |1 ~~",
+        );
+        // Player 1 faces south, so sees the board turned around from their seat
+        assert_eq!(payload.view, ViewTransform::Rotate180);
+        assert_eq!(payload.root, Some(Coordinate::new(1, 11)));
+
+        let payload = board.client_payload(0, &rules::Visibility::TileFog, &None, &HashSet::new());
+        assert_eq!(payload.view, ViewTransform::Upright);
+        assert_eq!(payload.root, Some(Coordinate::new(1, 0)));
+        assert!(!payload.board.to_string().contains("C1"));
+
+        // Once the game is over everything is revealed
+        let payload =
+            board.client_payload(1, &rules::Visibility::TileFog, &Some(0), &HashSet::new());
+        assert_eq!(payload.board, board);
+        assert_eq!(payload.root, Some(Coordinate::new(1, 11)));
+    }
+
+    #[test]
+    fn changes_in_player_view() {
+        let board = Board::from_string(
+            "~~ ~~ |0 ~~ ~~ ~~\n\
+             __ __ A0 __ __ __\n\
+             __ __ __ __ __ __\n\
+             __ __ __ __ __ __\n\
+             ~~ ~~ ~~ |1 ~~ ~~",
+        );
+        let changes = vec![Change::Board(BoardChange {
+            detail: BoardChangeDetail {
+                square: board.get(Coordinate::new(2, 1)).unwrap(),
+                coordinate: Coordinate::new(2, 1),
+            },
+            action: BoardChangeAction::Added,
+        })];
+        let coordinate = |changes: Vec<Change>| match &changes[0] {
+            Change::Board(change) => change.detail.coordinate,
+            _ => unreachable!(),
+        };
+
+        // Player 0 faces north and sees the board as it is
+        assert_eq!(
+            coordinate(board.reflect_move_for_opponent_view(0, &changes)),
+            Coordinate::new(2, 1)
+        );
+        // Player 1 sits across the table and sees the board turned around
+        let seen = coordinate(board.reflect_move_for_opponent_view(1, &changes));
+        assert_eq!(seen, Coordinate::new(3, 3));
+        assert_eq!(board.rotated(2).get(seen), board.get(Coordinate::new(2, 1)));
+        // Moves they send back land where they meant them to
+        assert_eq!(
+            board.coordinate_from_player_view(1, seen),
+            Coordinate::new(2, 1)
+        );
+        assert_eq!(
+            board.coordinate_from_player_view(0, Coordinate::new(2, 1)),
+            Coordinate::new(2, 1)
+        );
+
+        for view in [ViewTransform::Rotate90, ViewTransform::Rotate270] {
+            let seen = view.apply(Coordinate::new(2, 1), board.width(), board.height());
+            assert_eq!(
+                board.rotated(view.quarter_turns()).get(seen),
+                board.get(Coordinate::new(2, 1))
+            );
+            assert_eq!(
+                view.undo(seen, board.width(), board.height()),
+                Coordinate::new(2, 1)
+            );
+        }
+    }
+
+    #[test]
+    fn glyph_transforms() {
+        // Letters are turned so that their top faces the given direction
+        assert_eq!(Direction::North.glyph_transform(), ViewTransform::Upright);
+        assert_eq!(Direction::East.glyph_transform(), ViewTransform::Rotate90);
+        assert_eq!(Direction::South.glyph_transform(), ViewTransform::Rotate180);
+        assert_eq!(Direction::West.glyph_transform(), ViewTransform::Rotate270);
+
+        // Diagonals have no quarter turn of their own, so they take the nearest of north or south
+        assert_eq!(
+            Direction::NorthEast.glyph_transform(),
+            ViewTransform::Upright
+        );
+        assert_eq!(
+            Direction::NorthWest.glyph_transform(),
+            ViewTransform::Upright
+        );
+        assert_eq!(
+            Direction::SouthEast.glyph_transform(),
+            ViewTransform::Rotate180
+        );
+        assert_eq!(
+            Direction::SouthWest.glyph_transform(),
+            ViewTransform::Rotate180
+        );
+
+        // A player's own letters face the way they do, so turning the board to their seat stands them upright
+        for facing in [
+            Direction::North,
+            Direction::East,
+            Direction::South,
+            Direction::West,
+        ] {
+            let turns = |t: ViewTransform| match t {
+                ViewTransform::Upright => 0,
+                ViewTransform::Rotate90 => 1,
+                ViewTransform::Rotate180 => 2,
+                ViewTransform::Rotate270 => 3,
+            };
+            assert_eq!(
+                (turns(facing.glyph_transform()) + turns(facing.view_transform())) % 4,
+                0,
+                "{facing:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn fog_of_war_radius() {
+        let board = Board::from_string(
+            "~~ ~~ A0 ~~ ~~ ~~ ~~\n\
+             A0 A0 A0 A0 A0 __ __\n\
+             A0 __ __ A0 __ __ __\n\
+             A0 __ __ __ __ __ C0\n\
+             A0 A0 __ D0 __ __ C0\n\
+             A0 __ __ __ __ B1 __\n\
+             ~~ ~~ ~~ ~~ ~~ B1 ~~",
+        );
+
+        let visible_enemies = |radius| {
+            let foggy = board.fog_of_war_with_radius(
+                1,
+                &rules::Visibility::TileFog,
+                &HashSet::new(),
+                radius,
+            );
+            (0..foggy.height())
+                .flat_map(|y| (0..foggy.width()).map(move |x| Coordinate { x, y }))
+                .filter(|c| matches!(foggy.get(*c), Ok(Square::Occupied { player: 0, .. })))
+                .collect::<HashSet<_>>()
+        };
+
+        let (near, standard, far) = (visible_enemies(1), visible_enemies(2), visible_enemies(3));
+        assert!(near.is_empty());
+        assert_eq!(
+            standard,
+            HashSet::from([Coordinate::new(6, 3), Coordinate::new(6, 4)])
+        );
+        assert!(far.is_superset(&standard));
+        assert!(far.contains(&Coordinate::new(3, 4)));
+
+        assert_eq!(
+            board.fog_of_war(1, &rules::Visibility::TileFog, &HashSet::new()),
+            board.fog_of_war_with_radius(1, &rules::Visibility::TileFog, &HashSet::new(), 2)
+        );
+        assert_eq!(board.visible_enemy_tiles(1), standard.len());
+
+        // The rules can widen everyone's vision
+        let mut rules = GameRules::generation(0);
+        rules.vision_radius = 3;
+        let mut board = board.clone();
+        board.adopt_rules(&rules);
+        assert_eq!(
+            board.fog_of_war(1, &rules::Visibility::TileFog, &HashSet::new()),
+            board.fog_of_war_with_radius(1, &rules::Visibility::TileFog, &HashSet::new(), 3)
+        );
+        assert_eq!(board.visible_enemy_tiles(1), far.len());
     }
 
     #[test]
@@ -8,8 +8,26 @@ pub enum GamePlayError {
     #[error("Coordinate is not within board dimensions ({:?}, {:?})", position.x, position.y)]
     // TODO: should this be combined with InvalidPosition? How would we distinguish between dead squares and out of bounds? Should we?
     OutSideBoardDimensions { position: Coordinate },
-    #[error("Empty square found in a word, where the word should be an unbroken line of non empty tiles")]
-    EmptySquareInWord,
+    #[error(transparent)]
+    InvalidBoard(#[from] BoardError),
+    #[error("A board with {width}x{height} land is too small to hold both artifacts")]
+    BoardTooSmall { width: usize, height: usize },
+    #[error("Merged boards both fill the square at ({:?}, {:?})", position.x, position.y)]
+    MergeConflict { position: Coordinate },
+    #[error("Share code is invalid: {reason}")]
+    InvalidShareCode { reason: String },
+    #[error("Tile distributions can only hold the letters A to Z, not {letter:?}")]
+    UnknownTileLetter { letter: char },
+    #[error("Tile distribution has no tiles in it")]
+    EmptyTileDistribution,
+    #[error("{action} changes can't be reverted on their own")]
+    IrreversibleChange { action: String },
+    #[error("There is nothing to undo")]
+    NothingToUndo,
+    #[error("There is nothing to redo")]
+    NothingToRedo,
+    #[error("Empty square found in a word at ({:?}, {:?}), where the word should be an unbroken line of non empty tiles", position.x, position.y)]
+    EmptySquareInWord { position: Coordinate },
 
     #[error("Player {index:?} does not exist")]
     NonExistentPlayer { index: usize },
@@ -29,13 +47,81 @@ pub enum GamePlayError {
     #[error("You can't swap {count} in a row")]
     TooManySwaps { count: String },
 
+    #[error("Exchanging tiles is disabled")]
+    NoExchanging,
+    #[error("You must exchange at least one tile")]
+    EmptyExchange,
+    #[error("There is no tile at position {index} in your hand, or it was picked twice")]
+    InvalidHandIndex { index: usize },
+
     #[error("You can't place a tile on top of another")]
     OccupiedPlace,
+    #[error("{word} is not a valid word")]
+    InvalidWord { word: String },
+    #[error(
+        "That square was just vacated, and can't be played on for another {turns_left} turn(s)"
+    )]
+    SquareCoolingDown { turns_left: u32 },
     #[error("You can only place tiles touching your artifact or your existing tiles")]
     NonAdjacentPlace,
     #[error("You are attempting to place a tile next to your opponent's artifact")]
     OpponentStartPlace,
+    #[error("You must place at least one tile")]
+    EmptyPlace,
+    #[error("You can place at most {allowed} tiles in a turn, not {placed}")]
+    TooManyPlacements { placed: usize, allowed: usize },
+    #[error("You can't place two tiles on the same square")]
+    DuplicatePlace,
+    #[error("Tiles placed together must connect to each other and to your existing tiles")]
+    DisconnectedPlace,
+
+    #[error("You can't play for another {remaining} seconds")]
+    TooSoon { remaining: u64 },
+
+    #[error("Only finished games can count towards a match")]
+    UnfinishedGame,
+    #[error("The match has already been won")]
+    MatchOver,
 
     #[error("Player {player:?} doesn't have a '{tile:?}' tile")]
     PlayerDoesNotHaveTile { player: usize, tile: char },
+    #[error("There is no '{tile:?}' tile in the bag")]
+    TileNotInBag { tile: char },
+}
+
+#[derive(Clone, Error, Debug, PartialEq)]
+pub enum BoardError {
+    #[error("Board rows must all be the same, non-zero width")]
+    Malformed,
+    #[error("Board needs an artifact for at least two players, but has {found}")]
+    MissingArtifacts { found: usize },
+    #[error("Artifact at ({:?}, {:?}) doesn't touch any land", position.x, position.y)]
+    StrandedArtifact { position: Coordinate },
+    #[error("Land at ({:?}, {:?}) is cut off from the rest of the board", position.x, position.y)]
+    DisconnectedLand { position: Coordinate },
+}
+
+#[derive(Clone, Error, Debug, PartialEq)]
+pub enum BoardParseError {
+    #[error("Board text has no rows")]
+    Empty,
+    #[error("Row {row} has {found} squares, but the first row has {expected}")]
+    JaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("Square {column} of row {row} should be two characters followed by a single space, found {token:?}")]
+    MalformedSquare {
+        row: usize,
+        column: usize,
+        token: String,
+    },
+    #[error("Square {column} of row {row} belongs to player {player}, but the board only orients {orientations} players")]
+    MissingOrientation {
+        row: usize,
+        column: usize,
+        player: usize,
+        orientations: usize,
+    },
 }
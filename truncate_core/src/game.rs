@@ -1,17 +1,19 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ops::Sub;
 
 use time::Duration;
 use xxhash_rust::xxh3;
 
 use crate::bag::TileBag;
-use crate::board::{Coordinate, Square};
+use crate::board::{Coordinate, EliminationMode, Square};
 use crate::error::GamePlayError;
 use crate::judge::{Outcome, WordDict};
-use crate::reporting::{self, BoardChange, BoardChangeAction, BoardChangeDetail, TimeChange};
+use crate::reporting::{
+    self, BoardChange, BoardChangeAction, BoardChangeDetail, HandChange, TimeChange,
+};
 use crate::rules::{self, GameRules, OvertimeRule};
 
-use super::board::Board;
+use super::board::{Board, ClientBoard};
 use super::judge::Judge;
 use super::moves::Move;
 use super::player::Player;
@@ -31,6 +33,51 @@ pub const GAME_COLORS: [(u8, u8, u8); 5] = [
     GAME_COLOR_YELLOW,
 ];
 
+/// Upper bound on the number of word judgements a game will remember
+/// before its cache is cleared and rebuilt from scratch.
+pub const MAX_CACHED_WORD_JUDGEMENTS: usize = 1024;
+
+/// The dictionary that a game's cached word judgements were made against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JudgedAgainst {
+    /// The game's own judge, by its id
+    Judge(u64),
+    /// A dictionary supplied by the caller, by its address
+    External(usize),
+}
+
+/// Validity of words seen in previous battles, remembered for as long as
+/// battles keep being judged against the same dictionary
+#[derive(Debug, Clone, Default)]
+pub struct WordJudgements {
+    judged_against: Option<JudgedAgainst>,
+    judgements: HashMap<String, bool, xxh3::Xxh3Builder>,
+}
+
+impl WordJudgements {
+    pub fn get(&self, word: &str) -> Option<bool> {
+        self.judgements.get(word).copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.judgements.is_empty()
+    }
+
+    /// Judgements to use with the given dictionary, forgetting any made against another
+    fn against(
+        &mut self,
+        dictionary: JudgedAgainst,
+    ) -> &mut HashMap<String, bool, xxh3::Xxh3Builder> {
+        if self.judged_against != Some(dictionary)
+            || self.judgements.len() >= MAX_CACHED_WORD_JUDGEMENTS
+        {
+            self.judgements.clear();
+            self.judged_against = Some(dictionary);
+        }
+        &mut self.judgements
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Game {
     pub rules: GameRules,
@@ -47,6 +94,18 @@ pub struct Game {
     pub next_player: Option<usize>,
     pub paused: bool,
     pub winner: Option<usize>,
+    /// How the game was won, once it has been
+    pub game_end: Option<reporting::GameEnd>,
+    /// Validity of words seen in previous battles, used when the caller doesn't supply their own cache
+    pub word_judgements: WordJudgements,
+    /// Seconds that each player spent on each of their turns, in the order the turns were played
+    pub move_durations: Vec<Vec<Duration>>,
+    /// Turns in a row, across all players, on which no tiles were placed
+    pub consecutive_swaps: usize,
+    /// Vacated squares that can't be placed on until the given turn, under `GameRules::square_cooldown`
+    pub cooling_squares: HashMap<Coordinate, u32>,
+    /// Source of the current time in seconds, swapped out to control time in tests
+    pub clock: fn() -> u64,
 }
 
 // TODO: Move this to a helper file somewhere
@@ -70,7 +129,13 @@ impl Game {
         Self {
             players: Vec::with_capacity(2),
             board,
-            bag: TileBag::generation(rules.tile_generation, tile_seed),
+            bag: TileBag::from_distribution(
+                &rules.tile_distribution,
+                rules.tile_generation,
+                tile_seed,
+            )
+            .expect("Rule sets should have a usable tile distribution")
+            .with_vowel_guarantee(rules.vowel_guarantee.clone()),
             judge: Judge::default(),
             battle_count: 0,
             turn_count: 0,
@@ -81,6 +146,12 @@ impl Game {
             next_player,
             paused: false,
             winner: None,
+            game_end: None,
+            word_judgements: WordJudgements::default(),
+            move_durations: Vec::with_capacity(2),
+            consecutive_swaps: 0,
+            cooling_squares: HashMap::new(),
+            clock: now,
             rules,
         }
     }
@@ -102,7 +173,13 @@ impl Game {
         Self {
             players: Vec::with_capacity(2),
             board,
-            bag: TileBag::generation(rules.tile_generation, tile_seed),
+            bag: TileBag::from_distribution(
+                &rules.tile_distribution,
+                rules.tile_generation,
+                tile_seed,
+            )
+            .expect("Rule sets should have a usable tile distribution")
+            .with_vowel_guarantee(rules.vowel_guarantee.clone()),
             judge: Judge::default(),
             battle_count: 0,
             turn_count: 0,
@@ -113,29 +190,45 @@ impl Game {
             next_player,
             paused: false,
             winner: None,
+            game_end: None,
+            word_judgements: WordJudgements::default(),
+            move_durations: Vec::with_capacity(2),
+            consecutive_swaps: 0,
+            cooling_squares: HashMap::new(),
+            clock: now,
             rules,
         }
     }
 
     pub fn add_player(&mut self, name: String) {
+        let handicap = self
+            .rules
+            .handicaps
+            .get(self.players.len())
+            .cloned()
+            .unwrap_or_default();
         let time_allowance = match self.rules.timing {
             rules::Timing::PerPlayer {
                 time_allowance,
                 overtime_rule: _,
             } => Some(Duration::new(time_allowance as i64, 0)),
+            rules::Timing::PerTurn { time_allowance } => {
+                Some(Duration::new(time_allowance as i64, 0))
+            }
             rules::Timing::None => None,
             rules::Timing::Periodic { .. } => None,
-            _ => unimplemented!(),
-        };
+        }
+        .map(|allowance| allowance + Duration::new(handicap.extra_time as i64, 0));
         self.players.push(Player::new(
             name,
             self.players.len(),
-            self.rules.hand_size,
+            self.rules.hand_size + handicap.extra_hand_tiles,
             &mut self.bag,
             time_allowance,
             GAME_COLORS[self.players.len()],
         ));
         self.player_turn_count.push(0);
+        self.move_durations.push(vec![]);
     }
 
     pub fn get_player(&self, player: usize) -> Option<&Player> {
@@ -144,11 +237,15 @@ impl Game {
     }
 
     pub fn start(&mut self) {
-        let now = now();
+        let now = (self.clock)();
         self.started_at = Some(now);
+        self.board.adopt_rules(&self.rules);
+        self.place_seed_tiles();
 
         match self.rules.timing {
-            rules::Timing::PerPlayer { .. } | rules::Timing::None => {
+            rules::Timing::PerPlayer { .. }
+            | rules::Timing::PerTurn { .. }
+            | rules::Timing::None => {
                 self.players[self.next_player.unwrap()].turn_starts_no_later_than = Some(now);
                 self.players[self.next_player.unwrap()].turn_starts_no_sooner_than = Some(now);
             }
@@ -161,7 +258,55 @@ impl Game {
 
                 self.game_ends_at = Some(now + total_time_allowance as u64);
             }),
-            _ => unimplemented!(),
+        }
+    }
+
+    /// Grows each player's root by the number of seed tiles in the rules,
+    /// drawing them from the bag and placing each as close to the root as possible.
+    /// Squares touching another player's pieces are skipped so that no battles are set up.
+    fn place_seed_tiles(&mut self) {
+        for player in 0..self.players.len() {
+            let Some(root) = self.board.artifacts().iter().copied().find(|artifact| {
+                matches!(
+                    self.board.get(*artifact),
+                    Ok(Square::Artifact { player: p, .. }) if p == player
+                )
+            }) else {
+                continue;
+            };
+
+            let extra_seed_tiles = self
+                .rules
+                .handicaps
+                .get(player)
+                .map_or(0, |handicap| handicap.extra_seed_tiles);
+            for _ in 0..self.rules.seed_tiles + extra_seed_tiles {
+                let position = self
+                    .board
+                    .playable_positions(player, &rules::Truncation::Root)
+                    .into_iter()
+                    .filter(|position| {
+                        self.board
+                            .neighbouring_squares(*position)
+                            .iter()
+                            .all(|(_, square)| match square {
+                                Square::Occupied { player: p, .. }
+                                | Square::Artifact { player: p, .. }
+                                | Square::Town { player: p, .. } => *p == player,
+                                _ => true,
+                            })
+                    })
+                    .min_by_key(|position| (position.distance_to(&root), position.y, position.x));
+
+                let Some(position) = position else {
+                    break;
+                };
+
+                let tile = self.bag.draw_tile();
+                self.board
+                    .set(position, player, tile, None)
+                    .expect("Seed tiles should only be placed on land");
+            }
         }
     }
 
@@ -173,7 +318,7 @@ impl Game {
                 continue;
             };
             if let Some(turn_starts) = player.turn_starts_no_later_than {
-                let elapsed_time = now().saturating_sub(turn_starts);
+                let elapsed_time = (self.clock)().saturating_sub(turn_starts);
                 time_remaining -= Duration::seconds(elapsed_time as i64);
             }
 
@@ -191,6 +336,11 @@ impl Game {
         most_overtime_player.map(|(_, player_number)| player_number)
     }
 
+    /// Whether the game has finished, either with a winner or as a draw
+    pub fn is_over(&self) -> bool {
+        self.winner.is_some() || self.game_end.is_some()
+    }
+
     pub fn game_is_overtime(&self) -> bool {
         let Some(started_at) = self.started_at else {
             return false;
@@ -201,7 +351,7 @@ impl Game {
                 total_time_allowance,
                 ..
             } => {
-                let elapsed = now() - started_at;
+                let elapsed = (self.clock)() - started_at;
                 if elapsed as usize > *total_time_allowance {
                     return true;
                 }
@@ -218,6 +368,37 @@ impl Game {
         false
     }
 
+    /// Whether every player has swapped rather than placed on their last `max_consecutive_swaps` turns
+    pub fn is_stalemate(&self) -> bool {
+        self.rules
+            .max_consecutive_swaps
+            .is_some_and(|max| self.consecutive_swaps >= max * self.players.len())
+    }
+
+    /// Narrows down players who are level on the win metric using the rule's tie breakers, in order
+    fn break_tie(&self, mut contenders: Vec<usize>) -> Option<usize> {
+        for tie_breaker in &self.rules.tie_breakers {
+            let score = |player: usize| match tie_breaker {
+                rules::TieBreaker::Territory => self.board.enclosed_by(player).len(),
+                rules::TieBreaker::TileCount => self
+                    .board
+                    .squares
+                    .iter()
+                    .flatten()
+                    .filter(|sq| matches!(sq, Square::Occupied { player: p, .. } if *p == player))
+                    .count(),
+            };
+
+            let best = contenders.iter().map(|p| score(*p)).max()?;
+            contenders.retain(|p| score(*p) == best);
+            if contenders.len() == 1 {
+                return contenders.pop();
+            }
+        }
+
+        None
+    }
+
     pub fn calculate_game_over(&mut self, current_player: Option<usize>) {
         let overtime_rule = match &self.rules.timing {
             rules::Timing::PerPlayer { overtime_rule, .. } => Some(overtime_rule),
@@ -228,6 +409,15 @@ impl Game {
                 Some(overtime_player) => {
                     if self.winner.is_none() {
                         println!("{overtime_player} is over time! Defeating player.");
+                        if self.rules.reclaim_eliminated {
+                            let (changes, _) = self.board.reclaim_player(
+                                overtime_player,
+                                EliminationMode::ReturnTiles,
+                                &mut self.bag,
+                                false,
+                            );
+                            self.recent_changes.extend(changes);
+                        }
                     }
                     self.board.defeat_player(overtime_player);
                     self.winner = Some((overtime_player + 1) % 2);
@@ -236,6 +426,14 @@ impl Game {
             }
         }
 
+        // Nobody is trying to win any more, so the game ends level rather than on the win metric
+        if self.winner.is_none() && self.is_stalemate() {
+            self.game_end = Some(reporting::GameEnd::Stalemate {
+                turns: self.consecutive_swaps,
+            });
+            return;
+        }
+
         if self.game_is_overtime() {
             match &self.rules.win_metric {
                 rules::WinMetric::TownProximity | rules::WinMetric::ObeliskProximity => {
@@ -281,9 +479,14 @@ impl Game {
                     }
 
                     let winner = if remaining_players.len() == 1 {
-                        remaining_players.pop().unwrap()
+                        remaining_players.pop()
                     } else {
-                        0 // TODO: We need a draw mechanism
+                        self.break_tie(remaining_players)
+                    };
+                    let Some(winner) = winner else {
+                        println!("Nobody wins on proximity, the game is a draw!");
+                        self.game_end = Some(reporting::GameEnd::Draw);
+                        return;
                     };
 
                     println!("{winner} wins on proximity!");
@@ -329,7 +532,7 @@ impl Game {
                 .or(player.turn_starts_no_sooner_than);
 
             if let Some(current_player_turn) = current_player_turn {
-                let turn_delta = current_player_turn as i64 - (now() as i64);
+                let turn_delta = current_player_turn as i64 - ((self.clock)() as i64);
 
                 player.paused_turn_delta = Some(turn_delta);
             }
@@ -343,15 +546,15 @@ impl Game {
         self.paused = false;
 
         match self.rules.timing {
-            rules::Timing::PerPlayer { .. } => {
+            rules::Timing::PerPlayer { .. } | rules::Timing::PerTurn { .. } => {
                 if let Some(next_player_index) = self.next_player {
                     let next_player = &mut self.players[next_player_index];
                     let paused_turn_delta = next_player.paused_turn_delta.unwrap_or_default();
 
                     next_player.turn_starts_no_later_than =
-                        Some(now().saturating_add_signed(paused_turn_delta));
+                        Some((self.clock)().saturating_add_signed(paused_turn_delta));
                     next_player.turn_starts_no_sooner_than =
-                        Some(now().saturating_add_signed(paused_turn_delta));
+                        Some((self.clock)().saturating_add_signed(paused_turn_delta));
 
                     next_player.paused_turn_delta = None;
                 }
@@ -361,18 +564,121 @@ impl Game {
                     let paused_turn_delta = player.paused_turn_delta.unwrap_or_default();
 
                     player.turn_starts_no_later_than =
-                        Some(now().saturating_add_signed(paused_turn_delta));
+                        Some((self.clock)().saturating_add_signed(paused_turn_delta));
                     player.turn_starts_no_sooner_than =
-                        Some(now().saturating_add_signed(paused_turn_delta));
+                        Some((self.clock)().saturating_add_signed(paused_turn_delta));
 
                     player.paused_turn_delta = None;
                 }
             }
-            rules::Timing::PerTurn { time_allowance } => unimplemented!(),
             rules::Timing::None => { /* no-op */ }
         }
     }
 
+    /// The time by which the current player must finish their turn under `Timing::PerTurn`
+    pub fn turn_deadline(&self) -> Option<u64> {
+        let rules::Timing::PerTurn { time_allowance } = self.rules.timing else {
+            return None;
+        };
+        if self.is_over() || self.paused {
+            return None;
+        }
+
+        let player = self.next_player?;
+        let turn_starts = self.players.get(player)?.turn_starts_no_later_than?;
+        Some(turn_starts + (time_allowance + self.extra_time(player)) as u64)
+    }
+
+    /// Seconds added to a player's time allowance by their handicap
+    fn extra_time(&self, player: usize) -> usize {
+        self.rules
+            .handicaps
+            .get(player)
+            .map_or(0, |handicap| handicap.extra_time)
+    }
+
+    /// Applies any timing rules that don't wait on a player's move, returning what changed.
+    /// Under `Timing::PerTurn`, a player who runs past their allowance has their turn passed on
+    /// to the next player, which is recorded as a `Change::Time`.
+    /// Should be called by whoever is running the game once the `turn_deadline` has passed.
+    pub fn tick(&mut self) -> Vec<Change> {
+        let Some(deadline) = self.turn_deadline() else {
+            return vec![];
+        };
+        let now = (self.clock)();
+        if now < deadline {
+            return vec![];
+        }
+
+        let player = self.next_player.expect("Turns have a deadline once a player is up");
+        let turn_starts = self.players[player]
+            .turn_starts_no_later_than
+            .expect("Turns have a deadline once they have started");
+
+        if self.move_durations.len() < self.players.len() {
+            self.move_durations.resize(self.players.len(), vec![]);
+        }
+        self.move_durations[player].push(Duration::seconds(now.saturating_sub(turn_starts) as i64));
+        self.recent_changes = vec![Change::Time(TimeChange {
+            player,
+            time_change: 0,
+            reason: "Ran out of time, so the turn was passed".into(),
+        })];
+        self.turn_count += 1;
+        self.player_turn_count[player] += 1;
+
+        self.calculate_game_over(Some(player));
+        if self.is_over() {
+            return self.recent_changes.clone();
+        }
+
+        let next_player = (player + 1) % self.players.len();
+        self.next_player = Some(next_player);
+        self.players[player].turn_starts_no_later_than = None;
+        self.players[player].turn_starts_no_sooner_than = None;
+        self.players[next_player].turn_starts_no_later_than = Some(now);
+        self.players[next_player].turn_starts_no_sooner_than = Some(now);
+
+        self.recent_changes.clone()
+    }
+
+    /// Plays a turn like `play_turn`, gathering up everything that happened into one report
+    pub fn play_turn_with_report(
+        &mut self,
+        next_move: Move,
+        attacker_dictionary: Option<&WordDict>,
+        defender_dictionary: Option<&WordDict>,
+        cached_word_judgements: Option<&mut HashMap<String, bool, xxh3::Xxh3Builder>>,
+    ) -> Result<reporting::TurnReport, String> {
+        let player = match next_move {
+            Move::Place { player, .. } => player,
+            Move::Swap { player, .. } => player,
+            Move::PlaceMany { player, .. } => player,
+            Move::Exchange { player, .. } => player,
+        };
+        let turns_before = self.turn_count;
+
+        let winner = self.play_turn(
+            next_move,
+            attacker_dictionary,
+            defender_dictionary,
+            cached_word_judgements,
+        )?;
+
+        // The game may have ended before the move could be made
+        let changes = if self.turn_count == turns_before {
+            &[][..]
+        } else {
+            &self.recent_changes[..]
+        };
+        Ok(reporting::TurnReport::new(
+            player,
+            changes,
+            winner,
+            self.game_end.clone(),
+        ))
+    }
+
     pub fn play_turn(
         &mut self,
         next_move: Move,
@@ -380,17 +686,23 @@ impl Game {
         defender_dictionary: Option<&WordDict>,
         cached_word_judgements: Option<&mut HashMap<String, bool, xxh3::Xxh3Builder>>,
     ) -> Result<Option<usize>, String> {
-        if self.winner.is_some() {
+        if self.is_over() {
             return Err("Game is already over".into());
         }
 
         let player = match next_move {
             Move::Place { player, .. } => player,
             Move::Swap { player, .. } => player,
+            Move::PlaceMany { player, .. } => player,
+            Move::Exchange { player, .. } => player,
         };
+        let placed = matches!(next_move, Move::Place { .. } | Move::PlaceMany { .. });
+
+        // Moves that arrive after the turn has timed out are too late
+        self.tick();
 
         self.calculate_game_over(Some(player));
-        if self.winner.is_some() {
+        if self.is_over() {
             return Ok(self.winner);
         }
 
@@ -404,8 +716,12 @@ impl Game {
         }
 
         if let Some(turn_start) = self.players[player].turn_starts_no_sooner_than {
-            if turn_start > now() {
-                return Err("Player's turn has not yet started".into());
+            let now = (self.clock)();
+            if turn_start > now {
+                return Err(GamePlayError::TooSoon {
+                    remaining: turn_start - now,
+                }
+                .to_string());
             }
         } else {
             return Err("Player's turn has not yet started".into());
@@ -445,18 +761,37 @@ impl Game {
             }
         }
 
+        self.cool_vacated_squares();
+
         self.turn_count += 1;
         self.player_turn_count[player] += 1;
+        if placed {
+            self.consecutive_swaps = 0;
+        } else {
+            self.consecutive_swaps += 1;
+        }
 
         // Check for winning via defeated towns or artifacts
         if let Some(winner) = Judge::winner(&(self.board)) {
             self.winner = Some(winner);
+            self.game_end = self
+                .recent_changes
+                .iter()
+                .filter_map(|change| match change {
+                    Change::Board(BoardChange {
+                        detail: BoardChangeDetail { coordinate, .. },
+                        action: BoardChangeAction::Added,
+                    }) => self.board.winning_word(*coordinate),
+                    _ => None,
+                })
+                .next()
+                .map(|(word, text)| reporting::GameEnd::Destination { word, text });
             return Ok(Some(winner));
         }
 
         // Check for de-facto winning by blocking all moves
         self.calculate_game_over(Some(player));
-        if self.winner.is_some() {
+        if self.is_over() {
             return Ok(self.winner);
         }
 
@@ -465,14 +800,20 @@ impl Game {
         }
 
         let this_player = &mut self.players[player];
-        if let Some(time_remaining) = &mut this_player.time_remaining {
-            let turn_duration = now().saturating_sub(
-                this_player
-                    .turn_starts_no_later_than
-                    .or(this_player.turn_starts_no_sooner_than)
-                    .expect("Player played without the time running"),
-            );
+        let turn_duration = (self.clock)().saturating_sub(
+            this_player
+                .turn_starts_no_later_than
+                .or(this_player.turn_starts_no_sooner_than)
+                .expect("Player played without the time running"),
+        );
 
+        if self.move_durations.len() < self.players.len() {
+            self.move_durations.resize(self.players.len(), vec![]);
+        }
+        self.move_durations[player].push(Duration::seconds(turn_duration as i64));
+
+        let this_player = &mut self.players[player];
+        if let Some(time_remaining) = &mut this_player.time_remaining {
             *time_remaining -= Duration::seconds(turn_duration as i64);
 
             let overtime_rule = match &self.rules.timing {
@@ -503,14 +844,58 @@ impl Game {
                         }
                     }
                 }
+                Some(OvertimeRule::RemoveTiles { period, phase_time }) => {
+                    let overtime = time_remaining.whole_seconds().saturating_neg();
+                    if time_remaining.is_negative() && overtime >= *phase_time as i64 {
+                        let total_removals =
+                            1 + ((overtime - *phase_time as i64) / *period as i64) as usize;
+                        let new_removals =
+                            total_removals.saturating_sub(this_player.penalties_incurred);
+                        this_player.penalties_incurred += new_removals;
+
+                        for _ in 0..new_removals {
+                            let Some(change) = self.board.remove_penalty_tile(
+                                player,
+                                &mut self.bag,
+                                attacker_dictionary,
+                            ) else {
+                                break;
+                            };
+                            self.recent_changes.push(change);
+                        }
+                    }
+                }
+                // A wildcard is granted for each full period spent in overtime.
+                // A zero period would grant endlessly, so it grants nothing.
+                Some(OvertimeRule::FreeWildcard { period })
+                    if *period > 0 && time_remaining.is_negative() =>
+                {
+                    let total_wildcards =
+                        (time_remaining.whole_seconds() / -(*period as i64)) as usize;
+                    let new_wildcards =
+                        total_wildcards.saturating_sub(this_player.penalties_incurred);
+                    this_player.penalties_incurred += new_wildcards;
+
+                    for _ in 0..new_wildcards {
+                        self.recent_changes.push(this_player.add_special_tile('*'));
+                    }
+                }
                 _ => {}
             };
         }
 
+        // Each turn starts with a fresh allowance, including any head start from a handicap
+        if let rules::Timing::PerTurn { time_allowance } = self.rules.timing {
+            let allowance = time_allowance + self.extra_time(player);
+            self.players[player].time_remaining = Some(Duration::seconds(allowance as i64));
+        }
+
         match &self.rules.timing {
             rules::Timing::Periodic { turn_delay, .. } => {
-                self.players[player].turn_starts_no_later_than = Some(now() + *turn_delay as u64);
-                self.players[player].turn_starts_no_sooner_than = Some(now() + *turn_delay as u64);
+                self.players[player].turn_starts_no_later_than =
+                    Some((self.clock)() + *turn_delay as u64);
+                self.players[player].turn_starts_no_sooner_than =
+                    Some((self.clock)() + *turn_delay as u64);
             }
             _ => {
                 self.players[player].turn_starts_no_later_than = None;
@@ -522,13 +907,14 @@ impl Game {
                     .any(|c| matches!(c, Change::Battle(_)))
                 {
                     self.players[self.next_player.unwrap()].turn_starts_no_sooner_than =
-                        Some(now());
+                        Some((self.clock)());
                     self.players[self.next_player.unwrap()].turn_starts_no_later_than =
-                        Some(now() + self.rules.battle_delay);
+                        Some((self.clock)() + self.rules.battle_delay);
                 } else {
                     self.players[self.next_player.unwrap()].turn_starts_no_sooner_than =
-                        Some(now());
-                    self.players[self.next_player.unwrap()].turn_starts_no_later_than = Some(now());
+                        Some((self.clock)());
+                    self.players[self.next_player.unwrap()].turn_starts_no_later_than =
+                        Some((self.clock)());
                 }
             }
         }
@@ -565,6 +951,7 @@ impl Game {
                 if let Square::Occupied { .. } = self.board.get(position)? {
                     return Err(GamePlayError::OccupiedPlace);
                 }
+                self.check_square_cooldown(position)?;
 
                 let neighbors = self.board.neighbouring_squares(position);
 
@@ -586,6 +973,8 @@ impl Game {
                     return Err(GamePlayError::PlayerDoesNotHaveTile { player, tile });
                 }
 
+                self.check_words_formed(player, &[(position, tile)], attacker_dictionary)?;
+
                 changes.push(Change::Board(BoardChange {
                     detail: self
                         .board
@@ -596,12 +985,145 @@ impl Game {
 
                 self.resolve_attack(
                     player,
-                    position,
+                    &[position],
+                    attacker_dictionary,
+                    defender_dictionary,
+                    cached_word_judgements,
+                    &mut changes,
+                );
+                self.resolve_turn_end(&[position], attacker_dictionary, &mut changes);
+
+                self.players[player].swap_count = 0;
+
+                Ok(changes)
+            }
+            Move::PlaceMany {
+                player,
+                placements: player_reported_placements,
+            } => {
+                if self.get_player(player).is_none() {
+                    return Err(GamePlayError::NonExistentPlayer { index: player });
+                }
+
+                if player_reported_placements.is_empty() {
+                    return Err(GamePlayError::EmptyPlace);
+                }
+
+                if player_reported_placements.len() > self.rules.tiles_per_turn {
+                    return Err(GamePlayError::TooManyPlacements {
+                        placed: player_reported_placements.len(),
+                        allowed: self.rules.tiles_per_turn,
+                    });
+                }
+
+                let placements: Vec<_> = player_reported_placements
+                    .into_iter()
+                    .map(|(position, tile)| {
+                        (
+                            self.board.map_player_coord_to_game(
+                                player,
+                                position,
+                                &self.rules.visibility,
+                                &self.players[player].seen_tiles,
+                            ),
+                            tile,
+                        )
+                    })
+                    .collect();
+                let positions: Vec<_> = placements.iter().map(|(position, _)| *position).collect();
+
+                if positions.iter().collect::<HashSet<_>>().len() != positions.len() {
+                    return Err(GamePlayError::DuplicatePlace);
+                }
+
+                // Check every square before placing anything, so a rejected move changes nothing
+                for position in &positions {
+                    match self.board.get(*position)? {
+                        Square::Land { .. } => {}
+                        Square::Occupied { .. } => return Err(GamePlayError::OccupiedPlace),
+                        _ => return Err(GamePlayError::InvalidPosition { position: *position }),
+                    }
+                    self.check_square_cooldown(*position)?;
+
+                    if self.turn_count == 0
+                        && self.board.neighbouring_squares(*position).iter().any(|&(_, square)| {
+                            matches!(square, Square::Artifact { player: p, .. } if p != player)
+                        })
+                    {
+                        return Err(GamePlayError::OpponentStartPlace);
+                    }
+                }
+
+                // Check the whole hand up front, as using a tile draws its replacement
+                let mut hand = self.players[player].hand.0.clone();
+                for (_, tile) in &placements {
+                    let Some(index) = hand.iter().position(|t| t == tile) else {
+                        return Err(GamePlayError::PlayerDoesNotHaveTile {
+                            player,
+                            tile: *tile,
+                        });
+                    };
+                    hand.remove(index);
+                }
+
+                // Every placement must be reachable from the player's existing squares,
+                // either directly or through the other tiles placed this turn.
+                let anchored = |position: &Coordinate| {
+                    self.board
+                        .neighbouring_squares(*position)
+                        .iter()
+                        .any(|&(_, square)| match square {
+                            Square::Occupied { player: p, .. } => p == player,
+                            Square::Artifact { player: p, .. } => p == player,
+                            _ => false,
+                        })
+                };
+                let mut connected: Vec<_> = positions.iter().filter(|p| anchored(p)).collect();
+                if connected.is_empty() {
+                    return Err(GamePlayError::NonAdjacentPlace);
+                }
+                let mut frontier = connected.clone();
+                while let Some(position) = frontier.pop() {
+                    for (neighbour, _) in self.board.connected_squares(*position) {
+                        if let Some(placed) = positions.iter().find(|p| **p == neighbour) {
+                            if !connected.contains(&placed) {
+                                connected.push(placed);
+                                frontier.push(placed);
+                            }
+                        }
+                    }
+                }
+                if connected.len() != positions.len() {
+                    return Err(GamePlayError::DisconnectedPlace);
+                }
+
+                self.check_words_formed(player, &placements, attacker_dictionary)?;
+
+                let mut board = self.board.clone();
+                let mut placed = Vec::with_capacity(placements.len());
+                for (position, tile) in &placements {
+                    placed.push(board.set(*position, player, *tile, attacker_dictionary)?);
+                }
+                self.board = board;
+
+                for ((_, tile), detail) in placements.into_iter().zip(placed) {
+                    changes.push(Change::Board(BoardChange {
+                        detail,
+                        action: BoardChangeAction::Added,
+                    }));
+                    changes.push(self.players[player].use_tile(tile, &mut self.bag)?);
+                }
+
+                // All of the turn's tiles fight together in a single battle
+                self.resolve_attack(
+                    player,
+                    &positions,
                     attacker_dictionary,
                     defender_dictionary,
                     cached_word_judgements,
                     &mut changes,
                 );
+                self.resolve_turn_end(&positions, attacker_dictionary, &mut changes);
 
                 self.players[player].swap_count = 0;
 
@@ -657,41 +1179,38 @@ impl Game {
 
                 player.swap_count += 1;
 
-                match swap_rules {
-                    Some(rules::SwapPenalty::Time {
-                        swap_threshold,
-                        penalties,
-                    }) => {
-                        let player_swaps = player.swap_count;
-
-                        if player_swaps > *swap_threshold {
-                            let penalty_number = player_swaps - swap_threshold - 1;
-                            let penalty =
-                                penalties.get(penalty_number).or_else(|| penalties.last());
-                            if let (Some(penalty), Some(time_remaining)) =
-                                (penalty, &mut player.time_remaining)
-                            {
-                                let time_change = -(*penalty as isize);
-                                *time_remaining += Duration::seconds(time_change as i64);
-                                swap_result.push(Change::Time(TimeChange {
-                                    player: player_index,
-                                    time_change,
-                                    reason: format!(
-                                        "Lost time for {player_swaps} consecutive swap{}",
-                                        if player_swaps == 1 { "" } else { "s" }
-                                    ),
-                                }))
-                            }
-                        }
-                    }
-                    Some(rules::SwapPenalty::Disallowed { .. }) => {
-                        // Handled before move was made
-                    }
-                    None => {}
+                let player_swaps = player.swap_count;
+                let penalty = swap_rules.and_then(|rules| rules.time_penalty(player_swaps));
+                if let (Some(penalty), Some(time_remaining)) =
+                    (penalty, &mut player.time_remaining)
+                {
+                    let time_change = -(penalty as isize);
+                    *time_remaining += Duration::seconds(time_change as i64);
+                    swap_result.push(Change::Time(TimeChange {
+                        player: player_index,
+                        time_change,
+                        reason: format!(
+                            "Lost time for {player_swaps} consecutive swap{}",
+                            if player_swaps == 1 { "" } else { "s" }
+                        ),
+                    }))
                 }
 
                 Ok(swap_result)
             }
+            Move::Exchange { player, indices } => {
+                if self.get_player(player).is_none() {
+                    return Err(GamePlayError::NonExistentPlayer { index: player });
+                }
+
+                if !self.rules.tile_exchange {
+                    return Err(GamePlayError::NoExchanging);
+                }
+
+                let change = self.players[player].exchange_tiles(&indices, &mut self.bag)?;
+
+                Ok(vec![change])
+            }
         }
     }
 
@@ -705,13 +1224,43 @@ impl Game {
     fn resolve_attack(
         &mut self,
         player: usize,
-        position: Coordinate,
+        positions: &[Coordinate],
         attacker_dictionary: Option<&WordDict>,
         defender_dictionary: Option<&WordDict>,
         cached_word_judgements: Option<&mut HashMap<String, bool, xxh3::Xxh3Builder>>,
         changes: &mut Vec<Change>,
     ) {
-        let (attackers, defenders) = self.board.collect_combanants(player, position, &self.rules);
+        // The game's own cache can only be used when both sides are judged against the same words
+        let judged_against = match (attacker_dictionary, defender_dictionary) {
+            (Some(attacker), Some(defender)) if std::ptr::eq(attacker, defender) => {
+                Some(JudgedAgainst::External(attacker as *const WordDict as usize))
+            }
+            (None, None) => Some(JudgedAgainst::Judge(self.judge.id())),
+            _ => None,
+        };
+        let cached_word_judgements = match (cached_word_judgements, judged_against) {
+            (Some(cache), _) => Some(cache),
+            (None, Some(dictionary)) => Some(self.word_judgements.against(dictionary)),
+            (None, None) => None,
+        };
+
+        // Words shared by several of the placed tiles only fight once
+        let mut attackers: Vec<Vec<Coordinate>> = vec![];
+        let mut defenders: Vec<Vec<Coordinate>> = vec![];
+        for position in positions {
+            let (attacking, defending) =
+                self.board.collect_combanants(player, *position, &self.rules);
+            for word in attacking {
+                if !attackers.contains(&word) {
+                    attackers.push(word);
+                }
+            }
+            for word in defending {
+                if !defenders.contains(&word) {
+                    defenders.push(word);
+                }
+            }
+        }
         let attacking_words = self
             .board
             .word_strings(&attackers)
@@ -732,6 +1281,12 @@ impl Game {
         ) {
             battle.battle_number = Some(self.battle_count);
             self.battle_count += 1;
+            for (word, coordinates) in battle.attackers.iter_mut().zip(&attackers) {
+                word.coordinates = coordinates.clone();
+            }
+            for (word, coordinates) in battle.defenders.iter_mut().zip(&defenders) {
+                word.coordinates = coordinates.clone();
+            }
 
             match battle.outcome.clone() {
                 Outcome::DefenderWins => {
@@ -765,12 +1320,16 @@ impl Game {
                             if let Ok(Square::Occupied { tile, .. }) = self.board.get(square) {
                                 self.bag.return_tile(tile);
                             }
-                            self.board.clear(square, attacker_dictionary).map(|detail| {
-                                Change::Board(BoardChange {
-                                    detail,
-                                    action: BoardChangeAction::Defeated,
+                            self.board
+                                .clear(square, attacker_dictionary)
+                                .ok()
+                                .flatten()
+                                .map(|detail| {
+                                    Change::Board(BoardChange {
+                                        detail,
+                                        action: BoardChangeAction::Defeated,
+                                    })
                                 })
-                            })
                         }));
                     }
                 }
@@ -790,12 +1349,13 @@ impl Game {
                         let defender = defenders
                             .get(defender_index)
                             .expect("Losers should only contain valid squares");
-                        defender.into_iter()
+                        defender.iter()
                     });
+                    let mut captured_tiles = vec![];
                     changes.extend(squares.flat_map(|square| {
                         match self.board.get(*square) {
                             Ok(Square::Occupied { tile, .. }) => {
-                                self.bag.return_tile(tile);
+                                captured_tiles.push(tile);
                             }
                             Ok(Square::Town { player, .. }) => {
                                 _ = self.board.set_square(
@@ -822,6 +1382,8 @@ impl Game {
 
                         self.board
                             .clear(*square, attacker_dictionary)
+                            .ok()
+                            .flatten()
                             .map(|detail| {
                                 Change::Board(BoardChange {
                                     detail,
@@ -831,7 +1393,15 @@ impl Game {
                     }));
 
                     // explode adjacent letters belonging to opponents
-                    changes.extend(self.board.neighbouring_squares(position).iter().flat_map(
+                    let mut neighbours: Vec<(Coordinate, Square)> = vec![];
+                    for position in positions {
+                        for (coordinate, square) in self.board.neighbouring_squares(*position) {
+                            if !neighbours.iter().any(|(c, _)| *c == coordinate) {
+                                neighbours.push((coordinate, square));
+                            }
+                        }
+                    }
+                    changes.extend(neighbours.iter().flat_map(
                         |neighbour| {
                             if let (
                                 coordinate,
@@ -843,40 +1413,81 @@ impl Game {
                             ) = neighbour
                             {
                                 if *owner != player {
-                                    self.bag.return_tile(*tile);
-                                    return self.board.clear(*coordinate, attacker_dictionary).map(
-                                        |detail| {
+                                    captured_tiles.push(*tile);
+                                    return self
+                                        .board
+                                        .clear(*coordinate, attacker_dictionary)
+                                        .ok()
+                                        .flatten()
+                                        .map(|detail| {
                                             Change::Board(BoardChange {
                                                 detail,
                                                 action: BoardChangeAction::Exploded,
                                             })
-                                        },
-                                    );
+                                        });
                                 }
                             }
                             None
                         },
                     ));
+
+                    match self.rules.captured_tiles {
+                        rules::CapturedTileRule::ReturnToBag => {
+                            for tile in captured_tiles {
+                                self.bag.return_tile(tile);
+                            }
+                        }
+                        rules::CapturedTileRule::Void => {}
+                        rules::CapturedTileRule::TransferToCaptor => {
+                            changes.extend(
+                                captured_tiles
+                                    .into_iter()
+                                    .map(|tile| self.players[player].add_special_tile(tile)),
+                            );
+                        }
+                    }
                 }
             }
             changes.push(Change::Battle(battle));
         }
+    }
 
+    // Once all battles from a turn are resolved
+    //   - Disconnected tiles are truncated
+    //   - Any bombs placed this turn are cleared from the board
+    fn resolve_turn_end(
+        &mut self,
+        placed: &[Coordinate],
+        attacker_dictionary: Option<&WordDict>,
+        changes: &mut Vec<Change>,
+    ) {
+        let anchored = self
+            .board
+            .anchored_tiles(&self.judge, &self.rules, attacker_dictionary);
         match self.rules.truncation {
-            rules::Truncation::Root => changes.extend(
-                self.board
-                    .truncate(&mut self.bag, attacker_dictionary)
-                    .into_iter(),
-            ),
-            rules::Truncation::Larger => unimplemented!(),
+            rules::Truncation::Root => changes.extend(self.board.truncate(
+                &mut self.bag,
+                attacker_dictionary,
+                &anchored,
+            )),
+            rules::Truncation::Larger => changes.extend(self.board.truncate_larger(
+                &mut self.bag,
+                attacker_dictionary,
+                &anchored,
+            )),
             rules::Truncation::None => {}
         }
+        if self.rules.truncation_recovery == rules::TruncationRecovery::ToHand {
+            self.recover_truncated_tiles(changes);
+        }
 
-        match self.board.get(position) {
-            Ok(Square::Occupied { tile, .. }) if tile == '¤' => {
+        for position in placed {
+            if let Ok(Square::Occupied { tile: '¤', .. }) = self.board.get(*position) {
                 changes.push(
                     self.board
-                        .clear(position, attacker_dictionary)
+                        .clear(*position, attacker_dictionary)
+                        .ok()
+                        .flatten()
                         .map(|detail| {
                             Change::Board(BoardChange {
                                 detail,
@@ -886,7 +1497,137 @@ impl Game {
                         .expect("Tile exists and should be removable"),
                 );
             }
-            _ => {}
+        }
+    }
+
+    /// Squares that are still cooling down after being vacated, so can't be placed on yet
+    pub fn cooling_positions(&self) -> HashSet<Coordinate> {
+        self.cooling_squares
+            .iter()
+            .filter(|(_, free_at)| **free_at > self.turn_count)
+            .map(|(position, _)| *position)
+            .collect()
+    }
+
+    /// Where the player can place a tile this turn, leaving out squares that are cooling down
+    pub fn playable_positions(&self, player: usize) -> HashSet<Coordinate> {
+        let cooling = self.cooling_positions();
+        self.board
+            .playable_positions(player, &self.rules.truncation)
+            .into_iter()
+            .filter(|position| !cooling.contains(position))
+            .collect()
+    }
+
+    /// Rejects placements onto a square that is still cooling down after being vacated
+    fn check_square_cooldown(&self, position: Coordinate) -> Result<(), GamePlayError> {
+        match self.cooling_squares.get(&position) {
+            Some(&free_at) if free_at > self.turn_count => Err(GamePlayError::SquareCoolingDown {
+                turns_left: free_at - self.turn_count,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Starts the cooldown on any squares vacated by this turn's changes,
+    /// and forgets squares that have finished cooling down.
+    fn cool_vacated_squares(&mut self) {
+        let Some(cooldown) = self.rules.square_cooldown else {
+            return;
+        };
+
+        let turn = self.turn_count;
+        self.cooling_squares.retain(|_, free_at| *free_at > turn + 1);
+
+        for change in &self.recent_changes {
+            if let Change::Board(BoardChange {
+                detail: BoardChangeDetail { coordinate, .. },
+                action:
+                    BoardChangeAction::Defeated
+                    | BoardChangeAction::Truncated
+                    | BoardChangeAction::Exploded,
+            }) = change
+            {
+                self.cooling_squares.insert(*coordinate, turn + 1 + cooldown);
+            }
+        }
+    }
+
+    /// Under `WordValidity::Always`, rejects placements that would form any invalid word
+    /// through one of the placed tiles. Words are judged on a copy of the board, before the move commits.
+    fn check_words_formed(
+        &self,
+        player: usize,
+        placements: &[(Coordinate, char)],
+        attacker_dictionary: Option<&WordDict>,
+    ) -> Result<(), GamePlayError> {
+        if self.rules.word_validity == rules::WordValidity::OnAttack {
+            return Ok(());
+        }
+
+        let mut preview = self.board.clone();
+        for (position, tile) in placements {
+            preview.set(*position, player, *tile, None)?;
+        }
+        for (position, _) in placements {
+            let words: Vec<_> = preview
+                .get_words(*position)
+                .into_iter()
+                .filter(|word| word.len() > 1)
+                .collect();
+            for word in preview.word_strings(&words)? {
+                if self
+                    .judge
+                    .valid(
+                        &word,
+                        &self.rules.win_condition,
+                        attacker_dictionary,
+                        None,
+                        &mut None,
+                    )
+                    .is_none()
+                {
+                    return Err(GamePlayError::InvalidWord { word });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Moves this turn's truncated tiles from the bag back into their owners' hands,
+    /// up to each hand's capacity. Tiles that don't fit stay in the bag.
+    fn recover_truncated_tiles(&mut self, changes: &mut Vec<Change>) {
+        let mut recovered = vec![vec![]; self.players.len()];
+        for change in changes.iter() {
+            let Change::Board(BoardChange {
+                detail:
+                    BoardChangeDetail {
+                        square: Square::Occupied { player, tile, .. },
+                        ..
+                    },
+                action: BoardChangeAction::Truncated,
+            }) = change
+            else {
+                continue;
+            };
+            let Some(owner) = self.players.get_mut(*player) else {
+                continue;
+            };
+            if owner.hand.len() < owner.hand_capacity && self.bag.take(*tile) {
+                owner.hand.add(*tile);
+                recovered[*player].push(*tile);
+            }
+        }
+
+        for (player, added) in recovered.into_iter().enumerate() {
+            if !added.is_empty() {
+                changes.push(Change::Hand(HandChange {
+                    player,
+                    removed: vec![],
+                    added,
+                }));
+            }
         }
     }
 
@@ -895,25 +1636,64 @@ impl Game {
     }
 
     pub fn filter_game_to_player(&self, player_index: usize) -> (Board, Vec<Change>) {
+        let (payload, visible_changes) = self.client_payload(player_index);
+        (payload.board, visible_changes)
+    }
+
+    /// Like `filter_game_to_player`, but keeps the view and root that the player's client needs
+    /// to draw the board from their seat. See `Board::client_payload`.
+    pub fn client_payload(&self, player_index: usize) -> (ClientBoard, Vec<Change>) {
         let seen = &self.players[player_index].seen_tiles;
 
-        let visible_board = self.board.filter_to_player(
+        let payload =
+            self.board
+                .client_payload(player_index, &self.rules.visibility, &self.winner, seen);
+
+        let visible_changes = reporting::filter_to_player(
+            &self.recent_changes,
+            &self.board,
+            &payload.board,
             player_index,
             &self.rules.visibility,
             &self.winner,
             seen,
-            true,
         );
+        (payload, visible_changes)
+    }
 
-        let visible_changes = reporting::filter_to_player(
-            &self.recent_changes,
-            &self.board,
-            &visible_board,
+    /// How many enemy tiles the player can currently see, if fog of war is hiding any from them
+    pub fn visible_enemy_tiles(&self, player_index: usize) -> Option<usize> {
+        if self.is_over() || self.rules.visibility == rules::Visibility::Standard {
+            return None;
+        }
+
+        Some(self.board.visible_enemy_tiles(player_index))
+    }
+
+    /// Suggests the strongest attack the player can make from their hand, using `Board::best_attack`.
+    /// The search only sees the player's fogged board, so hints never give away hidden enemy tiles.
+    /// That board isn't trimmed, so the suggested position is in game coordinates.
+    pub fn hint(
+        &self,
+        player_index: usize,
+        dict: Option<&WordDict>,
+    ) -> Option<(Coordinate, char, reporting::BattleReport)> {
+        let player = self.players.get(player_index)?;
+        let visible_board = self.board.filter_to_player(
             player_index,
             &self.rules.visibility,
             &self.winner,
-            seen,
+            &player.seen_tiles,
+            false,
         );
-        (visible_board, visible_changes)
+
+        visible_board.best_attack(
+            player_index,
+            &player.hand,
+            &self.judge,
+            &self.rules,
+            &self.cooling_positions(),
+            dict,
+        )
     }
 }
@@ -12,14 +12,14 @@ use crate::{
     game::Game,
 };
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum ArtifactType {
     IslandV1,
     Coastal,
     Continental,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Symmetry {
     SmoothTwoFoldRotational,
     TwoFoldRotational,
@@ -33,20 +33,20 @@ pub struct BoardElements {
     pub obelisk: bool,
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BoardNoiseParams {
     pub dispersion: [f64; 2],
     pub island_influence: f64,
     pub symmetric: Symmetry,
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WaterLayer {
     pub params: BoardNoiseParams,
     pub density: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BoardParams {
     pub land_layer: BoardNoiseParams,
     pub water_layer: Option<WaterLayer>,
@@ -442,7 +442,7 @@ pub fn generate_board(
     // Recalculate the shortest path, as expanding the choke points
     // may have created new paths altogether
     let Some(shortest_attack_path) =
-        board.shortest_path_between(&board.artifacts[0], &board.artifacts[1])
+        board.shortest_path_between(&board.artifacts()[0], &board.artifacts()[1])
     else {
         return retry_with(board_seed, board);
     };
@@ -672,7 +672,7 @@ impl BoardGenerator for Board {
         debug: bool,
     ) -> Result<(), ()> {
         let Some(shortest_attack_path) =
-            self.shortest_path_between(&self.artifacts[0], &self.artifacts[1])
+            self.shortest_path_between(&self.artifacts()[0], &self.artifacts()[1])
         else {
             return Err(());
         };
@@ -1044,7 +1044,7 @@ impl BoardGenerator for Board {
         maximum_town_distance: f64,
         symmetric: Symmetry,
     ) -> Result<(), ()> {
-        let artifacts = &self.artifacts;
+        let artifacts = self.artifacts();
         let Some(Ok(Square::Artifact {
             player: player_zero,
             ..
@@ -1176,6 +1176,16 @@ pub fn get_game_verification(game: &Game) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn generated_boards_are_valid() {
+        for seed in 0..200 {
+            let board = generate_board(BoardSeed::new(seed))
+                .expect("Board can be resolved")
+                .board;
+            assert_eq!(board.validate(), Ok(()), "Seed {seed} generated:\n{board}");
+        }
+    }
+
     #[test]
     fn reroll_test() {
         let mut seed = BoardSeed::new(12345);
@@ -0,0 +1,249 @@
+use std::collections::VecDeque;
+
+use crate::{
+    bag::TileBag,
+    board::{Board, Square},
+    error::GamePlayError,
+    reporting::{BoardChange, BoardChangeAction, Change},
+};
+
+/// A bounded record of the changes made to a board, kept one move at a time so that moves can be undone and redone.
+/// Only the reported changes are stored, and the board is rebuilt from them, so the board itself is never copied into the history.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoardHistory {
+    limit: usize,
+    undo: VecDeque<Vec<BoardChange>>,
+    redo: Vec<Vec<BoardChange>>,
+}
+
+impl BoardHistory {
+    /// Keeps up to `limit` moves to undo, forgetting the oldest first
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            undo: VecDeque::new(),
+            redo: vec![],
+        }
+    }
+
+    /// Records the board changes from one move, such as those returned by `Board::swap` or `Board::truncate`.
+    /// Anything that had been undone can no longer be redone.
+    pub fn record(&mut self, changes: &[Change]) {
+        let group: Vec<_> = changes
+            .iter()
+            .filter_map(|change| match change {
+                Change::Board(change) => Some(change.clone()),
+                _ => None,
+            })
+            .collect();
+        if group.is_empty() || self.limit == 0 {
+            return;
+        }
+
+        self.redo.clear();
+        if self.undo.len() == self.limit {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(group);
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Steps the board and bag back over the most recent move, returning the changes that were undone.
+    /// Placed tiles are cleared from the board, and it is up to the caller to give them back to the player's hand.
+    pub fn undo(
+        &mut self,
+        board: &mut Board,
+        bag: &mut TileBag,
+    ) -> Result<Vec<BoardChange>, GamePlayError> {
+        let group = self.undo.pop_back().ok_or(GamePlayError::NothingToUndo)?;
+        if let Err(e) = board.revert_change_group(&group, bag) {
+            self.undo.push_back(group);
+            return Err(e);
+        }
+
+        self.redo.push(group.clone());
+        Ok(group)
+    }
+
+    /// Makes the most recently undone move again, returning its changes.
+    /// Truncated and defeated tiles go back into the bag, as they did the first time.
+    pub fn redo(
+        &mut self,
+        board: &mut Board,
+        bag: &mut TileBag,
+    ) -> Result<Vec<BoardChange>, GamePlayError> {
+        let group = self.redo.pop().ok_or(GamePlayError::NothingToRedo)?;
+        if let Err(e) = board.apply_change_group(&group) {
+            self.redo.push(group);
+            return Err(e);
+        }
+
+        for change in &group {
+            if matches!(
+                change.action,
+                BoardChangeAction::Truncated | BoardChangeAction::Defeated
+            ) {
+                if let Square::Occupied { tile, .. } = change.detail.square {
+                    bag.return_tile(tile);
+                }
+            }
+        }
+
+        self.undo.push_back(group.clone());
+        Ok(group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{
+        board::Coordinate,
+        reporting::BoardChangeDetail,
+        rules::{SwapPenalty, Swapping},
+    };
+
+    use super::*;
+
+    #[test]
+    fn undo_and_redo() {
+        let original = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             __ A0 __ __\n\
+             __ B0 C0 __\n\
+             __ __ __ __\n\
+             ~~ ~~ |1 ~~",
+        );
+        let mut board = original.clone();
+        let mut bag = TileBag::explicit(vec!['X'], Some(1));
+        let mut history = BoardHistory::new(10);
+
+        // A placement
+        let detail = board.set(Coordinate::new(0, 2), 0, 'D', None).unwrap();
+        history.record(&[Change::Board(BoardChange {
+            detail,
+            action: BoardChangeAction::Added,
+        })]);
+        let placed = board.clone();
+
+        // A swap
+        let swap = Swapping::Contiguous(SwapPenalty::Disallowed { allowed_swaps: 1 });
+        let changes = board
+            .swap(
+                0,
+                [Coordinate::new(1, 1), Coordinate::new(2, 2)],
+                &swap,
+                None,
+            )
+            .unwrap();
+        history.record(&changes);
+        let swapped = board.clone();
+        assert_eq!(
+            board.to_string(),
+            "~~ |0 ~~ ~~\n\
+             __ C0 __ __\n\
+             D0 B0 A0 __\n\
+             __ __ __ __\n\
+             ~~ ~~ |1 ~~",
+        );
+
+        // A multi-tile truncation, once C0 is taken out from under the others
+        board.clear(Coordinate::new(1, 1), None).unwrap();
+        let changes = board.truncate(&mut bag, None, &HashSet::new());
+        assert_eq!(changes.len(), 3);
+        history.record(&changes);
+        let truncated = board.clone();
+        let truncated_bag = bag.clone();
+
+        history.undo(&mut board, &mut bag).unwrap();
+        assert_eq!(board.to_string(), {
+            let mut b = swapped.clone();
+            b.clear(Coordinate::new(1, 1), None).unwrap();
+            b.to_string()
+        });
+        assert_eq!(bag, TileBag::explicit(vec!['X'], Some(1)));
+
+        // Redoing the truncation puts the tiles back into the bag
+        history.redo(&mut board, &mut bag).unwrap();
+        assert_eq!(board, truncated);
+        assert_eq!(bag, truncated_bag);
+        history.undo(&mut board, &mut bag).unwrap();
+
+        // Undoing the swap trades the tiles back
+        board
+            .set(Coordinate::new(1, 1), 0, 'C', None)
+            .expect("Tile cleared by hand is put back by hand");
+        history.undo(&mut board, &mut bag).unwrap();
+        assert_eq!(board.to_string(), placed.to_string());
+
+        // Undoing the placement leaves the original board
+        let undone = history.undo(&mut board, &mut bag).unwrap();
+        assert_eq!(
+            undone,
+            vec![BoardChange {
+                detail: BoardChangeDetail {
+                    square: placed.get(Coordinate::new(0, 2)).unwrap(),
+                    coordinate: Coordinate::new(0, 2),
+                },
+                action: BoardChangeAction::Added,
+            }]
+        );
+        assert_eq!(board.to_string(), original.to_string());
+        assert!(!history.can_undo());
+        assert_eq!(
+            history.undo(&mut board, &mut bag),
+            Err(GamePlayError::NothingToUndo)
+        );
+
+        // A new move can't be followed by a redo
+        assert!(history.can_redo());
+        history.record(
+            &board
+                .swap(
+                    0,
+                    [Coordinate::new(1, 1), Coordinate::new(1, 2)],
+                    &swap,
+                    None,
+                )
+                .unwrap(),
+        );
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn bounded_history() {
+        let mut board = Board::from_string(
+            "~~ |0 ~~ ~~\n\
+             __ __ __ __\n\
+             ~~ ~~ |1 ~~",
+        );
+        let mut bag = TileBag::explicit(vec![], Some(1));
+        let mut history = BoardHistory::new(2);
+
+        for (x, tile) in [(0, 'A'), (1, 'B'), (2, 'C')] {
+            let detail = board.set(Coordinate::new(x, 1), 0, tile, None).unwrap();
+            history.record(&[Change::Board(BoardChange {
+                detail,
+                action: BoardChangeAction::Added,
+            })]);
+        }
+
+        history.undo(&mut board, &mut bag).unwrap();
+        history.undo(&mut board, &mut bag).unwrap();
+        assert!(!history.can_undo());
+        assert_eq!(
+            board.to_string(),
+            "~~ |0 ~~ ~~\n\
+             A0 __ __ __\n\
+             ~~ ~~ |1 ~~",
+        );
+    }
+}
@@ -10,8 +10,11 @@ use super::board::{Board, Square};
 use std::{
     collections::HashMap,
     fmt::{self, Display},
+    sync::atomic::{AtomicU64, Ordering},
 };
 
+static NEXT_JUDGE_ID: AtomicU64 = AtomicU64::new(0);
+
 #[derive(Debug, Clone)]
 pub struct WordData {
     pub extensions: u32,
@@ -41,6 +44,10 @@ impl fmt::Display for Outcome {
 pub struct Judge {
     pub builtin_dictionary: WordDict,
     aliases: HashMap<char, Vec<char>>,
+    id: u64,
+    /// Words looked up rather than answered from a cache, so tests can see when the cache is used
+    #[cfg(test)]
+    pub(crate) lookups: std::cell::Cell<usize>,
 }
 
 impl Default for Judge {
@@ -48,6 +55,9 @@ impl Default for Judge {
         Self {
             builtin_dictionary: HashMap::new(),
             aliases: HashMap::new(),
+            id: NEXT_JUDGE_ID.fetch_add(1, Ordering::Relaxed),
+            #[cfg(test)]
+            lookups: Default::default(),
         }
     }
 }
@@ -67,16 +77,23 @@ impl Judge {
         }
         Self {
             builtin_dictionary: dictionary,
-            aliases: HashMap::new(),
+            ..Self::default()
         }
     }
 
+    /// Identifies the words and aliases this judge accepts, changing whenever the aliases do.
+    /// Judgements cached against one id shouldn't be trusted for another.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
     pub fn set_alias(&mut self, alias_target: Vec<char>) -> char {
         for p in ['1', '2', '3', '4', '5', '6', '7', '8', '9'] {
             if self.aliases.contains_key(&p) {
                 continue;
             }
             self.aliases.insert(p, alias_target);
+            self.id = NEXT_JUDGE_ID.fetch_add(1, Ordering::Relaxed);
             return p;
         }
         panic!("Too many aliases!");
@@ -84,6 +101,7 @@ impl Judge {
 
     pub fn remove_aliases(&mut self) {
         self.aliases.clear();
+        self.id = NEXT_JUDGE_ID.fetch_add(1, Ordering::Relaxed);
     }
 
     // A player wins if they touch an opponent's town
@@ -153,6 +171,7 @@ impl Judge {
                         valid: Some(valid.is_some()),
                         meanings: None,
                         resolved_word: valid.unwrap_or_else(|| w.to_string()),
+                        coordinates: vec![],
                     }
                 })
                 .collect(),
@@ -163,9 +182,13 @@ impl Judge {
                     resolved_word: w.to_string(),
                     meanings: None,
                     valid: None,
+                    coordinates: vec![],
                 })
                 .collect(),
             outcome: Outcome::DefenderWins,
+            attacker_length: None,
+            defender_length: None,
+            length_delta: battle_rules.length_delta,
         };
 
         // The defender wins if any attacking word is invalid
@@ -215,6 +238,14 @@ impl Judge {
             })
             .expect("already checked length");
 
+        battle_report.attacker_length = Some(longest_attacker.len());
+        battle_report.defender_length = battle_report
+            .defenders
+            .iter()
+            .filter(|word| word.valid == Some(true))
+            .map(|word| word.resolved_word.len())
+            .min();
+
         let attacker_wins_outright = attackers.iter().any(|word| word.as_ref().contains('¤'));
         if attacker_wins_outright {
             battle_report.outcome = Outcome::AttackerWins(vec![]);
@@ -416,6 +447,9 @@ impl Judge {
             }
         }
 
+        #[cfg(test)]
+        self.lookups.set(self.lookups.get() + 1);
+
         let word_str = word.as_ref().to_string();
         let valid = valid_inner(self, word, win_rules, external_dictionary, used_aliases);
 
@@ -969,15 +1003,20 @@ mod tests {
                     original_word: "B*G".into(),
                     resolved_word: "BAG".into(),
                     meanings: None,
-                    valid: Some(true)
+                    valid: Some(true),
+                    coordinates: vec![]
                 }],
                 defenders: vec![BattleWord {
                     original_word: "XYZ".into(),
                     resolved_word: "XYZ".into(),
                     meanings: None,
-                    valid: Some(false)
+                    valid: Some(false),
+                    coordinates: vec![]
                 }],
-                outcome: Outcome::AttackerWins(vec![0])
+                outcome: Outcome::AttackerWins(vec![0]),
+                attacker_length: Some(3),
+                defender_length: None,
+                length_delta: 2,
             })
         );
         assert_eq!(
@@ -996,15 +1035,20 @@ mod tests {
                     original_word: "R*G".into(),
                     resolved_word: "R*G".into(),
                     meanings: None,
-                    valid: Some(false)
+                    valid: Some(false),
+                    coordinates: vec![]
                 }],
                 defenders: vec![BattleWord {
                     original_word: "XYZ".into(),
                     resolved_word: "XYZ".into(),
                     meanings: None,
-                    valid: None
+                    valid: None,
+                    coordinates: vec![]
                 }],
-                outcome: Outcome::DefenderWins
+                outcome: Outcome::DefenderWins,
+                attacker_length: None,
+                defender_length: None,
+                length_delta: 2,
             })
         );
 
@@ -1024,15 +1068,20 @@ mod tests {
                     original_word: "ARTS".into(),
                     resolved_word: "ARTS".into(),
                     meanings: None,
-                    valid: Some(true)
+                    valid: Some(true),
+                    coordinates: vec![]
                 }],
                 defenders: vec![BattleWord {
                     original_word: "JALL*".into(),
                     resolved_word: "JALL*".into(),
                     meanings: None,
-                    valid: Some(false)
+                    valid: Some(false),
+                    coordinates: vec![]
                 }],
-                outcome: Outcome::AttackerWins(vec![0])
+                outcome: Outcome::AttackerWins(vec![0]),
+                attacker_length: Some(4),
+                defender_length: None,
+                length_delta: 2,
             })
         );
         assert_eq!(
@@ -1051,19 +1100,152 @@ mod tests {
                     original_word: "BAG".into(),
                     resolved_word: "BAG".into(),
                     meanings: None,
-                    valid: Some(true)
+                    valid: Some(true),
+                    coordinates: vec![]
                 }],
                 defenders: vec![BattleWord {
                     original_word: "JOLL*".into(),
                     resolved_word: "JOLLY".into(),
                     meanings: None,
-                    valid: Some(true)
+                    valid: Some(true),
+                    coordinates: vec![]
                 }],
-                outcome: Outcome::DefenderWins
+                outcome: Outcome::DefenderWins,
+                attacker_length: Some(3),
+                defender_length: Some(5),
+                length_delta: 2,
             })
         );
     }
 
+    #[test]
+    fn battle_explanations() {
+        let j = short_dict();
+        let explain_with = |attacker: &str, defender: &str, battle_rules: rules::BattleRules| {
+            j.battle(
+                vec![attacker],
+                vec![defender],
+                &battle_rules,
+                &test_win_rules(),
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .explanation()
+        };
+        let explain =
+            |attacker: &str, defender: &str| explain_with(attacker, defender, test_battle_rules());
+
+        assert_eq!(
+            explain("JOLLY", "BIG"),
+            "attacker 5 beats defender 3, needed +2"
+        );
+        assert_eq!(
+            explain("ARTS", "BIG"),
+            "attacker 4 can't beat defender 3, needed +2"
+        );
+        assert_eq!(
+            explain("FAT", "BIG"),
+            "attacker 3 ties defender 3, needed +2"
+        );
+        assert_eq!(explain("XYZ", "BIG"), "no attacking word was valid");
+
+        // Rules that favour the attacker need a negative delta
+        let favoured = || rules::BattleRules { length_delta: -1 };
+        assert_eq!(
+            explain_with("ARTS", "JOLLY", favoured()),
+            "attacker 4 beats defender 5, needed -1"
+        );
+        assert_eq!(
+            explain_with("FAT", "JOLLY", favoured()),
+            "attacker 3 can't beat defender 5, needed -1"
+        );
+    }
+
+    #[test]
+    fn battle_length_delta_boundary() {
+        let j = short_dict();
+        let outcome = |attacker: &str, defenders: Vec<&str>, length_delta: isize| {
+            j.battle(
+                vec![attacker],
+                defenders,
+                &rules::BattleRules { length_delta },
+                &test_win_rules(),
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .outcome
+        };
+
+        // An attacker exactly length_delta letters longer wins, and one letter short of that loses
+        assert_eq!(
+            outcome("JOLLY", vec!["FAT"], 2),
+            Outcome::AttackerWins(vec![0])
+        );
+        assert_eq!(outcome("ARTS", vec!["FAT"], 2), Outcome::DefenderWins);
+        assert_eq!(
+            outcome("ARTS", vec!["FAT"], 1),
+            Outcome::AttackerWins(vec![0])
+        );
+        assert_eq!(outcome("FAT", vec!["BIG"], 1), Outcome::DefenderWins);
+        assert_eq!(
+            outcome("FAT", vec!["BIG"], 0),
+            Outcome::AttackerWins(vec![0])
+        );
+
+        // Each defending word is measured on its own, so only those short enough fall
+        assert_eq!(
+            outcome("JOLLY", vec!["BIG", "SILLY"], 2),
+            Outcome::AttackerWins(vec![0])
+        );
+        assert_eq!(
+            outcome("JOLLY", vec!["BIG", "FOLK"], 1),
+            Outcome::AttackerWins(vec![0, 1])
+        );
+    }
+
+    #[test]
+    fn battle_descriptions() {
+        let j = short_dict();
+        let describe = |attackers: Vec<&str>, defenders: Vec<&str>| {
+            j.battle(
+                attackers,
+                defenders,
+                &test_battle_rules(),
+                &test_win_rules(),
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .describe()
+        };
+
+        assert_eq!(
+            describe(vec!["JOLLY"], vec!["BIG"]),
+            "JOLLY (5) defeated BIG (3)."
+        );
+        assert_eq!(
+            describe(vec!["ARTS"], vec!["BIG"]),
+            "ARTS (4) failed to beat BIG (3)."
+        );
+        assert_eq!(
+            describe(vec!["FAT"], vec!["BIG"]),
+            "FAT (3) tied with BIG (3), so the defence held."
+        );
+        assert_eq!(
+            describe(vec!["XYZ"], vec!["BIG"]),
+            "XYZ rejected — not a word."
+        );
+        assert_eq!(
+            describe(vec!["JOLLY", "FAT"], vec!["BIG", "XYZ"]),
+            "XYZ rejected — not a word.\nJOLLY (5) and FAT (3) defeated BIG (3) and XYZ."
+        );
+    }
+
     // #[test]
     // fn main_dict() {
     //     let j = Judge::default();
@@ -4,10 +4,13 @@ pub mod emojification;
 pub mod error;
 pub mod game;
 pub mod generation;
+pub mod history;
 pub mod judge;
+pub mod match_play;
 pub mod messages;
 pub mod moves;
 pub mod npc;
 pub mod player;
+pub mod replay;
 pub mod reporting;
 pub mod rules;
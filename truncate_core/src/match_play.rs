@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{error::GamePlayError, game::Game};
+
+/// What each finished game adds to the players' match scores
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum MatchScoring {
+    /// The winner of each game scores a point
+    Wins,
+    /// Every player scores the empty land they have walled in by the end of each game,
+    /// as measured by `Board::enclosed_by`
+    Territory,
+}
+
+/// A run of games between the same players, won by whoever first reaches `target` points.
+/// Players are numbered by their seat in the first game, and `seats` follows them through rematches.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Match {
+    pub target: usize,
+    pub scoring: MatchScoring,
+    /// Points for each player, in their order from the first game
+    pub scores: Vec<usize>,
+    /// The match player sitting in each seat of the current game
+    pub seats: Vec<usize>,
+    pub games_played: usize,
+}
+
+impl Match {
+    pub fn new(players: usize, target: usize, scoring: MatchScoring) -> Self {
+        Self {
+            target,
+            scoring,
+            scores: vec![0; players],
+            seats: (0..players).collect(),
+            games_played: 0,
+        }
+    }
+
+    /// A match decided on wins, over at most `games` games between two players
+    pub fn best_of(games: usize) -> Self {
+        Self::new(2, games / 2 + 1, MatchScoring::Wins)
+    }
+
+    /// Moves everyone along one seat, in the same way that rematches rotate who moves first
+    pub fn rotate_seats(&mut self) {
+        self.seats.rotate_left(1);
+    }
+
+    /// Adds a finished game's points to the match scores. Drawn games still count as played,
+    /// but only score points under territory scoring.
+    pub fn record_game(&mut self, game: &Game) -> Result<(), GamePlayError> {
+        if self.winner().is_some() {
+            return Err(GamePlayError::MatchOver);
+        }
+        if !game.is_over() {
+            return Err(GamePlayError::UnfinishedGame);
+        }
+
+        match self.scoring {
+            // Nobody scores from a drawn game
+            MatchScoring::Wins => {
+                if let Some(winner) = game.winner {
+                    let player = *self
+                        .seats
+                        .get(winner)
+                        .ok_or(GamePlayError::NonExistentPlayer { index: winner })?;
+                    self.scores[player] += 1;
+                }
+            }
+            MatchScoring::Territory => {
+                for (seat, player) in self.seats.iter().enumerate() {
+                    self.scores[*player] += game.board.enclosed_by(seat).len();
+                }
+            }
+        }
+        self.games_played += 1;
+
+        Ok(())
+    }
+
+    /// The player who has reached the target, if any.
+    /// Players who reach it on the same points keep playing until one pulls ahead.
+    pub fn winner(&self) -> Option<usize> {
+        let best = *self.scores.iter().max()?;
+        if best < self.target {
+            return None;
+        }
+
+        let mut leaders = self.scores.iter().enumerate().filter(|(_, s)| **s == best);
+        match (leaders.next(), leaders.next()) {
+            (Some((player, _)), None) => Some(player),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{board::Board, reporting::GameEnd, rules::GameRules};
+
+    use super::*;
+
+    fn finished_game(winner: usize) -> Game {
+        let mut game = Game::new(9, 9, None, GameRules::generation(0));
+        game.winner = Some(winner);
+        game
+    }
+
+    #[test]
+    fn best_of_three() {
+        let mut series = Match::best_of(3);
+        assert_eq!(series.target, 2);
+        assert_eq!(
+            series.record_game(&Game::new(9, 9, None, GameRules::generation(0))),
+            Err(GamePlayError::UnfinishedGame)
+        );
+
+        // The first player wins the first game, then the second player wins
+        // from the first seat once the players have swapped for the rematch
+        series.record_game(&finished_game(0)).unwrap();
+        series.rotate_seats();
+        series.record_game(&finished_game(0)).unwrap();
+        assert_eq!(series.scores, vec![1, 1]);
+        assert_eq!(series.winner(), None);
+
+        series.rotate_seats();
+        series.record_game(&finished_game(1)).unwrap();
+        assert_eq!(series.scores, vec![1, 2]);
+        assert_eq!(series.games_played, 3);
+        assert_eq!(series.winner(), Some(1));
+
+        assert_eq!(
+            series.record_game(&finished_game(0)),
+            Err(GamePlayError::MatchOver)
+        );
+    }
+
+    #[test]
+    fn territory_scoring() {
+        let mut series = Match::new(2, 3, MatchScoring::Territory);
+        let mut game = finished_game(1);
+        game.board = Board::from_string(
+            "~~ ~~ |0 ~~ ~~ ~~ ~~\n\
+             __ A0 A0 A0 __ __ __\n\
+             __ A0 __ A0 __ B1 __\n\
+             __ A0 __ A0 B1 __ B1\n\
+             __ A0 A0 A0 __ B1 __\n\
+             ~~ ~~ ~~ |1 ~~ ~~ ~~",
+        );
+
+        // Territory counts whoever won the game
+        series.record_game(&game).unwrap();
+        assert_eq!(series.scores, vec![2, 1]);
+        assert_eq!(series.winner(), None);
+
+        // Drawn games still score territory
+        game.winner = None;
+        game.game_end = Some(GameEnd::Draw);
+        series.record_game(&game).unwrap();
+        assert_eq!(series.scores, vec![4, 2]);
+        assert_eq!(series.games_played, 2);
+        assert_eq!(series.winner(), Some(0));
+    }
+
+    #[test]
+    fn drawn_games_count_without_scoring_wins() {
+        let mut series = Match::best_of(3);
+        let mut game = Game::new(9, 9, None, GameRules::generation(0));
+        game.game_end = Some(GameEnd::Stalemate { turns: 6 });
+
+        series.record_game(&game).unwrap();
+        assert_eq!(series.scores, vec![0, 0]);
+        assert_eq!(series.games_played, 1);
+        assert_eq!(series.winner(), None);
+    }
+}
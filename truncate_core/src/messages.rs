@@ -7,11 +7,12 @@ use time::Duration;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    board::{Board, Coordinate},
+    board::{Board, Coordinate, ViewTransform},
     game::Game,
     moves::Move,
     player::{Hand, Player},
-    reporting::{Change, WordMeaning},
+    reporting::{Change, GameEnd, WordMeaning},
+    rules::GameRules,
 };
 
 pub type RoomCode = String;
@@ -36,6 +37,9 @@ pub enum PlayerMessage {
     NewGame {
         player_name: String,
         effective_day: u32,
+        /// Private rooms are left out of the room listing
+        #[serde(default)]
+        private: bool,
     },
     JoinGame(RoomCode, String, Option<TruncateToken>),
     RejoinGame(TruncateToken),
@@ -44,8 +48,11 @@ pub enum PlayerMessage {
     StartGame,
     Resign,
     Place(Coordinate, char),
+    PlaceMany(Vec<(Coordinate, char)>),
     Swap(Coordinate, Coordinate),
+    Exchange(Vec<usize>),
     Rematch,
+    DeclineRematch,
     Pause,
     Unpause,
     RequestDefinitions(Vec<String>),
@@ -73,6 +80,7 @@ pub enum PlayerMessage {
     },
     RequestStats(TruncateToken),
     LoadReplay(String),
+    ListRooms,
     MarkChangelogRead(String),
     GenericEvent {
         name: String,
@@ -86,6 +94,7 @@ impl fmt::Display for PlayerMessage {
             PlayerMessage::NewGame {
                 player_name,
                 effective_day,
+                ..
             } => write!(
                 f,
                 "Create a new game as player {player_name} at day {effective_day}"
@@ -104,8 +113,21 @@ impl fmt::Display for PlayerMessage {
             PlayerMessage::StartGame => write!(f, "Start the game"),
             PlayerMessage::Resign => write!(f, "Resign"),
             PlayerMessage::Place(coord, tile) => write!(f, "Place {} at {}", tile, coord),
+            PlayerMessage::PlaceMany(placements) => write!(
+                f,
+                "Place {}",
+                placements
+                    .iter()
+                    .map(|(coord, tile)| format!("{} at {}", tile, coord))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
             PlayerMessage::Swap(a, b) => write!(f, "Swap the tiles at {} and {}", a, b),
+            PlayerMessage::Exchange(indices) => {
+                write!(f, "Exchange {} tiles with the bag", indices.len())
+            }
             PlayerMessage::Rematch => write!(f, "Rematch!"),
+            PlayerMessage::DeclineRematch => write!(f, "No rematch"),
             PlayerMessage::Pause => write!(f, "Pause!"),
             PlayerMessage::Unpause => write!(f, "Unpause!"),
             PlayerMessage::RequestDefinitions(words) => write!(f, "Get definition of {words:?}"),
@@ -129,6 +151,7 @@ impl fmt::Display for PlayerMessage {
             }
             PlayerMessage::RequestStats(_token) => write!(f, "Requesting daily puzzle stats!"),
             PlayerMessage::LoadReplay(id) => write!(f, "Requesting the replay for {id}!"),
+            PlayerMessage::ListRooms => write!(f, "Requesting the list of open rooms"),
             PlayerMessage::MarkChangelogRead(id) => write!(f, "Marked changelog {id} as read"),
             PlayerMessage::GenericEvent { name } => write!(f, "Tracking a {name} event"),
         }
@@ -179,6 +202,15 @@ pub struct GameStateMessage {
     pub game_ends_at: Option<u64>,
     pub remaining_turns: Option<u64>,
     pub paused: bool,
+    /// How the game was won, once it has ended
+    #[serde(default)]
+    pub game_end: Option<GameEnd>,
+    /// How many enemy tiles the player can currently see, when fog of war is hiding the rest
+    #[serde(default)]
+    pub visible_enemy_tiles: Option<usize>,
+    /// How the client should turn the board to show it from the player's seat
+    #[serde(default)]
+    pub view: ViewTransform,
 }
 
 impl fmt::Display for GameStateMessage {
@@ -198,6 +230,16 @@ impl fmt::Display for GameStateMessage {
     }
 }
 
+/// A room as shown in the lobby browser
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomSummary {
+    pub room_code: RoomCode,
+    pub player_count: usize,
+    pub rules: GameRules,
+    /// Whether new players can still join, i.e. the game hasn't started
+    pub joinable: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DailyStateMessage {
     pub puzzle_day: u32,
@@ -269,7 +311,8 @@ pub enum GameMessage {
     StartedGame(GameStateMessage),
     GameTimingUpdate(GameStateMessage),
     GameUpdate(GameStateMessage),
-    GameEnd(GameStateMessage, PlayerNumber),
+    /// The final state of the game, and its winner unless it ended in a draw
+    GameEnd(GameStateMessage, Option<PlayerNumber>),
     GameError(RoomCode, PlayerNumber, String),
     GenericError(String),
     SupplyDefinitions(Vec<(String, Option<Vec<WordMeaning>>)>),
@@ -280,6 +323,9 @@ pub enum GameMessage {
     ResumeDailyPuzzle(DailyStateMessage, Option<DailyStateMessage>), // (latest, best)
     DailyStats(DailyStats),
     LoadDailyReplay(DailyStateMessage),
+    RoomList(Vec<RoomSummary>),
+    RematchRequested(PlayerNumber),
+    RematchDeclined(PlayerNumber),
 }
 
 impl fmt::Display for GameMessage {
@@ -315,9 +361,12 @@ impl fmt::Display for GameMessage {
             GameMessage::StartedGame(game) => write!(f, "Started game:\n{}", game),
             GameMessage::GameTimingUpdate(game) => write!(f, "Update to timing:\n{}", game),
             GameMessage::GameUpdate(game) => write!(f, "Update to game:\n{}", game),
-            GameMessage::GameEnd(game, winner) => {
+            GameMessage::GameEnd(game, Some(winner)) => {
                 write!(f, "Conclusion of game, winner was {}:\n{}", winner, game)
             }
+            GameMessage::GameEnd(game, None) => {
+                write!(f, "Conclusion of game, which was a draw:\n{}", game)
+            }
             GameMessage::GameError(_, _, msg) => write!(f, "Error in game: {}", msg),
             GameMessage::GenericError(msg) => write!(f, "Generic error: {}", msg),
             GameMessage::SupplyDefinitions(_) => {
@@ -331,6 +380,13 @@ impl fmt::Display for GameMessage {
             }
             GameMessage::DailyStats(stats) => write!(f, "Stats for {} days", stats.days.len()),
             GameMessage::LoadDailyReplay(puzzle) => write!(f, "Loading puzzle replay:\n{}", puzzle),
+            GameMessage::RoomList(rooms) => write!(f, "Listing {} open rooms", rooms.len()),
+            GameMessage::RematchRequested(player) => {
+                write!(f, "Player {} would like a rematch", player)
+            }
+            GameMessage::RematchDeclined(player) => {
+                write!(f, "Player {} declined the rematch", player)
+            }
         }
     }
 }
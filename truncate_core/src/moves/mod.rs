@@ -15,6 +15,14 @@ pub enum Move {
         player: usize,
         positions: [Coordinate; 2],
     },
+    PlaceMany {
+        player: usize,
+        placements: Vec<(Coordinate, char)>,
+    },
+    Exchange {
+        player: usize,
+        indices: Vec<usize>,
+    },
 }
 
 impl PartialEq for Move {
@@ -46,6 +54,26 @@ impl PartialEq for Move {
                     && (l_positions == r_positions
                         || (l_positions[0] == r_positions[1] && l_positions[1] == r_positions[0]))
             }
+            (
+                Self::PlaceMany {
+                    player: l_player,
+                    placements: l_placements,
+                },
+                Self::PlaceMany {
+                    player: r_player,
+                    placements: r_placements,
+                },
+            ) => l_player == r_player && l_placements == r_placements,
+            (
+                Self::Exchange {
+                    player: l_player,
+                    indices: l_indices,
+                },
+                Self::Exchange {
+                    player: r_player,
+                    indices: r_indices,
+                },
+            ) => l_player == r_player && l_indices == r_indices,
             _ => false,
         }
     }
@@ -58,10 +86,16 @@ mod tests {
     use crate::error::GamePlayError;
     use crate::game::Game;
     use crate::judge::Judge;
-    use crate::player::Player;
+    use crate::player::{Hand, Player};
     use crate::reporting::*;
     use crate::reporting::{BoardChange, BoardChangeAction};
-    use crate::rules::GameRules;
+    use crate::rules::{
+        CapturedTileRule, GameRules, Handicap, OvertimeRule, SwapPenalty, Swapping, TieBreaker,
+        Timing, Truncation, TruncationRecovery, Visibility, WordValidity,
+    };
+
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use time::Duration;
 
     use super::super::bag::tests as TileUtils;
     use super::*;
@@ -647,6 +681,175 @@ mod tests {
         );
     }
 
+    #[test]
+    fn truncated_tiles_return_to_hand() {
+        let b = Board::from_string(
+            "__ S0 X0 |0 __\n\
+             __ T0 __ __ __\n\
+             __ R0 __ X1 __\n\
+             __ __ B1 X1 __\n\
+             __ __ I1 __ __\n\
+             __ __ G1 |1 __",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let mut players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+        // Leave room in the hand for a recovered tile
+        players[1].hand.0.pop();
+        let mut test_bag = bag.clone();
+
+        let mut rules = GameRules::generation(0);
+        rules.truncation_recovery = TruncationRecovery::ToHand;
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            ..Game::new_legacy(3, 1, None, rules)
+        };
+
+        let changes = game
+            .make_move(
+                Move::Place {
+                    player: 0,
+                    tile: 'A',
+                    position: Coordinate { x: 1, y: 3 },
+                },
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        // The defeated tiles still go back in the bag
+        for letter in ['B', 'X'] {
+            test_bag.return_tile(letter);
+        }
+        assert_eq!(game.bag, test_bag);
+
+        // While the X1 cut off from its artifact goes back to its owner
+        assert_eq!(game.players[1].hand.len(), 7);
+        assert_eq!(game.players[1].hand.iter().last(), Some(&'X'));
+        assert!(changes.contains(&Change::Hand(HandChange {
+            player: 1,
+            removed: vec![],
+            added: vec!['X'],
+        })));
+    }
+
+    #[test]
+    fn truncated_tiles_overflow_to_bag() {
+        let b = Board::from_string(
+            "__ S0 X0 |0 __\n\
+             __ T0 __ __ __\n\
+             __ R0 __ X1 __\n\
+             __ __ B1 X1 __\n\
+             __ __ I1 __ __\n\
+             __ __ G1 |1 __",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+        let mut test_bag = bag.clone();
+
+        let mut rules = GameRules::generation(0);
+        rules.truncation_recovery = TruncationRecovery::ToHand;
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            ..Game::new_legacy(3, 1, None, rules)
+        };
+
+        let changes = game
+            .make_move(
+                Move::Place {
+                    player: 0,
+                    tile: 'A',
+                    position: Coordinate { x: 1, y: 3 },
+                },
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        // A full hand can't take the truncated X1, so it stays in the bag
+        for letter in ['B', 'X', 'X'] {
+            test_bag.return_tile(letter);
+        }
+        assert_eq!(game.bag, test_bag);
+        assert_eq!(game.players[1].hand.len(), 7);
+        assert!(!changes
+            .iter()
+            .any(|change| matches!(change, Change::Hand(HandChange { player: 1, .. }))));
+    }
+
+    #[test]
+    fn resolve_without_truncation() {
+        let b = Board::from_string(
+            "__ S0 X0 |0 __\n\
+             __ T0 __ __ __\n\
+             __ R0 __ X1 __\n\
+             __ __ B1 X1 __\n\
+             __ __ I1 __ __\n\
+             __ __ G1 |1 __",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+        let mut test_bag = bag.clone();
+
+        let mut rules = GameRules::generation(0);
+        rules.truncation = Truncation::None;
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            ..Game::new_legacy(3, 1, None, rules)
+        };
+
+        game.make_move(
+            Move::Place {
+                player: 0,
+                tile: 'A',
+                position: Coordinate { x: 1, y: 3 },
+            },
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        // The battle is still fought, but only the defeated tiles go back in the bag
+        for letter in ['B', 'X'] {
+            test_bag.return_tile(letter);
+        }
+        assert_eq!(game.bag, test_bag);
+
+        // The remaining X1 is cut off from its artifact, but survives the turn
+        assert_eq!(
+            game.board.to_string(),
+            "__ S0 X0 |0 __\n\
+             __ T0 __ __ __\n\
+             __ R0 __ X1 __\n\
+             __ A0 __ __ __\n\
+             __ __ I1 __ __\n\
+             __ __ G1 |1 __",
+        );
+    }
+
     #[test]
     fn resolve_explosion() {
         let b = Board::from_string(
@@ -754,6 +957,17 @@ mod tests {
             }
         );
         assert_eq!(game.winner, Some(0));
+        assert_eq!(
+            game.game_end,
+            Some(GameEnd::Destination {
+                word: vec![
+                    Coordinate { x: 0, y: 5 },
+                    Coordinate { x: 0, y: 4 },
+                    Coordinate { x: 0, y: 3 },
+                ],
+                text: "AND".into()
+            })
+        );
     }
 
     #[test]
@@ -933,6 +1147,99 @@ mod tests {
         assert_eq!(game.winner, Some(0));
     }
 
+    #[test]
+    fn report_win_after_battle() {
+        let b = Board::from_string(
+            "__ __ S0 |0 __\n\
+             __ __ T0 __ __\n\
+             __ A0 R0 __ __\n\
+             D0 B0 __ X1 __\n\
+             N0 __ __ X1 __\n\
+             __ X1 I1 G1 __\n\
+             #1 #1 |1 #1 #1",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            ..Game::new_legacy(3, 1, None, GameRules::generation(0))
+        };
+        game.start();
+
+        let report = game
+            .play_turn_with_report(
+                Move::Place {
+                    player: 0,
+                    tile: 'A',
+                    position: Coordinate { x: 0, y: 5 },
+                },
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            report.placed,
+            vec![BoardChange {
+                detail: BoardChangeDetail {
+                    square: Square::Occupied {
+                        player: 0,
+                        tile: 'A',
+                        validity: SquareValidity::Unknown,
+                        foggy: false
+                    },
+                    coordinate: Coordinate { x: 0, y: 5 },
+                },
+                action: BoardChangeAction::Added,
+            }]
+        );
+        assert_eq!(report.battles.len(), 1);
+        assert_eq!(
+            report.battles[0].outcome,
+            crate::judge::Outcome::AttackerWins(vec![0, 1])
+        );
+        // Capturing XIG cuts off player 1's remaining X tiles
+        let mut truncated: Vec<_> = report
+            .truncated
+            .iter()
+            .map(|change| change.detail.coordinate)
+            .collect();
+        truncated.sort_by_key(|c| (c.y, c.x));
+        assert_eq!(
+            truncated,
+            vec![Coordinate { x: 3, y: 3 }, Coordinate { x: 3, y: 4 }]
+        );
+        assert_eq!(report.drawn, vec!['A']);
+        assert_eq!(report.winner, Some(0));
+        assert!(matches!(
+            report.game_end,
+            Some(GameEnd::Destination { ref text, .. }) if text == "AND"
+        ));
+
+        // Nothing more happens once the game is over
+        assert!(game
+            .play_turn_with_report(
+                Move::Place {
+                    player: 1,
+                    tile: 'A',
+                    position: Coordinate { x: 4, y: 5 },
+                },
+                None,
+                None,
+                None,
+            )
+            .is_err());
+    }
+
     #[test]
     fn resolve_win_via_explosion() {
         let b = Board::from_string(
@@ -1050,14 +1357,16 @@ mod tests {
         assert_eq!(game.winner, Some(0));
     }
 
-    #[test]
-    fn resolve_noop() {
+    fn play_to_turn_cap(tie_breakers: Vec<TieBreaker>) -> Game {
         let b = Board::from_string(
-            "~~ |0 __ ~~ ~~\n\
-             __ __ __ __ __\n\
-             __ __ __ __ __\n\
-             __ __ __ __ __\n\
-             ~~ ~~ T1 |1 ~~",
+            "#0 ~~ |0 ~~ ~~ ~~ ~~\n\
+             ~~ A0 A0 A0 __ __ ~~\n\
+             ~~ A0 __ A0 __ __ ~~\n\
+             ~~ A0 A0 A0 __ __ ~~\n\
+             ~~ __ __ __ __ __ ~~\n\
+             ~~ B1 B1 B1 B1 B1 ~~\n\
+             ~~ B1 B1 B1 B1 B1 ~~\n\
+             #1 ~~ |1 ~~ ~~ ~~ ~~",
         );
         let mut bag = TileUtils::trivial_bag();
         let players = vec![
@@ -1065,53 +1374,1150 @@ mod tests {
             Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
         ];
 
+        let mut rules = GameRules::generation(0);
+        rules.battle_delay = 0;
+        rules.max_turns = Some(2);
+        rules.tie_breakers = tie_breakers;
         let mut game = Game {
             board: b,
             bag,
             players,
             player_turn_count: vec![0, 0],
             judge: short_dict(),
-            ..Game::new_legacy(3, 1, None, GameRules::generation(0))
+            ..Game::new_legacy(3, 1, None, rules)
         };
+        game.start();
 
-        game.make_move(
+        let first = game.play_turn(
             Move::Place {
                 player: 0,
                 tile: 'A',
-                position: Coordinate { x: 2, y: 0 },
+                position: Coordinate { x: 4, y: 1 },
+            },
+            None,
+            None,
+            None,
+        );
+        assert_eq!(first, Ok(None));
+
+        game.play_turn(
+            Move::Place {
+                player: 1,
+                tile: 'A',
+                position: Coordinate { x: 5, y: 4 },
             },
             None,
             None,
             None,
         )
         .unwrap();
+        game
+    }
 
+    #[test]
+    fn resolve_turn_cap_tie_breakers() {
+        // Both towns are cut off by water, so the proximity metric can't split the players.
+        // Player 0 has walled in a square of territory, while player 1 has more tiles.
         assert_eq!(
-            game.board.to_string(),
-            "~~ |0 A0 ~~ ~~\n\
-             __ __ __ __ __\n\
-             __ __ __ __ __\n\
-             __ __ __ __ __\n\
-             ~~ ~~ T1 |1 ~~",
-        )
+            play_to_turn_cap(vec![TieBreaker::Territory, TieBreaker::TileCount]).winner,
+            Some(0)
+        );
+        assert_eq!(
+            play_to_turn_cap(vec![TieBreaker::TileCount, TieBreaker::Territory]).winner,
+            Some(1)
+        );
+
+        // Without any tie breakers the game ends level, rather than going to player 0
+        let mut drawn = play_to_turn_cap(vec![]);
+        assert_eq!(drawn.winner, None);
+        assert_eq!(drawn.game_end, Some(GameEnd::Draw));
+        assert!(drawn.is_over());
+        assert!(drawn
+            .play_turn(
+                Move::Place {
+                    player: 0,
+                    tile: 'A',
+                    position: Coordinate { x: 4, y: 2 },
+                },
+                None,
+                None,
+                None,
+            )
+            .is_err());
     }
 
     #[test]
-    fn resolve_with_smaller_attack_dict() {
+    fn overtime_free_wildcards() {
+        static NOW: AtomicU64 = AtomicU64::new(1_000);
+
         let b = Board::from_string(
-            "__ S0 X0 |0 __\n\
-             __ T0 __ __ __\n\
-             __ R0 __ __ __\n\
-             __ __ A1 __ __\n\
-             __ __ R1 __ __\n\
-             __ __ T1 __ __\n\
-             __ __ S1 |1 __",
-        );
-        let mut bag = TileUtils::trivial_bag();
+            "~~ ~~ |0 ~~ ~~\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let allowance = Some(Duration::seconds(10));
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, allowance, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, allowance, (0, 0, 0)),
+        ];
+
+        let mut rules = GameRules::generation(0);
+        rules.battle_delay = 0;
+        rules.timing = Timing::PerPlayer {
+            time_allowance: 10,
+            overtime_rule: OvertimeRule::FreeWildcard { period: 60 },
+        };
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            clock: || NOW.load(Ordering::SeqCst),
+            ..Game::new_legacy(3, 1, None, rules)
+        };
+        game.start();
+
+        let wildcards = |game: &Game, player: usize| {
+            game.players[player]
+                .hand
+                .iter()
+                .filter(|t| **t == '*')
+                .count()
+        };
+        let place = |game: &mut Game, player: usize, x: usize, y: usize| {
+            game.play_turn(
+                Move::Place {
+                    player,
+                    tile: 'A',
+                    position: Coordinate { x, y },
+                },
+                None,
+                None,
+                None,
+            )
+        };
+
+        // Player 0 takes 140 seconds on a 10 second allowance, spending 130 seconds in overtime
+        NOW.store(1_140, Ordering::SeqCst);
+        assert_eq!(place(&mut game, 0, 2, 1), Ok(None));
+        assert_eq!(wildcards(&game, 0), 2);
+        assert_eq!(wildcards(&game, 1), 0);
+        assert_eq!(
+            game.recent_changes
+                .iter()
+                .filter(|c| matches!(c, Change::Hand(h) if h.player == 0 && h.added == vec!['*']))
+                .count(),
+            2
+        );
+
+        assert_eq!(place(&mut game, 1, 2, 3), Ok(None));
+        assert_eq!(wildcards(&game, 1), 0);
+
+        // Only newly completed periods hand out more wildcards
+        NOW.store(1_190, Ordering::SeqCst);
+        assert_eq!(place(&mut game, 0, 1, 1), Ok(None));
+        assert_eq!(wildcards(&game, 0), 3);
+
+        // A zero period hands out nothing, however long the player runs over
+        game.rules.timing = Timing::PerPlayer {
+            time_allowance: 10,
+            overtime_rule: OvertimeRule::FreeWildcard { period: 0 },
+        };
+        assert_eq!(place(&mut game, 1, 3, 3), Ok(None));
+        NOW.store(1_400, Ordering::SeqCst);
+        assert_eq!(place(&mut game, 0, 3, 1), Ok(None));
+        assert_eq!(wildcards(&game, 0), 3);
+    }
+
+    #[test]
+    fn per_turn_timeout() {
+        static NOW: AtomicU64 = AtomicU64::new(1_000);
+
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let allowance = Some(Duration::seconds(30));
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, allowance, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, allowance, (0, 0, 0)),
+        ];
+
+        let mut rules = GameRules::generation(0);
+        rules.battle_delay = 0;
+        rules.timing = Timing::PerTurn { time_allowance: 30 };
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            clock: || NOW.load(Ordering::SeqCst),
+            ..Game::new_legacy(3, 1, None, rules)
+        };
+        game.start();
+        assert_eq!(game.turn_deadline(), Some(1_030));
+
+        let place = |game: &mut Game, player: usize, x: usize, y: usize| {
+            game.play_turn(
+                Move::Place {
+                    player,
+                    tile: 'A',
+                    position: Coordinate { x, y },
+                },
+                None,
+                None,
+                None,
+            )
+        };
+
+        // Nothing happens while the turn is still within its allowance
+        NOW.store(1_029, Ordering::SeqCst);
+        assert!(game.tick().is_empty());
+        assert_eq!(game.next(), Some(0));
+
+        // Once the allowance has passed the turn moves on
+        NOW.store(1_031, Ordering::SeqCst);
+        let changes = game.tick();
+        assert!(matches!(
+            changes.as_slice(),
+            [Change::Time(TimeChange { player: 0, .. })]
+        ));
+        assert_eq!(game.next(), Some(1));
+        assert!(game.tick().is_empty());
+        assert!(place(&mut game, 0, 2, 1).is_err());
+
+        // The next player gets a fresh allowance from the moment the turn passed
+        assert_eq!(game.turn_deadline(), Some(1_061));
+        NOW.store(1_060, Ordering::SeqCst);
+        assert_eq!(place(&mut game, 1, 2, 3), Ok(None));
+        assert_eq!(game.players[1].time_remaining, allowance);
+
+        // Moves that arrive too late are turned away
+        NOW.store(1_100, Ordering::SeqCst);
+        assert!(place(&mut game, 0, 2, 1).is_err());
+        assert_eq!(game.next(), Some(1));
+    }
+
+    #[test]
+    fn per_turn_handicap() {
+        static NOW: AtomicU64 = AtomicU64::new(1_000);
+
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let (allowance, handicapped) = (Some(Duration::seconds(30)), Some(Duration::seconds(45)));
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, allowance, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, handicapped, (0, 0, 0)),
+        ];
+
+        let mut rules = GameRules::generation(0);
+        rules.battle_delay = 0;
+        rules.timing = Timing::PerTurn { time_allowance: 30 };
+        rules.handicaps = vec![
+            Handicap::default(),
+            Handicap {
+                extra_time: 15,
+                ..Handicap::default()
+            },
+        ];
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            clock: || NOW.load(Ordering::SeqCst),
+            ..Game::new_legacy(3, 1, None, rules)
+        };
+        game.start();
+        assert_eq!(game.turn_deadline(), Some(1_030));
+
+        NOW.store(1_010, Ordering::SeqCst);
+        let place = Move::Place {
+            player: 0,
+            tile: 'A',
+            position: Coordinate { x: 2, y: 1 },
+        };
+        assert_eq!(game.play_turn(place, None, None, None), Ok(None));
+
+        // The handicapped player's turns run for their longer allowance
+        assert_eq!(game.turn_deadline(), Some(1_055));
+        NOW.store(1_050, Ordering::SeqCst);
+        assert!(game.tick().is_empty());
+        let place = Move::Place {
+            player: 1,
+            tile: 'A',
+            position: Coordinate { x: 2, y: 3 },
+        };
+        assert_eq!(game.play_turn(place, None, None, None), Ok(None));
+        assert_eq!(game.players[1].time_remaining, handicapped);
+    }
+
+    #[test]
+    fn periodic_turn_delay() {
+        static NOW: AtomicU64 = AtomicU64::new(1_000);
+
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+
+        let mut rules = GameRules::generation(0);
+        rules.battle_delay = 0;
+        rules.timing = Timing::Periodic {
+            turn_delay: 10,
+            total_time_allowance: 600,
+        };
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            clock: || NOW.load(Ordering::SeqCst),
+            ..Game::new_legacy(3, 1, None, rules)
+        };
+        game.start();
+
+        let place = |game: &mut Game, player: usize, x: usize, y: usize| {
+            game.play_turn(
+                Move::Place {
+                    player,
+                    tile: 'A',
+                    position: Coordinate { x, y },
+                },
+                None,
+                None,
+                None,
+            )
+        };
+
+        assert_eq!(place(&mut game, 0, 2, 1), Ok(None));
+
+        // Moving again before the delay is up is rejected
+        NOW.store(1_004, Ordering::SeqCst);
+        assert_eq!(
+            place(&mut game, 0, 1, 1),
+            Err(GamePlayError::TooSoon { remaining: 6 }.to_string())
+        );
+        assert_eq!(game.board.get(Coordinate::new(1, 1)), Ok(Square::land()));
+
+        // The delay only applies to the player who moved
+        assert_eq!(place(&mut game, 1, 2, 3), Ok(None));
+
+        NOW.store(1_010, Ordering::SeqCst);
+        assert_eq!(place(&mut game, 0, 1, 1), Ok(None));
+    }
+
+    #[test]
+    fn swap_time_penalties() {
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ A0 B0 __ __\n\
+             __ __ __ __ __\n\
+             __ __ A1 B1 __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let allowance = Some(Duration::seconds(600));
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, allowance, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, allowance, (0, 0, 0)),
+        ];
+
+        let mut rules = GameRules::generation(0);
+        rules.battle_delay = 0;
+        rules.timing = Timing::PerPlayer {
+            time_allowance: 600,
+            overtime_rule: OvertimeRule::Elimination,
+        };
+        rules.swapping = Swapping::Contiguous(SwapPenalty::Time {
+            swap_threshold: 2,
+            penalties: vec![5, 10, 20, 240],
+        });
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            clock: || 1_000,
+            ..Game::new_legacy(3, 1, None, rules)
+        };
+        game.start();
+
+        let mut lost = vec![];
+        for _ in 0..7 {
+            let before = game.players[0].time_remaining.unwrap();
+            let swap = Move::Swap {
+                player: 0,
+                positions: [Coordinate { x: 1, y: 1 }, Coordinate { x: 2, y: 1 }],
+            };
+            assert_eq!(game.play_turn(swap, None, None, None), Ok(None));
+            lost.push((before - game.players[0].time_remaining.unwrap()).whole_seconds());
+
+            let swap = Move::Swap {
+                player: 1,
+                positions: [Coordinate { x: 2, y: 3 }, Coordinate { x: 3, y: 3 }],
+            };
+            assert_eq!(game.play_turn(swap, None, None, None), Ok(None));
+        }
+
+        // The third and fourth swaps step through the penalties, and the seventh saturates at the last
+        assert_eq!(lost, vec![0, 0, 5, 10, 20, 240, 240]);
+    }
+
+    #[test]
+    fn reclaim_players_eliminated_on_time() {
+        let eliminate = |reclaim: bool| {
+            let b = Board::from_string(
+                "~~ ~~ |0 ~~ ~~\n\
+                 __ A0 B0 __ __\n\
+                 __ __ __ __ __\n\
+                 __ __ A1 B1 __\n\
+                 ~~ ~~ |1 ~~ ~~",
+            );
+            let mut bag = TileUtils::trivial_bag();
+            let players = vec![
+                Player::new("A".into(), 0, 7, &mut bag, Some(Duration::ZERO), (0, 0, 0)),
+                Player::new(
+                    "B".into(),
+                    1,
+                    7,
+                    &mut bag,
+                    Some(Duration::seconds(600)),
+                    (0, 0, 0),
+                ),
+            ];
+
+            let mut rules = GameRules::generation(0);
+            rules.timing = Timing::PerPlayer {
+                time_allowance: 600,
+                overtime_rule: OvertimeRule::Elimination,
+            };
+            rules.reclaim_eliminated = reclaim;
+            let mut game = Game {
+                board: b,
+                bag,
+                players,
+                player_turn_count: vec![0, 0],
+                judge: short_dict(),
+                clock: || 1_000,
+                ..Game::new_legacy(3, 1, None, rules)
+            };
+            game.calculate_game_over(None);
+            assert_eq!(game.winner, Some(1));
+            game
+        };
+
+        // Left alone, the eliminated player's tiles and artifact stay put
+        let game = eliminate(false);
+        assert!(game.recent_changes.is_empty());
+        assert!(matches!(
+            game.board.get(Coordinate::new(2, 0)),
+            Ok(Square::Artifact { player: 0, .. })
+        ));
+
+        let game = eliminate(true);
+        assert_eq!(game.board.get(Coordinate::new(2, 0)), Ok(Square::water()));
+        assert_eq!(game.board.get(Coordinate::new(1, 1)), Ok(Square::water()));
+        assert!(game.recent_changes.iter().any(|change| matches!(
+            change,
+            Change::Board(BoardChange {
+                detail: BoardChangeDetail {
+                    square: Square::Artifact { player: 0, .. },
+                    ..
+                },
+                action: BoardChangeAction::Neutralized,
+            })
+        )));
+    }
+
+    #[test]
+    fn exchange_tiles() {
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let mut bag = TileBag::explicit("ABCDEFGHIJKLMNOPQRST".chars().collect(), Some(1));
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            ..Game::new_legacy(3, 1, None, GameRules::generation(0))
+        };
+        game.start();
+
+        let exchange = |game: &mut Game, player: usize, indices: Vec<usize>| {
+            game.play_turn(Move::Exchange { player, indices }, None, None, None)
+        };
+
+        assert_eq!(
+            exchange(&mut game, 0, vec![0, 2]),
+            Err(GamePlayError::NoExchanging.to_string())
+        );
+
+        game.rules.tile_exchange = true;
+        let hand = game.players[0].hand.clone();
+        assert_eq!(game.bag.remaining(), 6);
+        assert_eq!(exchange(&mut game, 0, vec![0, 2]), Ok(None));
+
+        // Two tiles went back for two new ones, and the turn passed on
+        assert_eq!(game.players[0].hand.len(), 7);
+        assert_eq!(game.bag.remaining(), 6);
+        assert_ne!(game.players[0].hand.0[0], hand.0[0]);
+        assert_ne!(game.players[0].hand.0[2], hand.0[2]);
+        assert_eq!(game.players[0].hand.0[1], hand.0[1]);
+        assert!(matches!(
+            game.recent_changes.as_slice(),
+            [Change::Hand(HandChange { player: 0, removed, .. })] if *removed == vec![hand.0[0], hand.0[2]]
+        ));
+        assert_eq!(game.turn_count, 1);
+        assert_eq!(game.next(), Some(1));
+
+        assert_eq!(
+            exchange(&mut game, 1, vec![3, 3]),
+            Err(GamePlayError::InvalidHandIndex { index: 3 }.to_string())
+        );
+        assert_eq!(
+            exchange(&mut game, 1, vec![7]),
+            Err(GamePlayError::InvalidHandIndex { index: 7 }.to_string())
+        );
+        assert_eq!(game.next(), Some(1));
+    }
+
+    #[test]
+    fn swap_stalemate() {
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ A0 B0 __ __\n\
+             __ __ __ __ #0\n\
+             #1 __ __ __ __\n\
+             __ __ A1 B1 __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+
+        let mut rules = GameRules::generation(0);
+        rules.battle_delay = 0;
+        rules.swapping = Swapping::Contiguous(SwapPenalty::Disallowed { allowed_swaps: 10 });
+        rules.max_consecutive_swaps = Some(2);
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            ..Game::new_legacy(3, 1, None, rules)
+        };
+        game.start();
+
+        let swap = |game: &mut Game, player: usize| {
+            let positions = match player {
+                0 => [Coordinate { x: 1, y: 1 }, Coordinate { x: 2, y: 1 }],
+                _ => [Coordinate { x: 2, y: 4 }, Coordinate { x: 3, y: 4 }],
+            };
+            game.play_turn(Move::Swap { player, positions }, None, None, None)
+        };
+
+        assert_eq!(swap(&mut game, 0), Ok(None));
+        assert_eq!(swap(&mut game, 1), Ok(None));
+
+        // Placing a tile breaks the run of swaps
+        let place = Move::Place {
+            player: 0,
+            tile: 'A',
+            position: Coordinate { x: 3, y: 1 },
+        };
+        assert_eq!(game.play_turn(place, None, None, None), Ok(None));
+        assert_eq!(game.consecutive_swaps, 0);
+
+        assert_eq!(swap(&mut game, 1), Ok(None));
+        assert_eq!(swap(&mut game, 0), Ok(None));
+        assert_eq!(swap(&mut game, 1), Ok(None));
+        assert_eq!(game.game_end, None);
+
+        // Both players have now swapped twice in a row, which ends the game without a winner
+        assert_eq!(swap(&mut game, 0), Ok(None));
+        assert_eq!(game.game_end, Some(GameEnd::Stalemate { turns: 4 }));
+        assert_eq!(game.winner, None);
+        assert!(game.is_over());
+        assert!(swap(&mut game, 1).is_err());
+    }
+
+    #[test]
+    fn overtime_removes_tiles() {
+        static NOW: AtomicU64 = AtomicU64::new(1_000);
+
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~ ~~\n\
+             __ A0 B0 C0 __ __\n\
+             __ __ D0 __ __ __\n\
+             __ __ __ __ __ __\n\
+             __ __ __ __ __ __\n\
+             __ __ __ __ __ __\n\
+             ~~ ~~ |1 ~~ ~~ ~~",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let allowance = Some(Duration::seconds(10));
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, allowance, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, allowance, (0, 0, 0)),
+        ];
+
+        let mut rules = GameRules::generation(0);
+        rules.battle_delay = 0;
+        rules.timing = Timing::PerPlayer {
+            time_allowance: 10,
+            overtime_rule: OvertimeRule::RemoveTiles {
+                period: 30,
+                phase_time: 60,
+            },
+        };
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            clock: || NOW.load(Ordering::SeqCst),
+            ..Game::new_legacy(3, 1, None, rules)
+        };
+        game.start();
+
+        let removed = |game: &Game| {
+            game.recent_changes
+                .iter()
+                .filter_map(|c| match c {
+                    Change::Board(BoardChange {
+                        detail,
+                        action: BoardChangeAction::Truncated,
+                    }) => Some(detail.coordinate),
+                    _ => None,
+                })
+                .collect::<Vec<Coordinate>>()
+        };
+        let place = |game: &mut Game, player: usize, x: usize, y: usize| {
+            game.play_turn(
+                Move::Place {
+                    player,
+                    tile: 'A',
+                    position: Coordinate { x, y },
+                },
+                None,
+                None,
+                None,
+            )
+        };
+
+        // 50 seconds into overtime is still within the grace period
+        NOW.store(1_060, Ordering::SeqCst);
+        assert_eq!(place(&mut game, 0, 4, 1), Ok(None));
+        assert!(removed(&game).is_empty());
+        assert_eq!(place(&mut game, 1, 2, 5), Ok(None));
+
+        // 70 seconds in, the first tile goes: the leaf furthest from the artifact
+        NOW.store(1_080, Ordering::SeqCst);
+        assert_eq!(place(&mut game, 0, 4, 2), Ok(None));
+        assert_eq!(removed(&game), vec![Coordinate::new(4, 2)]);
+        assert_eq!(place(&mut game, 1, 3, 5), Ok(None));
+
+        // 130 seconds in, two more periods have passed
+        NOW.store(1_140, Ordering::SeqCst);
+        assert_eq!(place(&mut game, 0, 4, 2), Ok(None));
+        assert_eq!(
+            removed(&game),
+            vec![Coordinate::new(4, 2), Coordinate::new(4, 1)]
+        );
+        assert_eq!(
+            game.board.get(Coordinate::new(2, 2)),
+            Ok(Square::Occupied {
+                player: 0,
+                tile: 'D',
+                validity: SquareValidity::Unknown,
+                foggy: false,
+            })
+        );
+    }
+
+    #[test]
+    fn resolve_noop() {
+        let b = Board::from_string(
+            "~~ |0 __ ~~ ~~\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             ~~ ~~ T1 |1 ~~",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            ..Game::new_legacy(3, 1, None, GameRules::generation(0))
+        };
+
+        game.make_move(
+            Move::Place {
+                player: 0,
+                tile: 'A',
+                position: Coordinate { x: 2, y: 0 },
+            },
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            game.board.to_string(),
+            "~~ |0 A0 ~~ ~~\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             ~~ ~~ T1 |1 ~~",
+        )
+    }
+
+    #[test]
+    fn resolve_with_smaller_attack_dict() {
+        let b = Board::from_string(
+            "__ S0 X0 |0 __\n\
+             __ T0 __ __ __\n\
+             __ R0 __ __ __\n\
+             __ __ A1 __ __\n\
+             __ __ R1 __ __\n\
+             __ __ T1 __ __\n\
+             __ __ S1 |1 __",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            ..Game::new_legacy(1, 1, None, GameRules::generation(0))
+        };
+
+        game.make_move(
+            Move::Place {
+                player: 0,
+                tile: 'A',
+                position: Coordinate { x: 1, y: 3 },
+            },
+            Some(&b_dict().builtin_dictionary),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            game.board.to_string(),
+            "__ __ X0 |0 __\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             __ __ A1 __ __\n\
+             __ __ R1 __ __\n\
+             __ __ T1 __ __\n\
+             __ __ S1 |1 __",
+        )
+    }
+
+    #[test]
+    fn resolve_with_cached_judgements() {
+        let b = Board::from_string(
+            "__ S0 X0 |0 __\n\
+             __ T0 __ __ __\n\
+             __ R0 __ __ __\n\
+             __ __ I1 __ __\n\
+             __ __ T1 |1 __",
+        );
+        let attack = Move::Place {
+            player: 0,
+            tile: 'A',
+            position: Coordinate { x: 1, y: 3 },
+        };
+        let new_game = |judge: Judge| {
+            let mut bag = TileUtils::trivial_bag();
+            let players = vec![
+                Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+                Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+            ];
+            Game {
+                board: b.clone(),
+                bag,
+                players,
+                player_turn_count: vec![0, 0],
+                judge,
+                ..Game::new_legacy(1, 1, None, GameRules::generation(0))
+            }
+        };
+        let defender_wins = "__ __ X0 |0 __\n\
+                             __ __ __ __ __\n\
+                             __ __ __ __ __\n\
+                             __ __ I1 __ __\n\
+                             __ __ T1 |1 __";
+        let attacker_wins = "__ S0 X0 |0 __\n\
+                             __ T0 __ __ __\n\
+                             __ R0 __ __ __\n\
+                             __ A0 __ __ __\n\
+                             __ __ __ |1 __";
+
+        // Without a cache, a dictionary containing the defending word lets the defender win
+        let mut uncached = new_game(Judge::new(vec!["IT".into()]));
+        uncached
+            .make_move(attack.clone(), None, None, None)
+            .unwrap();
+        assert_eq!(uncached.board.to_string(), defender_wins);
+
+        let mut game = new_game(short_dict());
+        game.make_move(attack.clone(), None, None, None).unwrap();
+        assert_eq!(game.board.to_string(), attacker_wins);
+        assert_eq!(game.word_judgements.get("IT"), Some(false));
+        let lookups = game.judge.lookups.get();
+        assert!(lookups > 0);
+
+        // Replaying the same battle is answered entirely from the cache
+        game.board = b.clone();
+        game.make_move(attack.clone(), None, None, None).unwrap();
+        assert_eq!(game.board.to_string(), attacker_wins);
+        assert_eq!(game.judge.lookups.get(), lookups);
+
+        // A new judge doesn't inherit judgements made by the old one
+        game.board = b.clone();
+        game.judge = Judge::new(vec!["IT".into()]);
+        game.make_move(attack, None, None, None).unwrap();
+        assert_eq!(game.board.to_string(), defender_wins);
+        assert!(game.judge.lookups.get() > 0);
+
+        // Battles judged against differing dictionaries shouldn't touch the game's cache
+        let mut game = new_game(short_dict());
+        let attacker_dict = short_dict().builtin_dictionary;
+        let defender_dict = short_dict().builtin_dictionary;
+        game.make_move(
+            Move::Place {
+                player: 0,
+                tile: 'A',
+                position: Coordinate { x: 1, y: 3 },
+            },
+            Some(&attacker_dict),
+            Some(&defender_dict),
+            None,
+        )
+        .unwrap();
+        assert!(game.word_judgements.is_empty());
+    }
+
+    fn multi_placement_game() -> Game {
+        let b = Board::from_string(
+            "__ S0 X0 |0 __\n\
+             __ __ __ __ __\n\
+             __ __ __ __ __\n\
+             __ __ I1 __ __\n\
+             __ __ T1 |1 __",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let mut players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+        players[0].hand = Hand(vec!['T', 'R', 'A', 'A']);
+
+        let mut rules = GameRules::generation(0);
+        rules.tiles_per_turn = 3;
+
+        Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            ..Game::new_legacy(1, 1, None, rules)
+        }
+    }
+
+    #[test]
+    fn resolve_multi_placement() {
+        let mut game = multi_placement_game();
+
+        let changes = game
+            .make_move(
+                Move::PlaceMany {
+                    player: 0,
+                    placements: vec![
+                        (Coordinate { x: 1, y: 1 }, 'T'),
+                        (Coordinate { x: 1, y: 2 }, 'R'),
+                        (Coordinate { x: 1, y: 3 }, 'A'),
+                    ],
+                },
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        // Only the finished word should battle
+        assert_eq!(
+            changes
+                .iter()
+                .filter(|change| matches!(change, Change::Battle(_)))
+                .count(),
+            1
+        );
+        assert_eq!(
+            game.board.to_string(),
+            "__ S0 X0 |0 __\n\
+             __ T0 __ __ __\n\
+             __ R0 __ __ __\n\
+             __ A0 __ __ __\n\
+             __ __ __ |1 __",
+        );
+    }
+
+    #[test]
+    fn multi_placement_fights_one_battle() {
+        let mut game = multi_placement_game();
+        game.board = Board::from_string(
+            "G1 T0 |0 __ __\n\
+             B1 __ __ __ __\n\
+             __ __ I1 __ __\n\
+             __ __ T1 __ __\n\
+             __ __ __ |1 __",
+        );
+        game.players[0].hand = Hand(vec!['A', 'F']);
+
+        let changes = game
+            .make_move(
+                Move::PlaceMany {
+                    player: 0,
+                    placements: vec![
+                        (Coordinate { x: 1, y: 1 }, 'A'),
+                        (Coordinate { x: 1, y: 2 }, 'F'),
+                    ],
+                },
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        // Both placed tiles touch a different enemy word, but the turn still fights once
+        let battles: Vec<_> = changes
+            .iter()
+            .filter_map(|change| match change {
+                Change::Battle(battle) => Some(battle),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(battles.len(), 1);
+        assert_eq!(battles[0].defenders.len(), 2);
+    }
+
+    #[test]
+    fn invalid_multi_placements() {
+        let mut game = multi_placement_game();
+        let board = game.board.to_string();
+
+        assert_eq!(
+            game.make_move(
+                Move::PlaceMany {
+                    player: 0,
+                    placements: vec![
+                        (Coordinate { x: 1, y: 1 }, 'T'),
+                        (Coordinate { x: 1, y: 3 }, 'A'),
+                    ],
+                },
+                None,
+                None,
+                None,
+            ),
+            Err(GamePlayError::DisconnectedPlace)
+        );
+
+        assert_eq!(
+            game.make_move(
+                Move::PlaceMany {
+                    player: 0,
+                    placements: vec![
+                        (Coordinate { x: 1, y: 1 }, 'T'),
+                        (Coordinate { x: 1, y: 2 }, 'R'),
+                        (Coordinate { x: 1, y: 3 }, 'A'),
+                        (Coordinate { x: 0, y: 3 }, 'A'),
+                    ],
+                },
+                None,
+                None,
+                None,
+            ),
+            Err(GamePlayError::TooManyPlacements {
+                placed: 4,
+                allowed: 3
+            })
+        );
+
+        assert_eq!(
+            game.make_move(
+                Move::PlaceMany {
+                    player: 0,
+                    placements: vec![
+                        (Coordinate { x: 1, y: 1 }, 'T'),
+                        (Coordinate { x: 1, y: 2 }, 'T'),
+                    ],
+                },
+                None,
+                None,
+                None,
+            ),
+            Err(GamePlayError::PlayerDoesNotHaveTile {
+                player: 0,
+                tile: 'T'
+            })
+        );
+
+        // Squares later in the move are checked before the first tile goes down
+        assert_eq!(
+            game.make_move(
+                Move::PlaceMany {
+                    player: 0,
+                    placements: vec![
+                        (Coordinate { x: 1, y: 1 }, 'T'),
+                        (Coordinate { x: 3, y: 0 }, 'R'),
+                    ],
+                },
+                None,
+                None,
+                None,
+            ),
+            Err(GamePlayError::InvalidPosition {
+                position: Coordinate { x: 3, y: 0 }
+            })
+        );
+        assert_eq!(
+            game.make_move(
+                Move::PlaceMany {
+                    player: 0,
+                    placements: vec![
+                        (Coordinate { x: 1, y: 1 }, 'T'),
+                        (Coordinate { x: 1, y: 9 }, 'R'),
+                    ],
+                },
+                None,
+                None,
+                None,
+            ),
+            Err(GamePlayError::OutSideBoardDimensions {
+                position: Coordinate { x: 1, y: 9 }
+            })
+        );
+
+        // Rejected moves leave the board and hand untouched
+        assert_eq!(game.board.to_string(), board);
+        assert_eq!(game.players[0].hand.0, vec!['T', 'R', 'A', 'A']);
+    }
+
+    #[test]
+    fn multi_placements_connect_across_wrapped_edges() {
+        let mut game = multi_placement_game();
+        let placements = vec![
+            (Coordinate { x: 0, y: 0 }, 'T'),
+            (Coordinate { x: 0, y: 1 }, 'R'),
+            (Coordinate { x: 4, y: 1 }, 'A'),
+        ];
+
+        assert_eq!(
+            game.make_move(
+                Move::PlaceMany {
+                    player: 0,
+                    placements: placements.clone(),
+                },
+                None,
+                None,
+                None,
+            ),
+            Err(GamePlayError::DisconnectedPlace)
+        );
+
+        game.board.wrap = true;
+        assert!(game
+            .make_move(
+                Move::PlaceMany {
+                    player: 0,
+                    placements,
+                },
+                None,
+                None,
+                None,
+            )
+            .is_ok());
+    }
+
+    fn resolve_capture_with_rule(rule: CapturedTileRule) -> (Game, TileBag) {
+        let b = Board::from_string(
+            "__ S0 X0 |0 __\n\
+             __ T0 __ __ __\n\
+             __ R0 __ __ __\n\
+             __ __ I1 __ __\n\
+             __ __ T1 |1 __",
+        );
+        let mut bag = TileUtils::trivial_bag();
         let players = vec![
             Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
             Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
         ];
+        // The bag as it stands once both hands are dealt, for the tests to compare against
+        let test_bag = bag.clone();
+
+        let mut rules = GameRules::generation(0);
+        rules.captured_tiles = rule;
 
         let mut game = Game {
             board: b,
@@ -1119,7 +2525,7 @@ mod tests {
             players,
             player_turn_count: vec![0, 0],
             judge: short_dict(),
-            ..Game::new_legacy(1, 1, None, GameRules::generation(0))
+            ..Game::new_legacy(1, 1, None, rules)
         };
 
         game.make_move(
@@ -1128,7 +2534,7 @@ mod tests {
                 tile: 'A',
                 position: Coordinate { x: 1, y: 3 },
             },
-            Some(&b_dict().builtin_dictionary),
+            None,
             None,
             None,
         )
@@ -1136,14 +2542,43 @@ mod tests {
 
         assert_eq!(
             game.board.to_string(),
-            "__ __ X0 |0 __\n\
-             __ __ __ __ __\n\
-             __ __ __ __ __\n\
-             __ __ A1 __ __\n\
-             __ __ R1 __ __\n\
-             __ __ T1 __ __\n\
-             __ __ S1 |1 __",
-        )
+            "__ S0 X0 |0 __\n\
+             __ T0 __ __ __\n\
+             __ R0 __ __ __\n\
+             __ A0 __ __ __\n\
+             __ __ __ |1 __",
+        );
+
+        (game, test_bag)
+    }
+
+    #[test]
+    fn captured_tiles_return_to_bag() {
+        let (game, mut test_bag) = resolve_capture_with_rule(CapturedTileRule::ReturnToBag);
+
+        for letter in ['I', 'T'] {
+            test_bag.return_tile(letter);
+        }
+        assert_eq!(game.bag, test_bag);
+        assert_eq!(game.players[0].hand.len(), 7);
+    }
+
+    #[test]
+    fn captured_tiles_are_voided() {
+        let (game, test_bag) = resolve_capture_with_rule(CapturedTileRule::Void);
+
+        assert_eq!(game.bag, test_bag);
+        assert_eq!(game.players[0].hand.len(), 7);
+    }
+
+    #[test]
+    fn captured_tiles_transfer_to_captor() {
+        let (game, test_bag) = resolve_capture_with_rule(CapturedTileRule::TransferToCaptor);
+
+        assert_eq!(game.bag, test_bag);
+        assert_eq!(game.players[0].hand.len(), 9);
+        assert_eq!(game.players[0].hand.0[7..], ['I', 'T']);
+        assert_eq!(game.players[1].hand.len(), 7);
     }
 
     #[test]
@@ -1195,4 +2630,246 @@ mod tests {
              __ __ __ |1 __",
         )
     }
+
+    #[test]
+    fn seed_tiles_grow_from_roots() {
+        let mut bag = TileUtils::trivial_bag();
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+        let mut rules = GameRules::generation(0);
+        rules.seed_tiles = 3;
+
+        let mut game = Game {
+            board: Board::from_string(
+                "~~ ~~ |0 ~~ ~~\n\
+                 __ __ __ __ __\n\
+                 __ __ __ __ __\n\
+                 __ __ __ __ __\n\
+                 __ __ __ __ __\n\
+                 ~~ ~~ |1 ~~ ~~",
+            ),
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            ..Game::new_legacy(1, 1, None, rules)
+        };
+        game.start();
+
+        for (player, root) in [(0, Coordinate::new(2, 0)), (1, Coordinate::new(2, 5))] {
+            let seeded = game
+                .board
+                .squares
+                .iter()
+                .flatten()
+                .filter(|sq| matches!(sq, Square::Occupied { player: p, .. } if *p == player))
+                .count();
+            assert_eq!(seeded, 3);
+            // The root plus every seeded tile
+            assert_eq!(game.board.depth_first_search(root).len(), 4);
+        }
+    }
+
+    #[test]
+    fn hints_ignore_fogged_tiles() {
+        let mut board = Board::from_string(
+            "~~ |0 ~~ ~~ ~~ ~~ ~~ ~~ ~~\n\
+             __ A0 __ __ __ __ __ __ X1\n\
+             __ __ __ __ __ __ __ __ __\n\
+             ~~ ~~ ~~ ~~ ~~ ~~ ~~ |1 ~~",
+        );
+        // Across the wrapped edge the X is next door, but it's too far from A to be seen
+        board.wrap = true;
+        let mut bag = TileUtils::trivial_bag();
+        let mut players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+        players[0].hand = Hand(vec!['T']);
+
+        let mut game = Game {
+            board,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: Judge::new(vec!["AT".into()]),
+            ..Game::new_legacy(1, 1, None, GameRules::generation(0))
+        };
+
+        let (position, tile, _) = game.hint(0, None).expect("AT beats X");
+        assert_eq!((position, tile), (Coordinate::new(0, 1), 'T'));
+
+        game.rules.visibility = Visibility::TileFog;
+        assert!(game.hint(0, None).is_none());
+    }
+
+    #[test]
+    fn captured_squares_cool_down() {
+        let b = Board::from_string(
+            "~~ ~~ |0 ~~ ~~\n\
+             __ __ D0 __ __\n\
+             __ __ N0 __ __\n\
+             __ __ __ __ __\n\
+             __ __ X1 __ __\n\
+             __ __ __ __ __\n\
+             ~~ ~~ |1 ~~ ~~",
+        );
+        let mut bag = TileUtils::trivial_bag();
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+        let mut rules = GameRules::generation(0);
+        rules.square_cooldown = Some(1);
+
+        let mut game = Game {
+            board: b,
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            ..Game::new_legacy(1, 1, None, rules)
+        };
+        game.start();
+        let place = |player: usize, x: usize, y: usize| Move::Place {
+            player,
+            tile: 'A',
+            position: Coordinate { x, y },
+        };
+
+        // AND takes out the stray X
+        assert!(game.play_turn(place(0, 2, 3), None, None, None).is_ok());
+        let captured = Coordinate { x: 2, y: 4 };
+        assert_eq!(game.board.get(captured).unwrap(), Square::land());
+        assert!(!game.playable_positions(0).contains(&captured));
+
+        assert_eq!(
+            game.play_turn(place(1, 2, 4), None, None, None),
+            Err(GamePlayError::SquareCoolingDown { turns_left: 1 }.to_string())
+        );
+        assert!(game.play_turn(place(1, 2, 5), None, None, None).is_ok());
+
+        // The cooldown is over by the time the square comes around again
+        assert!(game.playable_positions(0).contains(&captured));
+        assert!(game.play_turn(place(0, 2, 4), None, None, None).is_ok());
+    }
+
+    #[test]
+    fn placements_must_form_valid_words() {
+        let game_with = |word_validity: WordValidity, below: char| {
+            let mut bag = TileUtils::trivial_bag();
+            let players = vec![
+                Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+                Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+            ];
+            let mut rules = GameRules::generation(0);
+            rules.word_validity = word_validity;
+            Game {
+                board: Board::from_string(format!(
+                    "~~ ~~ |0 ~~ ~~\n\
+                     __ __ __ __ __\n\
+                     __ __ __ __ __\n\
+                     __ F1 __ T1 __\n\
+                     __ __ N1 __ __\n\
+                     __ __ {below}1 __ __\n\
+                     ~~ ~~ |1 ~~ ~~"
+                )),
+                bag,
+                players,
+                player_turn_count: vec![0, 0],
+                judge: short_dict(),
+                ..Game::new_legacy(1, 1, None, rules)
+            }
+        };
+        let place = Move::Place {
+            player: 1,
+            tile: 'A',
+            position: Coordinate { x: 2, y: 3 },
+        };
+
+        // FAT across and AND down are both words
+        let mut game = game_with(WordValidity::Always, 'D');
+        assert!(game.make_move(place.clone(), None, None, None).is_ok());
+
+        // FAT is still a word, but ANG down is not
+        let mut game = game_with(WordValidity::Always, 'G');
+        let before = game.board.clone();
+        assert_eq!(
+            game.make_move(place.clone(), None, None, None),
+            Err(GamePlayError::InvalidWord { word: "ANG".into() })
+        );
+        assert_eq!(game.board, before);
+
+        // Words are only judged in battle by default
+        let mut game = game_with(WordValidity::OnAttack, 'G');
+        assert!(game.make_move(place, None, None, None).is_ok());
+    }
+
+    #[test]
+    fn opening_moves() {
+        let mut bag = TileUtils::trivial_bag();
+        let players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+        let mut game = Game {
+            board: Board::from_string(
+                "~~ ~~ |0 ~~ ~~\n\
+                 __ __ __ __ __\n\
+                 __ __ __ __ __\n\
+                 __ __ __ __ __\n\
+                 ~~ ~~ |1 ~~ ~~",
+            ),
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: short_dict(),
+            ..Game::new_legacy(1, 1, None, GameRules::generation(0))
+        };
+        game.start();
+        assert!(game.board.is_opening(0));
+        assert!(game.board.is_opening(1));
+
+        let place = Move::Place {
+            player: 0,
+            tile: 'A',
+            position: Coordinate { x: 2, y: 1 },
+        };
+        assert_eq!(game.play_turn(place, None, None, None), Ok(None));
+        assert!(!game.board.is_opening(0));
+        assert_eq!(game.board.player_tile_count(0), 1);
+        assert!(game.board.is_opening(1));
+    }
+
+    #[test]
+    fn handicapped_player_gets_a_head_start() {
+        let mut rules = GameRules::generation(0);
+        rules.timing = Timing::PerPlayer {
+            time_allowance: 60,
+            overtime_rule: OvertimeRule::Elimination,
+        };
+        rules.handicaps = vec![
+            Handicap::default(),
+            Handicap {
+                extra_hand_tiles: 2,
+                extra_time: 30,
+                extra_seed_tiles: 0,
+            },
+        ];
+
+        let mut game = Game::new_legacy(9, 9, None, rules);
+        game.add_player("A".into());
+        game.add_player("B".into());
+
+        assert_eq!(game.players[0].hand.len(), 7);
+        assert_eq!(game.players[0].hand_capacity, 7);
+        assert_eq!(game.players[0].time_remaining, Some(Duration::new(60, 0)));
+
+        assert_eq!(game.players[1].hand.len(), 9);
+        assert_eq!(game.players[1].hand_capacity, 9);
+        assert_eq!(game.players[1].allotted_time, Some(Duration::new(90, 0)));
+        assert_eq!(game.players[1].time_remaining, Some(Duration::new(90, 0)));
+    }
 }
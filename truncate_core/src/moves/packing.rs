@@ -34,6 +34,8 @@ pub fn pack_moves(moves: &Vec<Move>, player_count: usize) -> String {
         next_player = match first_move {
             Move::Place { player, .. } => *player,
             Move::Swap { player, .. } => *player,
+            Move::PlaceMany { player, .. } => *player,
+            Move::Exchange { player, .. } => *player,
         };
         packed.push_str(&format!("[{next_player}]"));
     };
@@ -70,6 +72,39 @@ pub fn pack_moves(moves: &Vec<Move>, player_count: usize) -> String {
                 packed.push_str(&pack_coord(*to));
                 packed.push('>');
 
+                incr_player(&mut next_player);
+            }
+            Move::PlaceMany { player, placements } => {
+                if *player != next_player {
+                    next_player = *player;
+                    packed.push_str(&format!("[{player}]"));
+                }
+
+                packed.push('{');
+                for (position, tile) in placements {
+                    packed.push_str(&pack_coord(*position));
+                    packed.push(*tile);
+                }
+                packed.push('}');
+
+                incr_player(&mut next_player);
+            }
+            Move::Exchange { player, indices } => {
+                if *player != next_player {
+                    next_player = *player;
+                    packed.push_str(&format!("[{player}]"));
+                }
+
+                packed.push('(');
+                packed.push_str(
+                    &indices
+                        .iter()
+                        .map(|index| index.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                );
+                packed.push(')');
+
                 incr_player(&mut next_player);
             }
         }
@@ -87,6 +122,8 @@ pub fn unpack_moves(packed_moves: &String, player_count: usize) -> Result<Vec<Mo
         Place(String),
         SwapFrom(String),
         SwapTo(Coordinate, String),
+        PlaceMany(Vec<(Coordinate, char)>, String),
+        Exchange(Vec<usize>, String),
     }
 
     let mut i = packed_moves.chars();
@@ -106,6 +143,10 @@ pub fn unpack_moves(packed_moves: &String, player_count: usize) -> Result<Vec<Mo
                     state = State::Place(c.to_string());
                 } else if c == '<' {
                     state = State::SwapFrom(String::new());
+                } else if c == '{' {
+                    state = State::PlaceMany(vec![], String::new());
+                } else if c == '(' {
+                    state = State::Exchange(vec![], String::new());
                 } else if c == '[' {
                     state = State::SetPlayer(String::new());
                 } else {
@@ -165,6 +206,41 @@ pub fn unpack_moves(packed_moves: &String, player_count: usize) -> Result<Vec<Mo
                     return Err(());
                 }
             }
+            // {0304A0305B} places tile 'A' at [3, 4] and 'B' at [3, 5]
+            State::PlaceMany(placements, s) => {
+                if c.is_numeric() {
+                    s.push(c);
+                } else if c.is_alphabetic() {
+                    placements.push((unpack_coord(s)?, c));
+                    s.clear();
+                } else if c == '}' && s.is_empty() {
+                    moves.push(Move::PlaceMany {
+                        player: incr_player(&mut player),
+                        placements: std::mem::take(placements),
+                    });
+                    state = State::None;
+                } else {
+                    return Err(());
+                }
+            }
+            // (0,3) exchanges the first and fourth tiles in the hand
+            State::Exchange(indices, s) => {
+                if c.is_numeric() {
+                    s.push(c);
+                } else if c == ',' || c == ')' {
+                    indices.push(s.parse().map_err(|_| ())?);
+                    s.clear();
+                    if c == ')' {
+                        moves.push(Move::Exchange {
+                            player: incr_player(&mut player),
+                            indices: std::mem::take(indices),
+                        });
+                        state = State::None;
+                    }
+                } else {
+                    return Err(());
+                }
+            }
         }
     }
 
@@ -301,4 +377,58 @@ mod tests {
 
         assert_eq!(unpacked, Ok(moves));
     }
+
+    #[test]
+    fn test_packing_multi_placements() {
+        let moves = vec![
+            Move::PlaceMany {
+                player: 0,
+                placements: vec![
+                    (Coordinate { x: 3, y: 4 }, 'A'),
+                    (Coordinate { x: 3, y: 5 }, 'B'),
+                    (Coordinate { x: 12, y: 6 }, 'C'),
+                ],
+            },
+            Move::Place {
+                player: 1,
+                tile: 'R',
+                position: Coordinate { x: 3, y: 3 },
+            },
+        ];
+
+        let packed = pack_moves(&moves, 2);
+
+        assert_eq!(packed, "[0]{34A35B1206C}33R".to_string());
+
+        let unpacked = unpack_moves(&packed, 2);
+
+        assert_eq!(unpacked, Ok(moves));
+    }
+
+    #[test]
+    fn test_packing_exchanges() {
+        let moves = vec![
+            Move::Exchange {
+                player: 0,
+                indices: vec![0, 3],
+            },
+            Move::Place {
+                player: 1,
+                tile: 'R',
+                position: Coordinate { x: 3, y: 3 },
+            },
+            Move::Exchange {
+                player: 0,
+                indices: vec![12],
+            },
+        ];
+
+        let packed = pack_moves(&moves, 2);
+
+        assert_eq!(packed, "[0](0,3)33R(12)".to_string());
+
+        let unpacked = unpack_moves(&packed, 2);
+
+        assert_eq!(unpacked, Ok(moves));
+    }
 }
@@ -13,6 +13,7 @@ use crate::{
 };
 
 pub mod scoring;
+pub mod scripted;
 
 use scoring::BoardScore;
 use xxhash_rust::xxh3;
@@ -172,7 +173,7 @@ impl Game {
         game.instrument_unknown_game_state(for_player, total_depth, depth);
         let pruning = arborist.prune();
 
-        if depth == 0 || game.winner.is_some() {
+        if depth == 0 || game.is_over() {
             return (
                 game.static_eval(self_dictionary, for_player, depth, caches, npc_params),
                 None,
@@ -317,9 +318,7 @@ impl Game {
 
         playable_tiles.sort();
 
-        let playable_squares = self
-            .board
-            .playable_positions(self.next_player.unwrap(), &self.rules.truncation);
+        let playable_squares = self.playable_positions(self.next_player.unwrap());
 
         let mut coords: Vec<_> = playable_squares
             .into_iter()
@@ -0,0 +1,213 @@
+use thiserror::Error;
+
+use crate::{game::Game, judge::WordDict, moves::Move};
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ScriptError {
+    #[error("The script has no moves left to play")]
+    Exhausted,
+    #[error("Scripted move #{index} can no longer be played: {reason}")]
+    IllegalMove { index: usize, reason: String },
+}
+
+/// A bot that ignores the state of the board and plays a fixed list of moves in order.
+/// Used to make tutorials reproducible, where the opponent's moves are part of the lesson.
+#[derive(Debug, Clone)]
+pub struct ScriptedBot {
+    moves: Vec<Move>,
+    next_move: usize,
+}
+
+impl ScriptedBot {
+    pub fn new(moves: Vec<Move>) -> Self {
+        Self {
+            moves,
+            next_move: 0,
+        }
+    }
+
+    pub fn peek(&self) -> Option<&Move> {
+        self.moves.get(self.next_move)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next_move >= self.moves.len()
+    }
+
+    /// Plays the next scripted move on the game, returning the winner if the move ended the game.
+    /// An illegal move is not skipped, so the script stays stuck on it.
+    pub fn play_next(
+        &mut self,
+        game: &mut Game,
+        dictionary: Option<&WordDict>,
+    ) -> Result<Option<usize>, ScriptError> {
+        let Some(next_move) = self.moves.get(self.next_move) else {
+            return Err(ScriptError::Exhausted);
+        };
+
+        let winner = game
+            .play_turn(next_move.clone(), dictionary, dictionary, None)
+            .map_err(|reason| ScriptError::IllegalMove {
+                index: self.next_move,
+                reason,
+            })?;
+
+        self.next_move += 1;
+        Ok(winner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use time::Duration;
+
+    use super::*;
+    use crate::{
+        bag::tests as TileUtils,
+        board::{Board, Coordinate},
+        judge::Judge,
+        player::{Hand, Player},
+        rules::GameRules,
+    };
+
+    fn scripted_game() -> Game {
+        let mut bag = TileUtils::trivial_bag();
+        let mut players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+        players[0].hand = Hand(vec!['A', 'C']);
+        players[1].hand = Hand(vec!['B', 'D']);
+
+        let mut rules = GameRules::generation(0);
+        rules.battle_delay = 0;
+
+        let mut game = Game {
+            board: Board::from_string(
+                "~~ |0 ~~ ~~\n\
+                 __ __ __ __\n\
+                 __ __ __ __\n\
+                 ~~ ~~ |1 ~~",
+            ),
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: Judge::new(vec![]),
+            ..Game::new_legacy(1, 1, None, rules)
+        };
+        game.start();
+        game
+    }
+
+    #[test]
+    fn plays_script_in_order() {
+        let mut game = scripted_game();
+        let mut bot = ScriptedBot::new(vec![
+            Move::Place {
+                player: 0,
+                tile: 'A',
+                position: Coordinate::new(1, 1),
+            },
+            Move::Place {
+                player: 1,
+                tile: 'B',
+                position: Coordinate::new(2, 2),
+            },
+        ]);
+
+        assert_eq!(bot.play_next(&mut game, None), Ok(None));
+        assert_eq!(bot.play_next(&mut game, None), Ok(None));
+        assert!(bot.is_finished());
+        assert_eq!(bot.play_next(&mut game, None), Err(ScriptError::Exhausted));
+
+        assert_eq!(
+            game.board.to_string(),
+            "~~ |0 ~~ ~~\n\
+             __ A0 __ __\n\
+             __ __ B1 __\n\
+             ~~ ~~ |1 ~~",
+        );
+    }
+
+    #[test]
+    fn surfaces_illegal_moves() {
+        let mut game = scripted_game();
+        let mut bot = ScriptedBot::new(vec![
+            Move::Place {
+                player: 0,
+                tile: 'A',
+                position: Coordinate::new(1, 1),
+            },
+            Move::Place {
+                player: 1,
+                tile: 'B',
+                position: Coordinate::new(1, 1),
+            },
+        ]);
+
+        assert_eq!(bot.play_next(&mut game, None), Ok(None));
+        assert_eq!(
+            bot.play_next(&mut game, None),
+            Err(ScriptError::IllegalMove {
+                index: 1,
+                reason: "You can't place a tile on top of another".into()
+            })
+        );
+        assert!(!bot.is_finished());
+        assert_eq!(
+            bot.peek(),
+            Some(&Move::Place {
+                player: 1,
+                tile: 'B',
+                position: Coordinate::new(1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn records_move_durations() {
+        static CLOCK: AtomicU64 = AtomicU64::new(1000);
+        fn clock() -> u64 {
+            CLOCK.load(Ordering::SeqCst)
+        }
+
+        let mut game = Game {
+            clock,
+            ..scripted_game()
+        };
+        game.start();
+
+        let mut bot = ScriptedBot::new(vec![
+            Move::Place {
+                player: 0,
+                tile: 'A',
+                position: Coordinate::new(1, 1),
+            },
+            Move::Place {
+                player: 1,
+                tile: 'B',
+                position: Coordinate::new(2, 2),
+            },
+            Move::Place {
+                player: 0,
+                tile: 'C',
+                position: Coordinate::new(0, 1),
+            },
+        ]);
+
+        for seconds in [4, 11, 2] {
+            CLOCK.fetch_add(seconds, Ordering::SeqCst);
+            assert_eq!(bot.play_next(&mut game, None), Ok(None));
+        }
+
+        assert_eq!(
+            game.move_durations,
+            vec![
+                vec![Duration::seconds(4), Duration::seconds(2)],
+                vec![Duration::seconds(11)]
+            ]
+        );
+    }
+}
@@ -140,6 +140,44 @@ impl Player {
         }
     }
 
+    /// Returns the tiles at `indices` to the bag, drawing a replacement for each.
+    /// Replacements are drawn before the old tiles go back, so the same tiles can't be drawn straight back out.
+    pub fn exchange_tiles(
+        &mut self,
+        indices: &[usize],
+        bag: &mut TileBag,
+    ) -> Result<Change, GamePlayError> {
+        if indices.is_empty() {
+            return Err(GamePlayError::EmptyExchange);
+        }
+        let mut picked = HashSet::new();
+        if let Some(index) = indices
+            .iter()
+            .find(|index| **index >= self.hand.len() || !picked.insert(**index))
+        {
+            return Err(GamePlayError::InvalidHandIndex { index: *index });
+        }
+
+        let removed: Vec<_> = indices.iter().map(|index| self.hand.0[*index]).collect();
+        let added: Vec<_> = indices
+            .iter()
+            .map(|index| {
+                let tile = bag.draw_tile();
+                self.hand.replace(*index, tile);
+                tile
+            })
+            .collect();
+        for tile in &removed {
+            bag.return_tile(*tile);
+        }
+
+        Ok(Change::Hand(HandChange {
+            player: self.index,
+            removed,
+            added,
+        }))
+    }
+
     pub fn add_special_tile(&mut self, tile: char) -> Change {
         self.hand.add(tile);
         Change::Hand(HandChange {
@@ -0,0 +1,342 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::Duration;
+
+use crate::{
+    board::{Board, Square},
+    error::GamePlayError,
+    game::{now, Game},
+    reporting::Change,
+    rules::GameRules,
+};
+
+/// The current version of the `.trreplay` format.
+/// Bump this whenever the serialized shape of a `Replay` changes,
+/// so that older files are rejected rather than mis-parsed.
+pub const REPLAY_FORMAT_VERSION: u32 = 2;
+
+pub const REPLAY_FILE_EXTENSION: &str = "trreplay";
+
+#[derive(Error, Debug)]
+pub enum ReplayError {
+    #[error("Replay file has version {found}, but this build only reads version {expected}")]
+    UnsupportedVersion { found: u32, expected: u32 },
+    #[error("Couldn't read or write the replay file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Replay file is malformed: {0}")]
+    Malformed(#[from] serde_json::Error),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayHeader {
+    pub version: u32,
+    pub rules: GameRules,
+    /// Seconds since the unix epoch at which the replay was recorded
+    pub recorded_at: u64,
+    pub player_names: Vec<String>,
+}
+
+/// A `.trreplay` file is a JSON document containing:
+///  - a header, describing the format version, rules, date, and players
+///  - the board as it was before the first move
+///  - the changes from each turn, in the order they were played
+///  - how long each player spent on each of their turns
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    pub header: ReplayHeader,
+    pub initial_board: Board,
+    pub change_groups: Vec<Vec<Change>>,
+    #[serde(default)]
+    pub move_durations: Vec<Vec<Duration>>,
+}
+
+/// Just enough of a replay file to check its version before parsing the rest
+#[derive(Deserialize)]
+struct VersionProbe {
+    header: HeaderVersionProbe,
+}
+
+#[derive(Deserialize)]
+struct HeaderVersionProbe {
+    version: u32,
+}
+
+impl Replay {
+    pub fn new(game: &Game) -> Self {
+        Self {
+            header: ReplayHeader {
+                version: REPLAY_FORMAT_VERSION,
+                rules: game.rules.clone(),
+                recorded_at: now(),
+                player_names: game.players.iter().map(|p| p.name.clone()).collect(),
+            },
+            initial_board: game.board.clone(),
+            change_groups: vec![],
+            move_durations: game.move_durations.clone(),
+        }
+    }
+
+    pub fn record_turn(&mut self, changes: Vec<Change>) {
+        self.change_groups.push(changes);
+    }
+
+    /// Records the changes from the turn the game just played, along with how long it took
+    pub fn record_game_turn(&mut self, game: &Game) {
+        self.record_turn(game.recent_changes.clone());
+        self.record_move_durations(game);
+    }
+
+    /// Copies over how long each player has taken on their turns so far
+    fn record_move_durations(&mut self, game: &Game) {
+        self.move_durations = game.move_durations.clone();
+    }
+
+    /// Rebuilds the board after each turn and finds the fraction of turns `player` had a tile on each square,
+    /// laid out in rows like the initial board.
+    pub fn control_heatmap(&self, player: usize) -> Result<Vec<Vec<f32>>, GamePlayError> {
+        let mut board = self.initial_board.clone();
+        let mut turns_held: Vec<Vec<usize>> = self
+            .initial_board
+            .squares
+            .iter()
+            .map(|row| vec![0; row.len()])
+            .collect();
+
+        for group in &self.change_groups {
+            let board_changes: Vec<_> = group
+                .iter()
+                .filter_map(|change| match change {
+                    Change::Board(board_change) => Some(board_change.clone()),
+                    _ => None,
+                })
+                .collect();
+            board.apply_change_group(&board_changes)?;
+
+            for (y, row) in turns_held.iter_mut().enumerate() {
+                for (x, held) in row.iter_mut().enumerate() {
+                    let square = board.squares.get(y).and_then(|row| row.get(x));
+                    if matches!(square, Some(Square::Occupied { player: p, .. }) if *p == player) {
+                        *held += 1;
+                    }
+                }
+            }
+        }
+
+        let turns = self.change_groups.len().max(1) as f32;
+        Ok(turns_held
+            .into_iter()
+            .map(|row| row.into_iter().map(|held| held as f32 / turns).collect())
+            .collect())
+    }
+
+    pub fn to_replay_string(&self) -> Result<String, ReplayError> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn from_replay_string<S: AsRef<str>>(s: S) -> Result<Self, ReplayError> {
+        let probe: VersionProbe = serde_json::from_str(s.as_ref())?;
+        if probe.header.version != REPLAY_FORMAT_VERSION {
+            return Err(ReplayError::UnsupportedVersion {
+                found: probe.header.version,
+                expected: REPLAY_FORMAT_VERSION,
+            });
+        }
+
+        Ok(serde_json::from_str(s.as_ref())?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ReplayError> {
+        fs::write(path, self.to_replay_string()?)?;
+        Ok(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ReplayError> {
+        Self::from_replay_string(fs::read_to_string(path)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use crate::{
+        bag::tests as TileUtils,
+        board::{Coordinate, Square, SquareValidity},
+        judge::Judge,
+        moves::Move,
+        player::{Hand, Player},
+        reporting::{BoardChange, BoardChangeAction, BoardChangeDetail, HandChange},
+    };
+
+    fn test_replay() -> Replay {
+        let mut game = Game::new(5, 5, Some(1), GameRules::generation(2));
+        game.add_player("Alpha".into());
+        game.add_player("Beta".into());
+
+        let mut replay = Replay::new(&game);
+        replay.record_turn(vec![
+            Change::Board(BoardChange {
+                detail: BoardChangeDetail {
+                    square: Square::Occupied {
+                        player: 0,
+                        tile: 'A',
+                        validity: SquareValidity::Unknown,
+                        foggy: false,
+                    },
+                    coordinate: Coordinate::new(3, 3),
+                },
+                action: BoardChangeAction::Added,
+            }),
+            Change::Hand(HandChange {
+                player: 0,
+                removed: vec!['A'],
+                added: vec!['B'],
+            }),
+        ]);
+        replay
+    }
+
+    #[test]
+    fn control_heatmap() {
+        let mut replay = test_replay();
+        replay.initial_board = Board::from_string(
+            "~~ |0 ~~\n\
+             __ __ __\n\
+             __ __ __\n\
+             ~~ |1 ~~",
+        );
+        replay.change_groups.clear();
+
+        let change = |x: usize, y: usize, player: usize, tile: char, action: BoardChangeAction| {
+            Change::Board(BoardChange {
+                detail: BoardChangeDetail {
+                    square: Square::Occupied {
+                        player,
+                        tile,
+                        validity: SquareValidity::Unknown,
+                        foggy: false,
+                    },
+                    coordinate: Coordinate::new(x, y),
+                },
+                action,
+            })
+        };
+        replay.record_turn(vec![change(1, 1, 0, 'A', BoardChangeAction::Added)]);
+        replay.record_turn(vec![change(1, 2, 1, 'B', BoardChangeAction::Added)]);
+        replay.record_turn(vec![change(0, 1, 0, 'C', BoardChangeAction::Added)]);
+        replay.record_turn(vec![
+            change(2, 2, 1, 'D', BoardChangeAction::Added),
+            change(1, 1, 0, 'A', BoardChangeAction::Defeated),
+        ]);
+
+        assert_eq!(
+            replay.control_heatmap(0),
+            Ok(vec![
+                vec![0.0, 0.0, 0.0],
+                vec![0.5, 0.75, 0.0],
+                vec![0.0, 0.0, 0.0],
+                vec![0.0, 0.0, 0.0],
+            ])
+        );
+        assert_eq!(
+            replay.control_heatmap(1),
+            Ok(vec![
+                vec![0.0, 0.0, 0.0],
+                vec![0.0, 0.0, 0.0],
+                vec![0.0, 0.75, 0.25],
+                vec![0.0, 0.0, 0.0],
+            ])
+        );
+    }
+
+    #[test]
+    fn records_move_durations() {
+        static CLOCK: AtomicU64 = AtomicU64::new(1000);
+        fn clock() -> u64 {
+            CLOCK.load(Ordering::SeqCst)
+        }
+
+        let mut bag = TileUtils::trivial_bag();
+        let mut players = vec![
+            Player::new("A".into(), 0, 7, &mut bag, None, (0, 0, 0)),
+            Player::new("B".into(), 1, 7, &mut bag, None, (0, 0, 0)),
+        ];
+        players[0].hand = Hand(vec!['A', 'C']);
+        players[1].hand = Hand(vec!['B', 'D']);
+        let mut rules = GameRules::generation(0);
+        rules.battle_delay = 0;
+
+        let mut game = Game {
+            board: Board::from_string(
+                "~~ |0 ~~ ~~\n\
+                 __ __ __ __\n\
+                 __ __ __ __\n\
+                 ~~ ~~ |1 ~~",
+            ),
+            bag,
+            players,
+            player_turn_count: vec![0, 0],
+            judge: Judge::new(vec![]),
+            clock,
+            ..Game::new_legacy(1, 1, None, rules)
+        };
+        game.start();
+        let mut replay = Replay::new(&game);
+
+        for (seconds, player, tile, position) in [
+            (4, 0, 'A', Coordinate::new(1, 1)),
+            (11, 1, 'B', Coordinate::new(2, 2)),
+            (2, 0, 'C', Coordinate::new(0, 1)),
+        ] {
+            CLOCK.fetch_add(seconds, Ordering::SeqCst);
+            let turn = Move::Place {
+                player,
+                tile,
+                position,
+            };
+            assert_eq!(game.play_turn(turn, None, None, None), Ok(None));
+            replay.record_game_turn(&game);
+        }
+
+        assert_eq!(replay.change_groups.len(), 3);
+        assert_eq!(
+            replay.move_durations,
+            vec![
+                vec![Duration::seconds(4), Duration::seconds(2)],
+                vec![Duration::seconds(11)]
+            ]
+        );
+    }
+
+    #[test]
+    fn save_and_load() {
+        let replay = test_replay();
+        let path = std::env::temp_dir().join(format!("save_and_load.{REPLAY_FILE_EXTENSION}"));
+
+        replay.save(&path).unwrap();
+        let loaded = Replay::load(&path).unwrap();
+        _ = fs::remove_file(&path);
+
+        assert_eq!(loaded, replay);
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let mut replay = test_replay();
+        replay.header.version = REPLAY_FORMAT_VERSION + 1;
+        let path = std::env::temp_dir().join(format!("other_version.{REPLAY_FILE_EXTENSION}"));
+
+        replay.save(&path).unwrap();
+        let loaded = Replay::load(&path);
+        _ = fs::remove_file(&path);
+
+        assert!(matches!(
+            loaded,
+            Err(ReplayError::UnsupportedVersion { found, expected })
+                if found == REPLAY_FORMAT_VERSION + 1 && expected == REPLAY_FORMAT_VERSION
+        ));
+    }
+}
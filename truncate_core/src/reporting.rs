@@ -15,6 +15,8 @@ pub enum BoardChangeAction {
     Defeated,
     Truncated,
     Exploded,
+    /// A tile that was turned into water, such as when its player was eliminated
+    Neutralized,
 }
 
 impl fmt::Display for BoardChangeAction {
@@ -26,6 +28,7 @@ impl fmt::Display for BoardChangeAction {
             BoardChangeAction::Defeated => write!(f, "Defeated"),
             BoardChangeAction::Truncated => write!(f, "Truncated"),
             BoardChangeAction::Exploded => write!(f, "Exploded"),
+            BoardChangeAction::Neutralized => write!(f, "Neutralized"),
         }
     }
 }
@@ -52,6 +55,17 @@ impl fmt::Display for BoardChange {
     }
 }
 
+/// How the game ended, so that the deciding moment can be shown to players
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum GameEnd {
+    /// A word reached the losing player's town or artifact
+    Destination { word: Vec<Coordinate>, text: String },
+    /// Every player swapped rather than placed for `turns` turns in a row, so nobody won
+    Stalemate { turns: usize },
+    /// Players were still level after the win metric and every tie breaker, so nobody won
+    Draw,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct HandChange {
     pub player: usize,
@@ -83,6 +97,39 @@ pub struct BattleWord {
     pub resolved_word: String,
     pub meanings: Option<Vec<WordMeaning>>,
     pub valid: Option<bool>,
+    /// The square under each letter of the word, used to hide letters from players who can't see them.
+    /// Only the game that fought the battle knows these.
+    #[serde(skip)]
+    pub coordinates: Vec<Coordinate>,
+}
+
+impl BattleWord {
+    /// Replaces letters outside of `visible` with '*', dropping any meanings that would give the word away
+    fn mask_hidden_letters(&mut self, visible: &HashSet<Coordinate>) {
+        if self.coordinates.iter().all(|c| visible.contains(c)) {
+            return;
+        }
+
+        let mask = |word: &str| -> String {
+            word.chars()
+                .zip(&self.coordinates)
+                .map(|(letter, c)| match letter {
+                    '#' | '|' => letter,
+                    _ if visible.contains(c) => letter,
+                    _ => '*',
+                })
+                .collect()
+        };
+        let original_word = mask(&self.original_word);
+        // Towns and artifacts can resolve to a word of a different length, which can't be masked letter by letter
+        self.resolved_word = if self.resolved_word.chars().count() == self.coordinates.len() {
+            mask(&self.resolved_word)
+        } else {
+            original_word.clone()
+        };
+        self.original_word = original_word;
+        self.meanings = None;
+    }
 }
 
 impl fmt::Display for BattleWord {
@@ -106,6 +153,104 @@ pub struct BattleReport {
     pub attackers: Vec<BattleWord>,
     pub defenders: Vec<BattleWord>,
     pub outcome: Outcome,
+    /// Length of the longest valid attacking word, if there was one
+    pub attacker_length: Option<usize>,
+    /// Length of the shortest valid defending word, if there was one
+    pub defender_length: Option<usize>,
+    /// How many letters longer than a defending word an attacking word needed to be
+    pub length_delta: isize,
+}
+
+impl BattleReport {
+    /// A short reason for the outcome of the battle, in terms of word lengths where they decided it
+    pub fn explanation(&self) -> String {
+        let Some(attacker) = self.attacker_length else {
+            return "no attacking word was valid".into();
+        };
+        let needed = self.length_delta;
+
+        match (&self.outcome, self.defender_length) {
+            (Outcome::AttackerWins(losers), _) if losers.is_empty() => {
+                "the attacker won outright".into()
+            }
+            (Outcome::AttackerWins(_), Some(defender))
+                if attacker as isize >= defender as isize + needed =>
+            {
+                format!("attacker {attacker} beats defender {defender}, needed {needed:+}")
+            }
+            (Outcome::AttackerWins(_), _) => "a defending word was invalid".into(),
+            (Outcome::DefenderWins, Some(defender)) if attacker == defender => {
+                format!("attacker {attacker} ties defender {defender}, needed {needed:+}")
+            }
+            (Outcome::DefenderWins, Some(defender)) => {
+                format!("attacker {attacker} can't beat defender {defender}, needed {needed:+}")
+            }
+            (Outcome::DefenderWins, None) => "the defence held".into(),
+        }
+    }
+
+    /// A line-by-line account of the battle for a text feed,
+    /// e.g. `SWORD (5) defeated CAT (3).`, with a line for each word that wasn't valid.
+    pub fn describe(&self) -> String {
+        // Invalid words have already been called out, so they go without a length
+        let label = |w: &BattleWord| match w.valid {
+            Some(true) => format!("{} ({})", w.resolved_word, w.resolved_word.chars().count()),
+            _ => w.resolved_word.clone(),
+        };
+        let valid = |words: &[BattleWord]| -> Vec<String> {
+            words
+                .iter()
+                .filter(|w| w.valid == Some(true))
+                .map(label)
+                .collect()
+        };
+        let attackers = list_words(valid(&self.attackers));
+        let defenders = list_words(valid(&self.defenders));
+
+        let mut lines: Vec<String> = self
+            .attackers
+            .iter()
+            .chain(self.defenders.iter())
+            .filter(|w| w.valid == Some(false))
+            .map(|w| format!("{} rejected — not a word.", w.resolved_word))
+            .collect();
+
+        match &self.outcome {
+            Outcome::AttackerWins(losers) if losers.is_empty() => {
+                lines.push(format!("{attackers} won outright."));
+            }
+            Outcome::AttackerWins(losers) => {
+                let defeated = self
+                    .defenders
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| losers.contains(i))
+                    .map(|(_, w)| label(w))
+                    .collect();
+                lines.push(format!("{attackers} defeated {}.", list_words(defeated)));
+            }
+            Outcome::DefenderWins if attackers.is_empty() || defenders.is_empty() => {}
+            Outcome::DefenderWins if self.attacker_length == self.defender_length => {
+                lines.push(format!(
+                    "{attackers} tied with {defenders}, so the defence held."
+                ));
+            }
+            Outcome::DefenderWins => {
+                lines.push(format!("{attackers} failed to beat {defenders}."));
+            }
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Joins words as `A`, `A and B`, or `A, B and C`
+fn list_words(mut words: Vec<String>) -> String {
+    match words.pop() {
+        None => String::new(),
+        Some(last) if words.is_empty() => last,
+        Some(last) => format!("{} and {last}", words.join(", ")),
+    }
 }
 
 impl fmt::Display for BattleReport {
@@ -152,6 +297,54 @@ pub enum Change {
     Time(TimeChange),
 }
 
+/// Everything that happened over a single turn, sorted by kind
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TurnReport {
+    /// Tiles placed or swapped by the player
+    pub placed: Vec<BoardChange>,
+    pub battles: Vec<BattleReport>,
+    /// Tiles removed for being cut off from their player's artifact
+    pub truncated: Vec<BoardChange>,
+    /// Tiles the player drew to refill their hand
+    pub drawn: Vec<char>,
+    pub winner: Option<usize>,
+    pub game_end: Option<GameEnd>,
+}
+
+impl TurnReport {
+    pub fn new(
+        player: usize,
+        changes: &[Change],
+        winner: Option<usize>,
+        game_end: Option<GameEnd>,
+    ) -> Self {
+        let mut report = TurnReport {
+            winner,
+            game_end,
+            ..Default::default()
+        };
+
+        for change in changes {
+            match change {
+                Change::Board(change) => match change.action {
+                    BoardChangeAction::Added | BoardChangeAction::Swapped => {
+                        report.placed.push(change.clone())
+                    }
+                    BoardChangeAction::Truncated => report.truncated.push(change.clone()),
+                    _ => {}
+                },
+                Change::Battle(battle) => report.battles.push(battle.clone()),
+                Change::Hand(hand) if hand.player == player => {
+                    report.drawn.extend(hand.added.iter().copied())
+                }
+                _ => {}
+            }
+        }
+
+        report
+    }
+}
+
 impl fmt::Display for Change {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -172,6 +365,28 @@ pub(crate) fn filter_to_player(
     winner: &Option<usize>,
     seen_tiles: &HashSet<Coordinate>,
 ) -> Vec<Change> {
+    // Under LetterFog, letters are hidden wherever they're reported unless the player can see the square,
+    // with the same vision as `Board::fog_of_war`. Everything is revealed once the game ends.
+    let letter_fog = match (visibility, winner) {
+        (rules::Visibility::LetterFog, None) => {
+            let mut visible = full_board.visible_coords(player_index, full_board.vision_radius);
+            // The player's own tiles stay legible even once they've left the board
+            visible.extend(changes.iter().filter_map(|change| match change {
+                Change::Board(BoardChange {
+                    detail:
+                        BoardChangeDetail {
+                            coordinate,
+                            square: Square::Occupied { player, .. },
+                        },
+                    ..
+                }) if *player == player_index => Some(*coordinate),
+                _ => None,
+            }));
+            Some(visible)
+        }
+        _ => None,
+    };
+
     changes
         .iter()
         .filter_map(|change| match change {
@@ -198,9 +413,16 @@ pub(crate) fn filter_to_player(
                 ) else {
                     return None;
                 };
+
+                let mut square = square.clone();
+                if let (Some(visible), Square::Occupied { tile, .. }) = (&letter_fog, &mut square) {
+                    if !visible.contains(coordinate) {
+                        *tile = '*';
+                    }
+                }
                 let relative_change = Change::Board(BoardChange {
                     detail: BoardChangeDetail {
-                        square: square.clone(),
+                        square,
                         coordinate: relative_coord,
                     },
                     action: action.clone(),
@@ -215,11 +437,15 @@ pub(crate) fn filter_to_player(
                     || action == &BoardChangeAction::Defeated
                     || action == &BoardChangeAction::Truncated
                     || action == &BoardChangeAction::Exploded
+                    || action == &BoardChangeAction::Neutralized
                 {
                     return Some(relative_change);
                 }
                 match visibility {
-                    rules::Visibility::Standard => Some(relative_change),
+                    // Tiles are always sent under LetterFog, with the hidden letters already masked
+                    rules::Visibility::Standard | rules::Visibility::LetterFog => {
+                        Some(relative_change)
+                    }
                     rules::Visibility::TileFog
                     | rules::Visibility::LandFog
                     | rules::Visibility::OnlyHouseFog => match visible_board.get(relative_coord) {
@@ -228,8 +454,167 @@ pub(crate) fn filter_to_player(
                     },
                 }
             }
-            Change::Battle(_) => Some(change.clone()),
+            Change::Battle(battle) => {
+                let mut battle = battle.clone();
+                if let Some(visible) = &letter_fog {
+                    for word in battle
+                        .attackers
+                        .iter_mut()
+                        .chain(battle.defenders.iter_mut())
+                    {
+                        word.mask_hidden_letters(visible);
+                    }
+                }
+                Some(Change::Battle(battle))
+            }
             Change::Time(_) => Some(change.clone()),
         })
         .collect::<Vec<_>>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fogged_board() -> Board {
+        Board::from_string(
+            "|0 A0 __ __ __ __ __ __ __ __ __ __\n\
+             __ __ __ __ __ __ __ __ __ __ B1 |1",
+        )
+    }
+
+    fn board_change(board: &Board, coordinate: Coordinate, action: BoardChangeAction) -> Change {
+        Change::Board(BoardChange {
+            detail: BoardChangeDetail {
+                square: board.get(coordinate).unwrap(),
+                coordinate,
+            },
+            action,
+        })
+    }
+
+    fn letters(changes: &[Change]) -> Vec<char> {
+        changes
+            .iter()
+            .map(|change| match change {
+                Change::Board(BoardChange {
+                    detail:
+                        BoardChangeDetail {
+                            square: Square::Occupied { tile, .. },
+                            ..
+                        },
+                    ..
+                }) => *tile,
+                _ => panic!("Expected a tile, got {change:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn letter_fog_masks_every_board_change() {
+        let board = fogged_board();
+        let hidden = Coordinate::new(10, 1);
+        let own = Coordinate::new(1, 0);
+        let visible_board = board.filter_to_player(
+            0,
+            &rules::Visibility::LetterFog,
+            &None,
+            &HashSet::new(),
+            true,
+        );
+        let filter = |changes: &Vec<Change>, winner: Option<usize>| {
+            filter_to_player(
+                changes,
+                &board,
+                &visible_board,
+                0,
+                &rules::Visibility::LetterFog,
+                &winner,
+                &HashSet::new(),
+            )
+        };
+
+        for action in [
+            BoardChangeAction::Added,
+            BoardChangeAction::Swapped,
+            BoardChangeAction::Victorious,
+            BoardChangeAction::Defeated,
+            BoardChangeAction::Truncated,
+            BoardChangeAction::Exploded,
+            BoardChangeAction::Neutralized,
+        ] {
+            let changes = vec![
+                board_change(&board, hidden, action.clone()),
+                board_change(&board, own, action.clone()),
+            ];
+            assert_eq!(letters(&filter(&changes, None)), vec!['*', 'A'], "{action}");
+            assert_eq!(
+                letters(&filter(&changes, Some(1))),
+                vec!['B', 'A'],
+                "{action}"
+            );
+        }
+
+        // The player's own tiles are legible after leaving the board, even out of sight
+        let mut gone = board_change(&board, hidden, BoardChangeAction::Defeated);
+        if let Change::Board(BoardChange {
+            detail:
+                BoardChangeDetail {
+                    square: Square::Occupied { player, .. },
+                    ..
+                },
+            ..
+        }) = &mut gone
+        {
+            *player = 0;
+        }
+        assert_eq!(letters(&filter(&vec![gone], None)), vec!['B']);
+    }
+
+    #[test]
+    fn letter_fog_masks_battle_words() {
+        let board = fogged_board();
+        let word = |text: &str, coordinates: Vec<Coordinate>| BattleWord {
+            original_word: text.into(),
+            resolved_word: text.into(),
+            meanings: Some(vec![WordMeaning {
+                pos: "noun".into(),
+                defs: vec!["A giveaway".into()],
+            }]),
+            valid: Some(true),
+            coordinates,
+        };
+        let changes = vec![Change::Battle(BattleReport {
+            battle_number: Some(0),
+            attackers: vec![word("A", vec![Coordinate::new(1, 0)])],
+            defenders: vec![word(
+                "|B",
+                vec![Coordinate::new(11, 1), Coordinate::new(10, 1)],
+            )],
+            outcome: Outcome::DefenderWins,
+            attacker_length: None,
+            defender_length: None,
+            length_delta: 2,
+        })];
+
+        let filtered = filter_to_player(
+            &changes,
+            &board,
+            &board,
+            0,
+            &rules::Visibility::LetterFog,
+            &None,
+            &HashSet::new(),
+        );
+        let [Change::Battle(battle)] = filtered.as_slice() else {
+            panic!("Expected a single battle, got {filtered:?}");
+        };
+        let Change::Battle(original) = &changes[0] else {
+            unreachable!()
+        };
+        assert_eq!(battle.attackers, original.attackers);
+        assert_eq!(battle.defenders[0].original_word, "|*");
+        assert_eq!(battle.defenders[0].resolved_word, "|*");
+        assert_eq!(battle.defenders[0].meanings, None);
+    }
+}
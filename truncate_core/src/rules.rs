@@ -1,4 +1,4 @@
-// TODO: Maximum consecutive swaps / stalemate rule
+use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
@@ -9,21 +9,21 @@ use crate::{
     },
 };
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TownDefense {
     BeatenByContact,
     BeatenByValidity,
     BeatenWithDefenseStrength(usize),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ArtifactDefense {
     Invincible,
     BeatenWithDefenseStrength(usize),
 }
 
 /// Conditions which, when hit, end the game and mark a winner
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum WinCondition {
     Destination {
         town_defense: TownDefense,
@@ -33,45 +33,106 @@ pub enum WinCondition {
 }
 
 /// Metrics to used to assign a winner when no condition was hit
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum WinMetric {
     TownProximity,
     ObeliskProximity,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Measures used, in order, to separate players who are still level after the win metric
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TieBreaker {
+    /// Most empty land walled in by the player's tiles
+    Territory,
+    /// Most tiles on the board
+    TileCount,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Visibility {
     Standard,
     TileFog,
+    /// Enemy tiles out of sight are shown, but not their letters
+    LetterFog,
     LandFog,
     OnlyHouseFog,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Truncation {
+    /// Tiles cut off from their player's artifact are removed at the end of each turn
     Root,
-    Larger, // TODO: Implement
+    /// Only each player's largest group of connected tiles survives the end of each turn
+    Larger,
+    /// Tiles are never removed for being disconnected, so islands of tiles can be played on
     None,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Where tiles go once they have been truncated from the board
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TruncationRecovery {
+    /// Truncated tiles are returned to the shared bag
+    #[default]
+    ToBag,
+    /// Truncated tiles go back to their owner's hand, which can take up to a full hand of extra tiles.
+    /// Any more than that are returned to the bag.
+    ToHand,
+}
+
+/// When the words a player forms have to be valid
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WordValidity {
+    /// Every word formed by a placement must be valid, or the placement is rejected
+    Always,
+    /// Words are only judged when they are used in a battle
+    #[default]
+    OnAttack,
+}
+
+/// Which squares count as touching when deciding whether tiles are still connected to their artifact
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Connectivity {
+    /// Only tiles that share an edge are connected
+    #[default]
+    Orthogonal,
+    /// Tiles that touch at a corner are connected too, as a chess king moves
+    King,
+}
+
+/// A head start given to one player at the start of the game, to even out a match between players of different strengths
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Handicap {
+    /// Tiles held in hand on top of the usual hand size
+    pub extra_hand_tiles: usize,
+    /// Seconds added to the player's time allowance, if the game is timed
+    pub extra_time: usize,
+    /// Tiles placed next to the player's root on top of the usual seed tiles
+    pub extra_seed_tiles: usize,
+}
+
+/// What happens to players who run over their time allowance.
+/// Under `RemoveTiles`, a player who has spent `phase_time` seconds over time loses a tile from the board,
+/// then another for every `period` seconds after that. See `Board::remove_penalty_tile` for which tile goes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum OvertimeRule {
     FreeWildcard { period: usize },
     Bomb { period: usize },
-    RemoveTiles { period: usize, phase_time: usize }, // TODO: Implement
+    RemoveTiles { period: usize, phase_time: usize },
     Elimination,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Timing {
     PerPlayer {
         time_allowance: usize,
         overtime_rule: OvertimeRule,
     },
+    /// Each turn must be played within `time_allowance` seconds, or it passes to the next player
     PerTurn {
-        // TODO: Implement
         time_allowance: usize,
     },
+    /// Players move whenever they like, but must wait `turn_delay` seconds after each of their moves,
+    /// and the game ends once `total_time_allowance` seconds have passed
     Periodic {
         turn_delay: usize,
         total_time_allowance: usize,
@@ -79,25 +140,52 @@ pub enum Timing {
     None,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Which letters fill the tile bag, and how many of each
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TileDistribution {
+    /// The letter counts of the rules' `tile_generation`
+    Standard,
+    /// How many of each letter the bag holds, for themed variants
+    Custom(HashMap<char, usize>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TileBagBehaviour {
     Standard,
     Infinite, // TODO: Implement
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Protects players from running dry on vowels once the tile bag runs low
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VowelGuarantee {
+    /// Only applies while the bag holds fewer tiles than this
+    pub below: usize,
+    /// Draws from the bag will never go this many times in a row without a vowel,
+    /// so long as the bag still has one to give
+    pub within: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BattleRules {
     pub length_delta: isize,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// What happens to the letters of defending tiles that lose a battle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CapturedTileRule {
+    ReturnToBag,
+    Void,
+    TransferToCaptor,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Swapping {
     Contiguous(SwapPenalty),
     Universal(SwapPenalty),
     None,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SwapPenalty {
     Time {
         swap_threshold: usize,
@@ -108,7 +196,7 @@ pub enum SwapPenalty {
     },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum BoardGenesis {
     Passthrough,
     SpecificBoard(Board),
@@ -116,22 +204,54 @@ pub enum BoardGenesis {
     Random(BoardParams),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GameRules {
     pub generation: Option<u32>,
     pub win_condition: WinCondition,
     pub win_metric: WinMetric,
     pub visibility: Visibility,
+    /// How many squares away each tile can see through fog of war, or further if it is part of a valid word
+    pub vision_radius: usize,
     pub truncation: Truncation,
+    /// Tiles in a valid word of at least this many letters are anchored, and survive truncation
+    pub truncation_immune_word_length: Option<usize>,
+    pub truncation_recovery: TruncationRecovery,
+    pub word_validity: WordValidity,
+    /// Squares vacated by a defeat, truncation or explosion can't be placed on for this many turns
+    pub square_cooldown: Option<u32>,
+    pub connectivity: Connectivity,
     pub timing: Timing,
     pub hand_size: usize,
+    /// The most tiles a player can place in a single turn
+    pub tiles_per_turn: usize,
+    /// How many tiles are placed next to each player's root before the first turn
+    pub seed_tiles: usize,
+    /// Head starts for individual players, indexed by player. Players without an entry get none.
+    pub handicaps: Vec<Handicap>,
     pub tile_generation: u32,
+    pub tile_distribution: TileDistribution,
     pub tile_bag_behaviour: TileBagBehaviour,
+    pub vowel_guarantee: Option<VowelGuarantee>,
     pub battle_rules: BattleRules,
+    pub captured_tiles: CapturedTileRule,
     pub swapping: Swapping,
+    /// Whether players can spend their turn exchanging tiles from their hand with the bag
+    pub tile_exchange: bool,
+    /// Ends the game as a draw once every player has gone this many turns in a row without placing a tile
+    pub max_consecutive_swaps: Option<usize>,
     pub battle_delay: u64,
     pub max_turns: Option<u64>,
+    pub tie_breakers: Vec<TieBreaker>,
     pub board_genesis: BoardGenesis,
+    /// Whether words and adjacency wrap around the edges of the board
+    pub wrap: bool,
+    /// Whether tiles lined up diagonally also form words
+    pub diagonal_words: bool,
+    /// Whether a tile with none of its player's tiles beside it counts as a one letter word
+    pub lone_tiles_are_words: bool,
+    /// Whether a player eliminated for running out of time has their tiles returned to the bag
+    /// and their home region turned to water, as `Board::reclaim_player` does
+    pub reclaim_eliminated: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -148,16 +268,35 @@ const RULE_GENERATIONS: [(Option<EffectiveRuleDay>, GameRules); 3] = [
             },
             win_metric: WinMetric::TownProximity,
             visibility: Visibility::Standard,
+            vision_radius: 2,
             truncation: Truncation::Root,
+            truncation_immune_word_length: None,
+            truncation_recovery: TruncationRecovery::ToBag,
+            word_validity: WordValidity::OnAttack,
+            square_cooldown: None,
+            connectivity: Connectivity::Orthogonal,
             timing: Timing::None,
             hand_size: 7,
+            tiles_per_turn: 1,
+            seed_tiles: 0,
+            handicaps: Vec::new(),
             tile_generation: 0,
+            tile_distribution: TileDistribution::Standard,
             tile_bag_behaviour: TileBagBehaviour::Standard,
+            vowel_guarantee: None,
             battle_rules: BattleRules { length_delta: 2 },
+            captured_tiles: CapturedTileRule::ReturnToBag,
             swapping: Swapping::Contiguous(SwapPenalty::Disallowed { allowed_swaps: 1 }),
+            tile_exchange: false,
+            max_consecutive_swaps: None,
             battle_delay: 2,
             max_turns: None,
+            tie_breakers: Vec::new(),
             board_genesis: BoardGenesis::Passthrough,
+            wrap: false,
+            diagonal_words: false,
+            lone_tiles_are_words: true,
+            reclaim_eliminated: false,
         },
     ),
     (
@@ -170,16 +309,35 @@ const RULE_GENERATIONS: [(Option<EffectiveRuleDay>, GameRules); 3] = [
             },
             win_metric: WinMetric::TownProximity,
             visibility: Visibility::Standard,
+            vision_radius: 2,
             truncation: Truncation::Root,
+            truncation_immune_word_length: None,
+            truncation_recovery: TruncationRecovery::ToBag,
+            word_validity: WordValidity::OnAttack,
+            square_cooldown: None,
+            connectivity: Connectivity::Orthogonal,
             timing: Timing::None,
             hand_size: 7,
+            tiles_per_turn: 1,
+            seed_tiles: 0,
+            handicaps: Vec::new(),
             tile_generation: 1,
+            tile_distribution: TileDistribution::Standard,
             tile_bag_behaviour: TileBagBehaviour::Standard,
+            vowel_guarantee: None,
             battle_rules: BattleRules { length_delta: 2 },
+            captured_tiles: CapturedTileRule::ReturnToBag,
             swapping: Swapping::Contiguous(SwapPenalty::Disallowed { allowed_swaps: 1 }),
+            tile_exchange: false,
+            max_consecutive_swaps: None,
             battle_delay: 2,
             max_turns: None,
+            tie_breakers: Vec::new(),
             board_genesis: BoardGenesis::Passthrough,
+            wrap: false,
+            diagonal_words: false,
+            lone_tiles_are_words: true,
+            reclaim_eliminated: false,
         },
     ),
     (
@@ -192,20 +350,56 @@ const RULE_GENERATIONS: [(Option<EffectiveRuleDay>, GameRules); 3] = [
             },
             win_metric: WinMetric::TownProximity,
             visibility: Visibility::Standard,
+            vision_radius: 2,
             truncation: Truncation::Root,
+            truncation_immune_word_length: None,
+            truncation_recovery: TruncationRecovery::ToBag,
+            word_validity: WordValidity::OnAttack,
+            square_cooldown: None,
+            connectivity: Connectivity::Orthogonal,
             timing: Timing::None,
             hand_size: 7,
+            tiles_per_turn: 1,
+            seed_tiles: 0,
+            handicaps: Vec::new(),
             tile_generation: 1,
+            tile_distribution: TileDistribution::Standard,
             tile_bag_behaviour: TileBagBehaviour::Standard,
+            vowel_guarantee: None,
             battle_rules: BattleRules { length_delta: 1 },
+            captured_tiles: CapturedTileRule::ReturnToBag,
             swapping: Swapping::Contiguous(SwapPenalty::Disallowed { allowed_swaps: 1 }),
+            tile_exchange: false,
+            max_consecutive_swaps: None,
             battle_delay: 2,
             max_turns: None,
+            tie_breakers: Vec::new(),
             board_genesis: BoardGenesis::Passthrough,
+            wrap: false,
+            diagonal_words: false,
+            lone_tiles_are_words: true,
+            reclaim_eliminated: false,
         },
     ),
 ];
 
+impl SwapPenalty {
+    /// The seconds lost for a player's `swaps`th swap in a row, if any.
+    /// Swaps past the end of `penalties` keep costing its last entry.
+    pub fn time_penalty(&self, swaps: usize) -> Option<usize> {
+        match self {
+            SwapPenalty::Time {
+                swap_threshold,
+                penalties,
+            } if swaps > *swap_threshold => penalties
+                .get(swaps - swap_threshold - 1)
+                .or_else(|| penalties.last())
+                .copied(),
+            _ => None,
+        }
+    }
+}
+
 impl GameRules {
     pub fn generation(gen: u32) -> Self {
         let (_, mut rules) = RULE_GENERATIONS
@@ -243,18 +437,33 @@ impl GameRules {
             },
             win_metric: WinMetric::ObeliskProximity,
             visibility: Visibility::LandFog,
+            vision_radius: 2,
             truncation: Truncation::None,
+            truncation_immune_word_length: None,
+            truncation_recovery: TruncationRecovery::ToBag,
+            word_validity: WordValidity::OnAttack,
+            square_cooldown: None,
+            connectivity: Connectivity::Orthogonal,
             timing: Timing::PerPlayer {
                 time_allowance: 75 * 60,
                 overtime_rule: OvertimeRule::Elimination,
             },
             hand_size: 7,
+            tiles_per_turn: 1,
+            seed_tiles: 0,
+            handicaps: Vec::new(),
             tile_generation: 1,
+            tile_distribution: TileDistribution::Standard,
             tile_bag_behaviour: TileBagBehaviour::Standard,
+            vowel_guarantee: None,
             battle_rules: BattleRules { length_delta: 1 },
+            captured_tiles: CapturedTileRule::ReturnToBag,
             swapping: Swapping::Contiguous(SwapPenalty::Disallowed { allowed_swaps: 1 }),
+            tile_exchange: false,
+            max_consecutive_swaps: None,
             battle_delay: 2,
             max_turns: Some(1050),
+            tie_breakers: Vec::new(),
             board_genesis: BoardGenesis::Random(BoardParams {
                 land_layer: BoardNoiseParams {
                     dispersion: [3.0, 3.0],
@@ -282,6 +491,10 @@ impl GameRules {
                     obelisk: true,
                 },
             }),
+            wrap: false,
+            diagonal_words: false,
+            lone_tiles_are_words: true,
+            reclaim_eliminated: false,
         }
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use rand::seq::SliceRandom;
+use rand::Rng;
 use rusqlite::Connection;
 use truncate_core::{
     judge::{WordData, WordDict},
@@ -12,7 +12,8 @@ pub static TRUNCATE_DICT: &str = include_str!("../../dict_builder/final_wordlist
 pub struct WordDB {
     pub conn: Option<Connection>,
     pub valid_words: WordDict,
-    pub room_codes: Vec<String>,
+    /// Room codes that aren't in use, in no particular order
+    pub free_room_codes: Vec<String>,
     pub allocated_room_codes: HashSet<String>,
 }
 
@@ -36,21 +37,26 @@ impl WordDB {
             .flatten()
     }
 
-    fn rand_code(&self) -> String {
-        self.room_codes
-            .choose(&mut rand::thread_rng())
-            .cloned()
-            .expect("No words in dataset")
+    /// Allocates a random room code from those not already in use,
+    /// or `None` if every room code is taken
+    pub fn get_free_code(&mut self) -> Option<String> {
+        if self.free_room_codes.is_empty() {
+            return None;
+        }
+
+        let index = rand::thread_rng().gen_range(0..self.free_room_codes.len());
+        let word = self.free_room_codes.swap_remove(index);
+        self.allocated_room_codes.insert(word.clone());
+        Some(word)
     }
 
-    // TODO: Reclaim codes after use
-    pub fn get_free_code(&mut self) -> String {
-        let mut word = self.rand_code();
-        while self.allocated_room_codes.get(&word).is_some() {
-            word = self.rand_code();
+    /// Returns a room code to the pool, returning whether it had been allocated
+    pub fn release_code(&mut self, code: &str) -> bool {
+        let released = self.allocated_room_codes.remove(code);
+        if released {
+            self.free_room_codes.push(code.to_string());
         }
-        self.allocated_room_codes.insert(word.clone());
-        word
+        released
     }
 }
 
@@ -99,7 +105,7 @@ pub fn read_defs() -> WordDB {
 
     WordDB {
         conn: word_db_connection,
-        room_codes,
+        free_room_codes: room_codes,
         valid_words,
         allocated_room_codes: HashSet::new(),
     }
@@ -3,10 +3,12 @@ use parking_lot::{Mutex, MutexGuard};
 use serde::{Deserialize, Serialize};
 use std::{net::SocketAddr, sync::Arc};
 use truncate_core::{
-    board::{Board, Coordinate},
+    board::{Board, ClientBoard, Coordinate},
+    error::{BoardError, GamePlayError},
     game::Game,
     generation::{ArtifactType, BoardParams},
-    messages::{GameMessage, GamePlayerMessage, GameStateMessage, LobbyPlayerMessage},
+    match_play::Match,
+    messages::{GameMessage, GamePlayerMessage, GameStateMessage, LobbyPlayerMessage, RoomSummary},
     moves::Move,
     reporting::Change,
     rules::GameRules,
@@ -30,6 +32,14 @@ pub struct GameManager {
     pub players: Vec<Player>,
     pub core_game: Game,
     pub effective_day: u32,
+    /// Private games are left out of the room listing
+    pub private: bool,
+    /// Players who have agreed to a rematch once this game has finished
+    pub rematch_requests: Vec<usize>,
+    /// The match this game is part of, which is carried over to each rematch
+    pub current_match: Option<Match>,
+    /// When the server first noticed that none of the players were connected
+    pub unattended_since: Option<u64>,
 }
 
 impl GameManager {
@@ -42,6 +52,19 @@ impl GameManager {
             players: vec![],
             core_game: game,
             effective_day,
+            private: false,
+            rematch_requests: vec![],
+            current_match: None,
+            unattended_since: None,
+        }
+    }
+
+    pub fn summary(&self) -> RoomSummary {
+        RoomSummary {
+            room_code: self.game_id.clone(),
+            player_count: self.players.len(),
+            rules: self.core_game.rules.clone(),
+            joinable: self.core_game.started_at.is_none(),
         }
     }
 
@@ -103,8 +126,8 @@ impl GameManager {
             .collect()
     }
 
-    pub fn edit_board(&mut self, board: Board) {
-        self.core_game.board = board;
+    pub fn edit_board(&mut self, board: Board) -> Result<(), BoardError> {
+        self.core_game.board.replace_with(board)
     }
 
     pub fn game_msg(
@@ -112,7 +135,8 @@ impl GameManager {
         player_index: usize,
         word_map: Option<&MutexGuard<'_, WordDB>>,
     ) -> GameStateMessage {
-        let (board, mut changes) = self.core_game.filter_game_to_player(player_index);
+        let (ClientBoard { board, view, .. }, mut changes) =
+            self.core_game.client_payload(player_index);
 
         if let Some(definitions) = word_map {
             for battle in changes.iter_mut().filter_map(|change| match change {
@@ -143,6 +167,10 @@ impl GameManager {
             }
         }
 
+        // Turn the board, and everything that happened on it, to face the player
+        let changes = board.reflect_move_for_opponent_view(player_index, &changes);
+        let board = board.rotated(view.quarter_turns());
+
         let hand = self
             .core_game
             .get_player(player_index)
@@ -172,6 +200,9 @@ impl GameManager {
             game_ends_at: self.core_game.game_ends_at,
             paused: self.core_game.paused,
             remaining_turns,
+            game_end: self.core_game.game_end.clone(),
+            visible_enemy_tiles: self.core_game.visible_enemy_tiles(player_index),
+            view,
         }
     }
 
@@ -222,18 +253,78 @@ impl GameManager {
         messages
     }
 
+    /// Records a player's agreement to a rematch, returning whether every player has now agreed
+    pub fn request_rematch(&mut self, player: SocketAddr) -> Result<bool, String> {
+        if !self.core_game.is_over() {
+            return Err("Cannot rematch unfinished game".into());
+        }
+        let Some(player_index) = self.get_player_index(player) else {
+            return Err("Player is not in this game".into());
+        };
+
+        if !self.rematch_requests.contains(&player_index) {
+            self.rematch_requests.push(player_index);
+        }
+
+        Ok(self.rematch_requests.len() == self.players.len())
+    }
+
+    /// Withdraws any pending rematch, returning the index of the player who declined
+    pub fn decline_rematch(&mut self, player: SocketAddr) -> Option<usize> {
+        let player_index = self.get_player_index(player)?;
+        self.rematch_requests.clear();
+        Some(player_index)
+    }
+
+    /// Sets up a fresh game with the same rules and players, rotating the players
+    /// so that someone else moves first. The players are moved out of this game.
+    pub fn rematch(&mut self, new_game_id: String) -> GameManager {
+        let mut new_game = GameManager::new(new_game_id, self.effective_day);
+        new_game.private = self.private;
+        new_game.core_game = Game::new(9, 9, None, self.core_game.rules.clone());
+
+        let mut next_board = self.core_game.board.clone();
+        next_board.reset();
+        new_game.core_game.board = next_board;
+
+        // A match that has already been won keeps its final score
+        if let Some(mut current_match) = self.current_match.take() {
+            match current_match.record_game(&self.core_game) {
+                Ok(()) | Err(GamePlayError::MatchOver) => {}
+                Err(e) => eprintln!("Couldn't score game {} in its match: {e}", self.game_id),
+            }
+            current_match.rotate_seats();
+            new_game.current_match = Some(current_match);
+        }
+
+        let mut next_sockets = std::mem::take(&mut self.players);
+        next_sockets.rotate_left(1);
+        self.rematch_requests.clear();
+
+        let mut next_players = self.core_game.players.clone();
+        next_players.rotate_left(1);
+        for (player, socket) in next_players.into_iter().zip(next_sockets) {
+            new_game
+                .add_player(socket, player.name)
+                .expect("Failed to add player to game");
+        }
+
+        new_game
+    }
+
     pub fn resign(&mut self, player: SocketAddr) -> Vec<(&Player, GameMessage)> {
         if let Some(player_index) = self.get_player_index(player) {
             self.core_game.resign_player(player_index);
             let mut messages = Vec::with_capacity(self.players.len());
 
-            if let Some(winner) = self.core_game.winner {
+            if self.core_game.is_over() {
+                let winner = self.core_game.winner.map(|w| w as u64);
                 for (player_index, player) in self.players.iter().enumerate() {
                     let mut end_game_msg = self.game_msg(player_index, None);
                     end_game_msg.changes = vec![];
                     messages.push((
                         player,
-                        GameMessage::GameEnd(self.game_msg(player_index, None), winner as u64),
+                        GameMessage::GameEnd(self.game_msg(player_index, None), winner),
                     ));
                 }
             }
@@ -250,34 +341,84 @@ impl GameManager {
         position: Coordinate,
         tile: char,
         words: Arc<Mutex<WordDB>>,
+    ) -> Vec<(&Player, GameMessage)> {
+        self.play_placement(
+            player,
+            |player_index| Move::Place {
+                player: player_index,
+                tile,
+                position,
+            },
+            words,
+        )
+    }
+
+    pub fn play_many(
+        &mut self,
+        player: SocketAddr,
+        placements: Vec<(Coordinate, char)>,
+        words: Arc<Mutex<WordDB>>,
+    ) -> Vec<(&Player, GameMessage)> {
+        self.play_placement(
+            player,
+            |player_index| Move::PlaceMany {
+                player: player_index,
+                placements,
+            },
+            words,
+        )
+    }
+
+    pub fn exchange(
+        &mut self,
+        player: SocketAddr,
+        indices: Vec<usize>,
+        words: Arc<Mutex<WordDB>>,
+    ) -> Vec<(&Player, GameMessage)> {
+        self.play_placement(
+            player,
+            |player_index| Move::Exchange {
+                player: player_index,
+                indices,
+            },
+            words,
+        )
+    }
+
+    fn play_placement(
+        &mut self,
+        player: SocketAddr,
+        placement: impl FnOnce(usize) -> Move,
+        words: Arc<Mutex<WordDB>>,
     ) -> Vec<(&Player, GameMessage)> {
         let mut messages = Vec::with_capacity(self.players.len());
 
         if let Some(player_index) = self.get_player_index(player) {
             let words_db = words.lock();
+            // Pass the turn first if it has run out, so that everyone hears about it
+            // even though the late move will be rejected
+            let timed_out = !self.core_game.tick().is_empty();
+            let next_move = self.move_from_player_view(player_index, placement(player_index));
             match self.core_game.play_turn(
-                Move::Place {
-                    player: player_index,
-                    tile,
-                    position,
-                },
+                next_move,
                 Some(&words_db.valid_words),
                 Some(&words_db.valid_words),
                 None,
             ) {
-                Ok(Some(winner)) => {
+                Ok(_) if self.core_game.is_over() => {
+                    let winner = self.core_game.winner.map(|w| w as u64);
                     for (player_index, player) in self.players.iter().enumerate() {
                         messages.push((
                             player,
                             GameMessage::GameEnd(
                                 self.game_msg(player_index, Some(&words_db)),
-                                winner as u64,
+                                winner,
                             ),
                         ));
                     }
                     return messages;
                 }
-                Ok(None) => {
+                Ok(_) => {
                     for (player_index, player) in self.players.iter().enumerate() {
                         messages.push((
                             player,
@@ -287,14 +428,18 @@ impl GameManager {
                     return messages;
                 }
                 Err(msg) => {
-                    return vec![(
+                    if timed_out {
+                        messages = self.state_messages(Some(&words_db));
+                    }
+                    messages.push((
                         &self.players[player_index],
                         GameMessage::GameError(
                             self.game_id.clone(),
                             player_index as u64,
                             msg.into(),
                         ),
-                    )]
+                    ));
+                    return messages;
                 }
             }
         } else {
@@ -315,19 +460,31 @@ impl GameManager {
 
         if let Some(player_index) = self.get_player_index(player) {
             let words_db = words.lock();
+            let timed_out = !self.core_game.tick().is_empty();
             match self.core_game.play_turn(
-                Move::Swap {
-                    player: player_index,
-                    positions: [from, to],
-                },
+                self.move_from_player_view(
+                    player_index,
+                    Move::Swap {
+                        player: player_index,
+                        positions: [from, to],
+                    },
+                ),
                 Some(&words_db.valid_words),
                 Some(&words_db.valid_words),
                 None,
             ) {
-                Ok(Some(_)) => {
-                    unreachable!("Cannot win by swapping")
+                Ok(_) if self.core_game.is_over() => {
+                    let winner = self.core_game.winner.map(|w| w as u64);
+                    for (player_index, player) in self.players.iter().enumerate() {
+                        messages.push((
+                            player,
+                            GameMessage::GameEnd(self.game_msg(player_index, None), winner),
+                        ));
+                    }
+
+                    messages
                 }
-                Ok(None) => {
+                Ok(_) => {
                     for (player_index, player) in self.players.iter().enumerate() {
                         messages.push((
                             player,
@@ -338,14 +495,18 @@ impl GameManager {
                     messages
                 }
                 Err(msg) => {
-                    return vec![(
+                    if timed_out {
+                        messages = self.state_messages(Some(&words_db));
+                    }
+                    messages.push((
                         &self.players[player_index],
                         GameMessage::GameError(
                             self.game_id.clone(),
                             player_index as u64,
                             msg.into(),
                         ),
-                    )]
+                    ));
+                    return messages;
                 }
             }
         } else {
@@ -353,6 +514,66 @@ impl GameManager {
         }
     }
 
+    /// Moves the coordinates of a player's move out of the turned board they were sent in `game_msg`
+    fn move_from_player_view(&self, player_index: usize, player_move: Move) -> Move {
+        let (ClientBoard { board, .. }, _) = self.core_game.client_payload(player_index);
+        let from_view = |coordinate| board.coordinate_from_player_view(player_index, coordinate);
+
+        match player_move {
+            Move::Place {
+                player,
+                tile,
+                position,
+            } => Move::Place {
+                player,
+                tile,
+                position: from_view(position),
+            },
+            Move::PlaceMany { player, placements } => Move::PlaceMany {
+                player,
+                placements: placements
+                    .into_iter()
+                    .map(|(position, tile)| (from_view(position), tile))
+                    .collect(),
+            },
+            Move::Swap { player, positions } => Move::Swap {
+                player,
+                positions: positions.map(from_view),
+            },
+            other => other,
+        }
+    }
+
+    /// Passes the turn on from a player who has run out of time, returning the messages to send out
+    pub fn tick(&mut self, words: Arc<Mutex<WordDB>>) -> Vec<(&Player, GameMessage)> {
+        if self.core_game.tick().is_empty() {
+            return vec![];
+        }
+
+        let words_db = words.lock();
+        self.state_messages(Some(&words_db))
+    }
+
+    /// Sends every player the current state of the game, or its conclusion if it has ended
+    fn state_messages(
+        &self,
+        words_db: Option<&MutexGuard<'_, WordDB>>,
+    ) -> Vec<(&Player, GameMessage)> {
+        self.players
+            .iter()
+            .enumerate()
+            .map(|(player_index, player)| {
+                let state = self.game_msg(player_index, words_db);
+                let message = if self.core_game.is_over() {
+                    GameMessage::GameEnd(state, self.core_game.winner.map(|w| w as u64))
+                } else {
+                    GameMessage::GameUpdate(state)
+                };
+                (player, message)
+            })
+            .collect()
+    }
+
     pub fn pause(&mut self, words: Arc<Mutex<WordDB>>) -> Vec<(&Player, GameMessage)> {
         self.core_game.pause();
 
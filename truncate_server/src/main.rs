@@ -11,7 +11,7 @@ use std::{env, io::Error as IoError, net::SocketAddr, sync::Arc};
 use uuid::Uuid;
 
 use definitions::WordDB;
-use futures_util::{future, pin_mut, stream::TryStreamExt, StreamExt};
+use futures_util::{future, future::BoxFuture, pin_mut, stream::TryStreamExt, StreamExt};
 use jwt_simple::prelude::*;
 use tokio::net::{TcpListener, TcpStream};
 use tokio::sync::mpsc;
@@ -28,9 +28,12 @@ use game_state::GameManager;
 use storage::accounts::{self, mark_most_changelogs_read, AuthedTruncateToken};
 use truncate_core::messages::{
     DailyStateMessage, GameMessage, GameStateMessage, LobbyPlayerMessage, Nonce,
-    NoncedPlayerMessage, PlayerMessage,
+    NoncedPlayerMessage, PlayerMessage, RoomSummary,
 };
 
+/// Seconds that a game in progress is kept after its last player disconnects
+const ABANDONED_GAME_TIMEOUT: u64 = 60 * 60;
+
 // TODO: Also find a way to include this in the database to prevent replay if reconnecting to a different backend
 #[derive(Default)]
 pub struct NonceTracker {
@@ -80,7 +83,7 @@ impl ServerState {
         Arc::clone(&self.word_db)
     }
 
-    fn game_code(&self) -> String {
+    fn game_code(&self) -> Option<String> {
         self.word_db.lock().get_free_code()
     }
 
@@ -93,6 +96,91 @@ impl ServerState {
         game
     }
 
+    /// Drops a finished game, detaching its players and freeing its room code for reuse
+    fn remove_game(&self, game_id: &str) {
+        let game_id = game_id.to_lowercase();
+
+        self.games.lock().remove(&game_id);
+        self.assignments.lock().retain(|_, id| *id != game_id);
+        self.word_db.lock().release_code(&game_id);
+    }
+
+    /// Drops the room a player was in once nobody is left connected to it,
+    /// unless its game is still underway and may yet be rejoined.
+    fn leave_room(&self, addr: &SocketAddr) {
+        let Some(game_id) = self.assignments.lock().get(addr).cloned() else {
+            return;
+        };
+        let Some(game) = self.get_game_by_code(&game_id) else {
+            return;
+        };
+
+        let abandoned = {
+            let game = game.lock();
+            let underway = game.core_game.started_at.is_some() && !game.core_game.is_over();
+            !underway && !self.is_attended(&game)
+        };
+
+        if abandoned {
+            self.remove_game(&game_id);
+        }
+    }
+
+    /// Whether any of the room's players are still connected to the server
+    fn is_attended(&self, game: &GameManager) -> bool {
+        let peers = self.peers.lock();
+        game.players.iter().any(|player| {
+            player
+                .socket
+                .is_some_and(|socket| peers.contains_key(&socket))
+        })
+    }
+
+    /// Drops every room that nobody is connected to. Games that are still underway
+    /// are kept for `ABANDONED_GAME_TIMEOUT` seconds first, so their players can rejoin.
+    fn release_abandoned_rooms(&self, now: u64) {
+        let games: Vec<_> = self
+            .games
+            .lock()
+            .iter()
+            .map(|(game_id, game)| (game_id.clone(), Arc::clone(game)))
+            .collect();
+
+        for (game_id, game) in games {
+            let abandoned = {
+                let mut game = game.lock();
+                if self.is_attended(&game) {
+                    game.unattended_since = None;
+                    false
+                } else {
+                    let underway = game.core_game.started_at.is_some() && !game.core_game.is_over();
+                    let since = *game.unattended_since.get_or_insert(now);
+                    !underway || now.saturating_sub(since) >= ABANDONED_GAME_TIMEOUT
+                }
+            };
+
+            if abandoned {
+                self.remove_game(&game_id);
+            }
+        }
+    }
+
+    /// Summarises the rooms that currently exist, for browsing open lobbies
+    fn room_listing(&self, include_private: bool) -> Vec<RoomSummary> {
+        let games: Vec<_> = self.games.lock().values().map(Arc::clone).collect();
+        let allocated = self.word_db.lock().allocated_room_codes.clone();
+
+        let mut rooms: Vec<_> = games
+            .iter()
+            .map(|game| game.lock())
+            .filter(|game| include_private || !game.private)
+            .filter(|game| allocated.contains(&game.game_id))
+            .map(|game| game.summary())
+            .collect();
+        rooms.sort_by(|a, b| a.room_code.cmp(&b.room_code));
+        rooms
+    }
+
     fn attach_player_to_game(&self, addr: &SocketAddr, game_id: &String) {
         let mut assignments = self.assignments.lock();
         let game_id = game_id.to_lowercase();
@@ -180,7 +268,7 @@ async fn handle_player_msg(
             // they may be stuck waiting for the info (e.g. waiting for DailyStats to show splash screen)
             let replayable = matches!(
                 parsed_msg,
-                RequestDefinitions(_) | RequestStats(_) | LoadReplay(_)
+                RequestDefinitions(_) | RequestStats(_) | LoadReplay(_) | ListRooms
             );
 
             if !replayable {
@@ -220,9 +308,13 @@ async fn handle_player_msg(
         NewGame {
             mut player_name,
             effective_day,
+            private,
         } => {
-            let new_game_id = server_state.game_code();
+            let Some(new_game_id) = server_state.game_code() else {
+                return player_err("There are no free rooms right now, try again soon".into());
+            };
             let mut game = GameManager::new(new_game_id.clone(), effective_day);
+            game.private = private;
 
             let connection_player = connection_info_mutex.lock().player.clone();
             _ = create_event(&server_state, &"new_game".into(), connection_player).await;
@@ -416,7 +508,7 @@ async fn handle_player_msg(
         EditBoard(board) => {
             if let Some(existing_game) = server_state.get_game_by_player(&player_addr) {
                 let mut game_manager = existing_game.lock();
-                game_manager.edit_board(board.clone());
+                let rejection = game_manager.edit_board(board).err();
                 let player_list: Vec<_> = game_manager
                     .core_game
                     .players
@@ -432,6 +524,20 @@ async fn handle_player_msg(
                     todo!("Handle player editing the board without having a turn index");
                 };
 
+                if let Some(e) = rejection {
+                    // Put the editor back in step with the board the server kept
+                    _ = server_state.send_to_player(
+                        &player_addr,
+                        GameMessage::LobbyUpdate(
+                            player_index as u64,
+                            game_manager.game_id.clone(),
+                            player_list,
+                            game_manager.core_game.board.clone(),
+                        ),
+                    );
+                    return player_err(format!("Couldn't use that board: {e}"));
+                }
+
                 for player in &game_manager.players {
                     let Some(socket) = player.socket else {
                         continue;
@@ -443,7 +549,7 @@ async fn handle_player_msg(
                                 player_index as u64,
                                 game_manager.game_id.clone(),
                                 player_list.clone(),
-                                board.clone(),
+                                game_manager.core_game.board.clone(),
                             ),
                         )
                         .unwrap();
@@ -494,6 +600,18 @@ async fn handle_player_msg(
         }
         StartGame => {
             if let Some(existing_game) = server_state.get_game_by_player(&player_addr) {
+                {
+                    let game_manager = existing_game.lock();
+                    if matches!(
+                        game_manager.core_game.rules.board_genesis,
+                        truncate_core::rules::BoardGenesis::Passthrough
+                    ) {
+                        if let Err(e) = game_manager.core_game.board.validate() {
+                            return player_err(format!("Couldn't start with that board: {e}"));
+                        }
+                    }
+                }
+
                 let connection_player = connection_info_mutex.lock().player.clone();
                 _ = create_event(&server_state, &"start_game".into(), connection_player).await;
 
@@ -554,6 +672,38 @@ async fn handle_player_msg(
                 todo!("Handle player not being enrolled in a game");
             }
         }
+        PlaceMany(placements) => {
+            if let Some(existing_game) = server_state.get_game_by_player(&player_addr) {
+                let mut game_manager = existing_game.lock();
+                for (player, message) in
+                    game_manager.play_many(player_addr, placements, server_state.words())
+                {
+                    let Some(socket) = player.socket else {
+                        continue;
+                    };
+                    server_state.send_to_player(&socket, message).unwrap();
+                }
+                // TODO: Error handling flow
+            } else {
+                return player_err("You aren't playing in a game".into());
+            }
+        }
+        Exchange(indices) => {
+            if let Some(existing_game) = server_state.get_game_by_player(&player_addr) {
+                let mut game_manager = existing_game.lock();
+                for (player, message) in
+                    game_manager.exchange(player_addr, indices, server_state.words())
+                {
+                    let Some(socket) = player.socket else {
+                        continue;
+                    };
+                    server_state.send_to_player(&socket, message).unwrap();
+                }
+                // TODO: Error handling flow
+            } else {
+                return player_err("You aren't playing in a game".into());
+            }
+        }
         Swap(from, to) => {
             if let Some(existing_game) = server_state.get_game_by_player(&player_addr) {
                 let mut game_manager = existing_game.lock();
@@ -576,70 +726,91 @@ async fn handle_player_msg(
                 _ = create_event(&server_state, &"rematch".into(), connection_player).await;
 
                 let mut existing_game_manager = existing_game.lock();
-                if existing_game_manager.core_game.winner.is_none() {
-                    return player_err("Cannot rematch unfinished game".into());
-                } else {
-                    let new_game_id = server_state.game_code();
-                    let mut new_game =
-                        GameManager::new(new_game_id.clone(), existing_game_manager.effective_day);
-
-                    let mut next_board = existing_game_manager.core_game.board.clone();
-                    next_board.reset();
-                    new_game.core_game.board = next_board;
-
-                    let mut next_sockets = existing_game_manager.players.clone();
-                    next_sockets.rotate_left(1);
-                    existing_game_manager.players = vec![];
-
-                    let mut next_players = existing_game_manager.core_game.players.clone();
-                    next_players.rotate_left(1);
-                    for (i, player) in next_players.into_iter().enumerate() {
-                        new_game
-                            .add_player(
-                                next_sockets
-                                    .get(i)
-                                    .expect("All players rejoining have a socket")
-                                    .clone(),
-                                player.name,
+                let everyone_agreed = match existing_game_manager.request_rematch(player_addr) {
+                    Ok(agreed) => agreed,
+                    Err(msg) => return player_err(msg),
+                };
+
+                if !everyone_agreed {
+                    let requester = existing_game_manager
+                        .get_player_index(player_addr)
+                        .expect("Player requesting a rematch is in the game");
+                    for player in &existing_game_manager.players {
+                        let Some(socket) = player.socket else {
+                            continue;
+                        };
+                        if socket == player_addr {
+                            continue;
+                        }
+                        server_state
+                            .send_to_player(
+                                &socket,
+                                GameMessage::RematchRequested(requester as u64),
                             )
-                            .expect("Failed to add player to game");
+                            .unwrap();
                     }
+                    return Ok(());
+                }
 
-                    drop(existing_game_manager); // Done with the old game, don't accidentally use it.
+                let Some(new_game_id) = server_state.game_code() else {
+                    return player_err("There are no free rooms for a rematch right now".into());
+                };
+                let new_game = existing_game_manager.rematch(new_game_id.clone());
 
-                    let new_game = server_state.add_new_game(&new_game_id, new_game);
-                    let new_game_manager = new_game.lock();
+                let old_game_id = existing_game_manager.game_id.clone();
+                drop(existing_game_manager); // Done with the old game, don't accidentally use it.
+                server_state.remove_game(&old_game_id);
 
-                    for (i, player) in new_game_manager.players.iter().enumerate() {
-                        let Some(socket) = player.socket else {
-                            continue;
-                        };
+                let new_game = server_state.add_new_game(&new_game_id, new_game);
+                let new_game_manager = new_game.lock();
 
-                        server_state.attach_player_to_game(&socket, &new_game_id);
+                for (i, player) in new_game_manager.players.iter().enumerate() {
+                    let Some(socket) = player.socket else {
+                        continue;
+                    };
 
-                        let claims = Claims::with_custom_claims(
-                            PlayerClaims {
-                                player_index: i,
-                                room_code: new_game_id.clone(),
-                            },
-                            Duration::from_days(7), // TODO: Determine game expiration time
-                        );
-                        let token = server_state
-                            .jwt_key
-                            .authenticate(claims)
-                            .expect("Claims should be serializable");
+                    server_state.attach_player_to_game(&socket, &new_game_id);
 
+                    let claims = Claims::with_custom_claims(
+                        PlayerClaims {
+                            player_index: i,
+                            room_code: new_game_id.clone(),
+                        },
+                        Duration::from_days(7), // TODO: Determine game expiration time
+                    );
+                    let token = server_state
+                        .jwt_key
+                        .authenticate(claims)
+                        .expect("Claims should be serializable");
+
+                    server_state
+                        .send_to_player(
+                            &socket,
+                            GameMessage::JoinedLobby(
+                                i as u64,
+                                new_game_id.clone(),
+                                new_game_manager.player_list(),
+                                new_game_manager.core_game.board.clone(),
+                                token,
+                            ),
+                        )
+                        .unwrap();
+                }
+            }
+        }
+        DeclineRematch => {
+            if let Some(existing_game) = server_state.get_game_by_player(&player_addr) {
+                let mut game_manager = existing_game.lock();
+                if let Some(decliner) = game_manager.decline_rematch(player_addr) {
+                    for player in &game_manager.players {
+                        let Some(socket) = player.socket else {
+                            continue;
+                        };
+                        if socket == player_addr {
+                            continue;
+                        }
                         server_state
-                            .send_to_player(
-                                &socket,
-                                GameMessage::JoinedLobby(
-                                    i as u64,
-                                    new_game_id.clone(),
-                                    new_game_manager.player_list(),
-                                    new_game_manager.core_game.board.clone(),
-                                    token,
-                                ),
-                            )
+                            .send_to_player(&socket, GameMessage::RematchDeclined(decliner as u64))
                             .unwrap();
                     }
                 }
@@ -814,6 +985,14 @@ async fn handle_player_msg(
                 return player_err("Replay does not exist".into());
             }
         }
+        ListRooms => {
+            server_state
+                .send_to_player(
+                    &player_addr,
+                    GameMessage::RoomList(server_state.room_listing(false)),
+                )
+                .unwrap();
+        }
         PersistPuzzleMoves {
             player_token,
             day,
@@ -922,9 +1101,15 @@ async fn handle_connection(server_state: ServerState, raw_stream: TcpStream, add
                             let next_player = &players[*next_player as usize];
                             if let Some(time_remaining) = next_player.time_remaining {
                                 println!("Some player has {time_remaining} time left");
+                                // The clock doesn't start until any battle delay has passed
+                                let turn_starts_in = next_player
+                                    .turn_starts_no_later_than
+                                    .map(|t| t.saturating_sub(truncate_core::game::now()))
+                                    .unwrap_or_default();
                                 tokio::spawn(check_game_over(
                                     room_code.clone(),
-                                    time_remaining.whole_milliseconds(),
+                                    time_remaining.whole_milliseconds()
+                                        + turn_starts_in as i128 * 1000,
                                     server_state.clone(),
                                 ));
                             }
@@ -941,38 +1126,67 @@ async fn handle_connection(server_state: ServerState, raw_stream: TcpStream, add
     pin_mut!(handle_player_msg, messages_to_player);
     future::select(handle_player_msg, messages_to_player).await;
 
-    let mut peer_map = server_state.peers.lock();
-    peer_map.remove(&addr);
+    server_state.peers.lock().remove(&addr);
+    server_state.leave_room(&addr);
 }
 
-async fn check_game_over(game_id: String, check_in_ms: i128, server_state: ServerState) {
-    if check_in_ms.is_negative() {
-        return;
-    }
-    tokio::time::sleep(Duration::from_millis(check_in_ms as u64 + 10).into()).await;
+/// Boxed so that it can schedule itself again for the next turn's deadline
+fn check_game_over(
+    game_id: String,
+    check_in_ms: i128,
+    server_state: ServerState,
+) -> BoxFuture<'static, ()> {
+    Box::pin(async move {
+        if check_in_ms.is_negative() {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(check_in_ms as u64 + 10).into()).await;
 
-    let mut game_map = server_state.games.lock();
-    let Some(existing_game) = game_map.get_mut(&game_id) else {
-        return;
-    };
-    let mut game_manager = existing_game.lock();
-    game_manager.core_game.calculate_game_over(None);
+        let mut game_map = server_state.games.lock();
+        let Some(existing_game) = game_map.get_mut(&game_id) else {
+            return;
+        };
+        let mut game_manager = existing_game.lock();
+        game_manager.core_game.calculate_game_over(None);
 
-    let words_db = server_state.words();
+        let words_db = server_state.words();
 
-    if let Some(winner) = game_manager.core_game.winner {
-        for (player_index, player) in game_manager.players.iter().enumerate() {
-            let Some(socket) = player.socket else {
-                continue;
-            };
-            let mut end_game_msg = game_manager.game_msg(player_index, Some(&words_db.lock()));
-            // Don't send any of the latest battles or hand changes
-            end_game_msg.changes = vec![];
-            server_state
-                .send_to_player(&socket, GameMessage::GameEnd(end_game_msg, winner as u64))
-                .unwrap();
+        // Turns that have run out of time are passed on
+        if !game_manager.core_game.is_over() {
+            for (player, message) in game_manager.tick(words_db.clone()) {
+                let Some(socket) = player.socket else {
+                    continue;
+                };
+                // The player may have disconnected, in which case they'll catch up on rejoining
+                _ = server_state.send_to_player(&socket, message);
+            }
+
+            // Keep watching the clock of whoever is up next
+            if let Some(deadline) = game_manager.core_game.turn_deadline() {
+                let now = truncate_core::game::now();
+                tokio::spawn(check_game_over(
+                    game_id,
+                    deadline.saturating_sub(now) as i128 * 1000,
+                    server_state.clone(),
+                ));
+            }
+            return;
         }
-    }
+
+        if game_manager.core_game.is_over() {
+            let winner = game_manager.core_game.winner.map(|w| w as u64);
+            for (player_index, player) in game_manager.players.iter().enumerate() {
+                let Some(socket) = player.socket else {
+                    continue;
+                };
+                let mut end_game_msg = game_manager.game_msg(player_index, Some(&words_db.lock()));
+                // Don't send any of the latest battles or hand changes
+                end_game_msg.changes = vec![];
+                _ = server_state
+                    .send_to_player(&socket, GameMessage::GameEnd(end_game_msg, winner));
+            }
+        }
+    })
 }
 
 async fn clean_nonces(server_state: ServerState) {
@@ -985,6 +1199,15 @@ async fn clean_nonces(server_state: ServerState) {
     }
 }
 
+async fn clean_abandoned_rooms(server_state: ServerState) {
+    loop {
+        // Look for rooms that everyone has left every five minutes
+        tokio::time::sleep(Duration::from_mins(5).into()).await;
+
+        server_state.release_abandoned_rooms(truncate_core::game::now());
+    }
+}
+
 async fn ping_peers(server_state: ServerState) {
     loop {
         // Ping all clients every five seconds
@@ -1066,6 +1289,7 @@ async fn main() -> Result<(), IoError> {
 
     tokio::spawn(ping_peers(server_state.clone()));
     tokio::spawn(clean_nonces(server_state.clone()));
+    tokio::spawn(clean_abandoned_rooms(server_state.clone()));
 
     std::thread::spawn(move || loop {
         std::thread::sleep(std::time::Duration::from_secs(10));
@@ -1090,3 +1314,196 @@ async fn main() -> Result<(), IoError> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use truncate_core::{judge::WordDict, match_play::Match, rules::GameRules};
+
+    use super::*;
+
+    fn test_server() -> ServerState {
+        ServerState {
+            games: Default::default(),
+            assignments: Default::default(),
+            peers: Default::default(),
+            word_db: Arc::new(Mutex::new(WordDB {
+                conn: None,
+                valid_words: WordDict::new(),
+                free_room_codes: vec!["apple".into(), "banana".into(), "cherry".into()],
+                allocated_room_codes: HashSet::new(),
+            })),
+            nonces: Default::default(),
+            truncate_db: None,
+            jwt_key: HS256Key::generate(),
+        }
+    }
+
+    fn open_room(server: &ServerState, private: bool) -> String {
+        let code = server.game_code().unwrap();
+        let mut game = GameManager::new(code.clone(), 0);
+        game.private = private;
+        game.add_player(Player { socket: None }, "Player 1".into())
+            .unwrap();
+        server.add_new_game(&code, game);
+        code
+    }
+
+    #[test]
+    fn room_listing_tracks_rooms() {
+        let server = test_server();
+        assert!(server.room_listing(true).is_empty());
+
+        let public = open_room(&server, false);
+        let private = open_room(&server, true);
+
+        let listed = server.room_listing(false);
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].room_code, public);
+        assert_eq!(listed[0].player_count, 1);
+        assert!(listed[0].joinable);
+
+        let mut everything: Vec<_> = server
+            .room_listing(true)
+            .into_iter()
+            .map(|r| r.room_code)
+            .collect();
+        everything.sort();
+        let mut expected = vec![public.clone(), private.clone()];
+        expected.sort();
+        assert_eq!(everything, expected);
+
+        server.remove_game(&public);
+        assert!(server.room_listing(false).is_empty());
+        assert!(!server.word_db.lock().allocated_room_codes.contains(&public));
+
+        server.remove_game(&private);
+        assert!(server.room_listing(true).is_empty());
+    }
+
+    #[test]
+    fn room_codes_are_reclaimed() {
+        let server = test_server();
+        let mut codes: Vec<_> = (0..3).map(|_| server.game_code().unwrap()).collect();
+        codes.sort();
+        assert_eq!(codes, vec!["apple", "banana", "cherry"]);
+        assert_eq!(server.game_code(), None);
+
+        let mut words = server.word_db.lock();
+        assert!(words.release_code("banana"));
+        assert!(!words.release_code("banana"));
+        assert!(!words.release_code("durian"));
+        assert_eq!(words.get_free_code(), Some("banana".into()));
+        drop(words);
+
+        // Rooms are dropped once their last player leaves, but not mid-game
+        let addr: SocketAddr = "127.0.0.1:1000".parse().unwrap();
+        let (tx, _rx) = mpsc::unbounded_channel();
+        server.track_peer(&addr, tx);
+        server.word_db.lock().release_code("apple");
+        let code = open_room(&server, false);
+        let game = server.get_game_by_code(&code).unwrap();
+        game.lock().players[0].socket = Some(addr);
+        server.attach_player_to_game(&addr, &code);
+
+        server.leave_room(&addr);
+        assert!(server.get_game_by_code(&code).is_some());
+
+        server.peers.lock().remove(&addr);
+        game.lock().core_game.started_at = Some(0);
+        server.leave_room(&addr);
+        assert!(server.get_game_by_code(&code).is_some());
+
+        game.lock().core_game.winner = Some(0);
+        server.leave_room(&addr);
+        assert!(server.get_game_by_code(&code).is_none());
+        assert!(!server.word_db.lock().allocated_room_codes.contains(&code));
+    }
+
+    #[test]
+    fn abandoned_rooms_are_released() {
+        let server = test_server();
+        let addr: SocketAddr = "127.0.0.1:1000".parse().unwrap();
+        let (tx, _rx) = mpsc::unbounded_channel();
+        server.track_peer(&addr, tx);
+
+        let lobby = open_room(&server, false);
+        let attended = open_room(&server, false);
+        let underway = open_room(&server, false);
+        server.get_game_by_code(&attended).unwrap().lock().players[0].socket = Some(addr);
+        let game = server.get_game_by_code(&underway).unwrap();
+        game.lock().core_game.started_at = Some(0);
+
+        // Empty lobbies go straight away, while games in progress wait for their players
+        server.release_abandoned_rooms(1_000);
+        assert!(server.get_game_by_code(&lobby).is_none());
+        assert!(server.get_game_by_code(&attended).is_some());
+        assert!(server.get_game_by_code(&underway).is_some());
+
+        server.release_abandoned_rooms(1_000 + ABANDONED_GAME_TIMEOUT - 1);
+        assert!(server.get_game_by_code(&underway).is_some());
+
+        server.release_abandoned_rooms(1_000 + ABANDONED_GAME_TIMEOUT);
+        assert!(server.get_game_by_code(&underway).is_none());
+        assert!(server.get_game_by_code(&attended).is_some());
+
+        let mut free: Vec<_> = (0..2).map(|_| server.game_code().unwrap()).collect();
+        free.sort();
+        let mut released = vec![lobby, underway];
+        released.sort();
+        assert_eq!(free, released);
+    }
+
+    #[test]
+    fn rematch_needs_consent_and_swaps_players() {
+        let first: SocketAddr = "127.0.0.1:1000".parse().unwrap();
+        let second: SocketAddr = "127.0.0.1:2000".parse().unwrap();
+
+        let mut game = GameManager::new("apple".into(), 0);
+        let mut rules = GameRules::generation(0);
+        rules.max_turns = Some(40);
+        game.core_game.rules = rules.clone();
+        game.add_player(
+            Player {
+                socket: Some(first),
+            },
+            "Player 1".into(),
+        )
+        .unwrap();
+        game.add_player(
+            Player {
+                socket: Some(second),
+            },
+            "Player 2".into(),
+        )
+        .unwrap();
+
+        game.current_match = Some(Match::best_of(3));
+
+        assert!(game.request_rematch(first).is_err());
+        game.core_game.winner = Some(0);
+
+        // A decline withdraws the first player's request
+        assert_eq!(game.request_rematch(first), Ok(false));
+        assert_eq!(game.decline_rematch(second), Some(1));
+        assert_eq!(game.request_rematch(second), Ok(false));
+        assert_eq!(game.request_rematch(first), Ok(true));
+
+        let rematch = game.rematch("banana".into());
+        assert!(game.players.is_empty());
+        assert_eq!(rematch.game_id, "banana");
+        assert_eq!(rematch.core_game.rules, rules);
+        assert_eq!(rematch.core_game.winner, None);
+        assert_eq!(rematch.players[0].socket, Some(second));
+        assert_eq!(rematch.players[1].socket, Some(first));
+        assert_eq!(rematch.core_game.players[0].name, "Player 2");
+        assert_eq!(rematch.core_game.players[1].name, "Player 1");
+        assert!(rematch.rematch_requests.is_empty());
+
+        // The finished game counts towards the match, which follows the players to their new seats
+        let current_match = rematch.current_match.as_ref().unwrap();
+        assert_eq!(current_match.scores, vec![1, 0]);
+        assert_eq!(current_match.seats, vec![1, 0]);
+    }
+}
@@ -242,6 +242,8 @@ pub async fn persist_moves(
             let player = match m {
                 Move::Place { player, .. } => player,
                 Move::Swap { player, .. } => player,
+                Move::PlaceMany { player, .. } => player,
+                Move::Exchange { player, .. } => player,
             };
             *player as i32 == human_player
         })